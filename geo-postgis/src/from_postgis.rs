@@ -3,7 +3,10 @@ use geo_types::{
     Polygon,
 };
 
-use postgis::ewkb::{GeometryCollectionT, GeometryT};
+use postgis::ewkb::{
+    self, GeometryCollectionT, GeometryT, LineStringT, MultiLineStringT, MultiPointT,
+    MultiPolygonT, PolygonT,
+};
 
 #[cfg_attr(docsrs, doc(cfg(feature = "postgis")))]
 /// 从PostGIS类型创建几何对象。
@@ -82,6 +85,9 @@ impl<'a, T> FromPostgis<&'a GeometryCollectionT<T>> for GeometryCollection
 where
     T: postgis::Point + postgis::ewkb::EwkbRead,
 {
+    /// 每个成员通过 [`Option<Geometry>`] 的转换进行转换，因此嵌套的几何集合会被递归转换，
+    /// 空集合转换为空的 [`GeometryCollection`]。
+    ///
     /// 此实现丢弃不能转换的几何体
     /// （当调用`from_postgis()`时返回`None`）。
     fn from_postgis(gc: &'a GeometryCollectionT<T>) -> Self {
@@ -117,3 +123,78 @@ where
         })
     }
 }
+
+#[cfg_attr(docsrs, doc(cfg(feature = "postgis")))]
+/// 从PostGIS类型创建几何对象，同时返回其SRID（空间参考系统标识符）。
+///
+/// [`FromPostgis`] 会丢弃SRID；如果下游需要知道数据所在的参考系统，请使用此trait。
+/// 返回的SRID取自最外层的PostGIS几何体，成员几何体各自的SRID不会被检查。
+/// 与 [`FromPostgis`] 一样，不会尝试在参考系统之间转换数据。
+///
+/// # 示例
+///
+/// ```rust
+/// use geo_postgis::FromPostgisWithSrid;
+///
+/// let postgis_point = postgis::ewkb::Point { x: 1., y: -2., srid: Some(4326) };
+///
+/// let (geo_point, srid) = geo_types::Point::from_postgis_with_srid(&postgis_point);
+///
+/// assert_eq!(geo_types::point!(x: 1., y: -2.), geo_point);
+/// assert_eq!(Some(4326), srid);
+/// ```
+pub trait FromPostgisWithSrid<T>: Sized {
+    fn from_postgis_with_srid(_: T) -> (Self, Option<i32>);
+}
+
+macro_rules! from_postgis_with_srid_point_impl {
+    ($($point:ident),*) => {
+        $(
+            impl<'a> FromPostgisWithSrid<&'a ewkb::$point> for Point {
+                fn from_postgis_with_srid(pt: &'a ewkb::$point) -> (Self, Option<i32>) {
+                    (Point::from_postgis(pt), pt.srid)
+                }
+            }
+            impl<'a> FromPostgisWithSrid<&'a GeometryT<ewkb::$point>> for Option<Geometry> {
+                /// 返回一个`Option`，因为提供的几何体可能是一个无效的`Polygon`；
+                /// 即使如此，SRID仍会被返回。
+                fn from_postgis_with_srid(
+                    geo: &'a GeometryT<ewkb::$point>,
+                ) -> (Self, Option<i32>) {
+                    let srid = match *geo {
+                        GeometryT::Point(ref p) => p.srid,
+                        GeometryT::LineString(ref ls) => ls.srid,
+                        GeometryT::Polygon(ref p) => p.srid,
+                        GeometryT::MultiPoint(ref p) => p.srid,
+                        GeometryT::MultiLineString(ref p) => p.srid,
+                        GeometryT::MultiPolygon(ref p) => p.srid,
+                        GeometryT::GeometryCollection(ref p) => p.srid,
+                    };
+                    (Option::from_postgis(geo), srid)
+                }
+            }
+        )*
+    };
+}
+
+from_postgis_with_srid_point_impl!(Point, PointZ, PointM, PointZM);
+
+macro_rules! from_postgis_with_srid_impl {
+    ($from:ident, $to:ty) => {
+        impl<'a, P> FromPostgisWithSrid<&'a $from<P>> for $to
+        where
+            P: 'a + postgis::Point + postgis::ewkb::EwkbRead,
+        {
+            fn from_postgis_with_srid(geo: &'a $from<P>) -> (Self, Option<i32>) {
+                (<$to>::from_postgis(geo), geo.srid)
+            }
+        }
+    };
+}
+
+from_postgis_with_srid_impl!(LineStringT, LineString);
+from_postgis_with_srid_impl!(PolygonT, Option<Polygon<f64>>);
+from_postgis_with_srid_impl!(MultiPointT, MultiPoint);
+from_postgis_with_srid_impl!(MultiLineStringT, MultiLineString);
+from_postgis_with_srid_impl!(MultiPolygonT, MultiPolygon);
+from_postgis_with_srid_impl!(GeometryCollectionT, GeometryCollection);
@@ -40,4 +40,117 @@ mod to_postgis; // 模块用于将geo-types转换为postgis
 pub use to_postgis::ToPostgis; // 导出ToPostgis trait
 
 mod from_postgis; // 模块用于将postgis转换为geo-types
-pub use from_postgis::FromPostgis; // 导出FromPostgis trait
+pub use from_postgis::{FromPostgis, FromPostgisWithSrid}; // 导出FromPostgis和FromPostgisWithSrid trait
+
+#[cfg(test)]
+mod test {
+    use super::{FromPostgis, FromPostgisWithSrid, ToPostgis};
+    use geo_types::{line_string, point, polygon, Geometry, GeometryCollection};
+    use postgis::ewkb;
+
+    /// `ewkb::GeometryCollection` 没有实现 `PartialEq`，因此逐个成员递归比较
+    fn assert_collection_eq(left: &ewkb::GeometryCollection, right: &ewkb::GeometryCollection) {
+        assert_eq!(left.srid, right.srid);
+        assert_eq!(left.geometries.len(), right.geometries.len());
+        for (l, r) in left.geometries.iter().zip(right.geometries.iter()) {
+            match (l, r) {
+                (ewkb::GeometryT::Point(l), ewkb::GeometryT::Point(r)) => assert_eq!(l, r),
+                (ewkb::GeometryT::LineString(l), ewkb::GeometryT::LineString(r)) => {
+                    assert_eq!(l, r)
+                }
+                (ewkb::GeometryT::Polygon(l), ewkb::GeometryT::Polygon(r)) => assert_eq!(l, r),
+                (ewkb::GeometryT::MultiPoint(l), ewkb::GeometryT::MultiPoint(r)) => {
+                    assert_eq!(l, r)
+                }
+                (ewkb::GeometryT::MultiLineString(l), ewkb::GeometryT::MultiLineString(r)) => {
+                    assert_eq!(l, r)
+                }
+                (ewkb::GeometryT::MultiPolygon(l), ewkb::GeometryT::MultiPolygon(r)) => {
+                    assert_eq!(l, r)
+                }
+                (
+                    ewkb::GeometryT::GeometryCollection(l),
+                    ewkb::GeometryT::GeometryCollection(r),
+                ) => assert_collection_eq(l, r),
+                (l, r) => panic!("几何类型不同：{l:?} 与 {r:?}"),
+            }
+        }
+    }
+
+    fn nested_collection() -> GeometryCollection {
+        GeometryCollection::new_from(vec![
+            Geometry::Point(point!(x: 1., y: 2.)),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Geometry::LineString(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+            ])),
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]),
+        ])
+    }
+
+    #[test]
+    fn empty_geometry_collection_round_trip() {
+        let gc = GeometryCollection::new_from(vec![]);
+        let postgis_gc = gc.to_postgis_with_srid(None);
+        assert!(postgis_gc.geometries.is_empty());
+        assert_eq!(postgis_gc.srid, None);
+        assert_eq!(GeometryCollection::from_postgis(&postgis_gc), gc);
+
+        let postgis_gc = ewkb::GeometryCollection::new();
+        let round_trip = GeometryCollection::from_postgis(&postgis_gc).to_postgis_with_srid(None);
+        assert_collection_eq(&round_trip, &postgis_gc);
+    }
+
+    #[test]
+    fn nested_geometry_collection_round_trip() {
+        let gc = nested_collection();
+        let postgis_gc = gc.to_postgis_with_srid(None);
+        assert_eq!(GeometryCollection::from_postgis(&postgis_gc), gc);
+
+        let round_trip = GeometryCollection::from_postgis(&postgis_gc).to_postgis_with_srid(None);
+        assert_collection_eq(&round_trip, &postgis_gc);
+
+        // 作为 `Geometry` 转换时同样递归
+        let geometry = Geometry::GeometryCollection(gc);
+        let postgis_geometry = geometry.to_postgis_with_srid(None);
+        assert_eq!(Option::from_postgis(&postgis_geometry), Some(geometry));
+    }
+
+    #[test]
+    fn geometry_collection_with_srid_round_trip() {
+        let gc = nested_collection();
+        let postgis_gc = gc.to_postgis_wgs84();
+        assert_eq!(postgis_gc.srid, Some(4326));
+        // SRID 也被设置到每个成员上，包括嵌套集合内部
+        match &postgis_gc.geometries[1] {
+            ewkb::GeometryT::GeometryCollection(inner) => {
+                assert_eq!(inner.srid, Some(4326));
+                match &inner.geometries[0] {
+                    ewkb::GeometryT::LineString(ls) => assert_eq!(ls.srid, Some(4326)),
+                    other => panic!("期望 LineString，得到 {other:?}"),
+                }
+            }
+            other => panic!("期望 GeometryCollection，得到 {other:?}"),
+        }
+        assert_eq!(
+            GeometryCollection::from_postgis_with_srid(&postgis_gc),
+            (gc, Some(4326))
+        );
+
+        let (geo_gc, srid) = GeometryCollection::from_postgis_with_srid(&postgis_gc);
+        assert_collection_eq(&geo_gc.to_postgis_with_srid(srid), &postgis_gc);
+
+        let postgis_geometry = ewkb::GeometryT::GeometryCollection(postgis_gc);
+        let (geometry, srid) = Option::<Geometry>::from_postgis_with_srid(&postgis_geometry);
+        assert_eq!(srid, Some(4326));
+        match geometry.unwrap().to_postgis_with_srid(srid) {
+            ewkb::GeometryT::GeometryCollection(round_trip) => match postgis_geometry {
+                ewkb::GeometryT::GeometryCollection(ref original) => {
+                    assert_collection_eq(&round_trip, original)
+                }
+                _ => unreachable!(),
+            },
+            other => panic!("期望 GeometryCollection，得到 {other:?}"),
+        }
+    }
+}
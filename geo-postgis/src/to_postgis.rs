@@ -9,6 +9,10 @@ use postgis::ewkb;
 /// 请注意，PostGIS 数据库可以包含存储在其中的几何体的 SRID（空间参考系统标识符）。
 /// 在转换时，您应该指定几何体的 SRID，使用 `to_postgis_with_srid()`，
 /// 或者如果您的数据是标准的 WGS84，则使用 `to_postgis_wgs84()`。
+///
+/// 对于多几何体和 [`GeometryCollection`]，SRID 会被设置到集合本身以及其中的每个成员上，
+/// 嵌套的几何集合也会被递归转换。由于 PostGIS 没有对应的类型，
+/// [`Line`] 被转换为 `LineString`，`Rect` 和 `Triangle` 被转换为 `Polygon`。
 pub trait ToPostgis<T> {
     /// 使用提供的 SRID 将此几何体转换为 PostGIS 类型。
     fn to_postgis_with_srid(&self, srid: Option<i32>) -> T;
@@ -0,0 +1,110 @@
+use crate::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+/// 直接通过特征接口计算几何体的边界矩形，无需先转换为具体的几何类型。
+///
+/// 以 `(min_x, min_y, max_x, max_y)` 的形式返回。如果几何体不包含任何坐标（例如空点或空的几何集合），
+/// 返回 `None`。
+///
+/// 每个坐标只被访问一次，且只读取前两个维度，因此对于 WKB 包装器等实现，
+/// 可以在不完全物化几何体的情况下计算其范围。
+///
+/// # 示例
+///
+/// ```
+/// use geo_traits::bounding_rect;
+/// use geo_types::{line_string, point, Geometry, GeometryCollection};
+///
+/// let line_string = line_string![(x: 1.0, y: 5.0), (x: -2.0, y: 3.0)];
+/// assert_eq!(bounding_rect(&line_string), Some((-2.0, 3.0, 1.0, 5.0)));
+///
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::LineString(line_string),
+///     Geometry::Point(point!(x: 4.0, y: 0.0)),
+/// ]);
+/// assert_eq!(bounding_rect(&collection), Some((-2.0, 0.0, 4.0, 5.0)));
+///
+/// let empty = GeometryCollection::<f64>::new_from(vec![]);
+/// assert_eq!(bounding_rect(&empty), None);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bounding_rect<G>(geometry: &G) -> Option<(G::T, G::T, G::T, G::T)>
+where
+    G: GeometryTrait,
+    G::T: Copy + PartialOrd,
+{
+    let mut bounds = Bounds(None);
+    bounds.add_geometry(geometry);
+    bounds.0
+}
+
+/// 累计的 `(min_x, min_y, max_x, max_y)`。
+struct Bounds<T>(Option<(T, T, T, T)>);
+
+impl<T: Copy + PartialOrd> Bounds<T> {
+    fn add_coord(&mut self, coord: &impl CoordTrait<T = T>) {
+        let (x, y) = coord.x_y();
+        self.0 = Some(match self.0 {
+            None => (x, y, x, y),
+            Some((min_x, min_y, max_x, max_y)) => (
+                if x < min_x { x } else { min_x },
+                if y < min_y { y } else { min_y },
+                if x > max_x { x } else { max_x },
+                if y > max_y { y } else { max_y },
+            ),
+        });
+    }
+
+    fn add_point(&mut self, point: &impl PointTrait<T = T>) {
+        if let Some(coord) = point.coord() {
+            self.add_coord(&coord);
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &impl LineStringTrait<T = T>) {
+        line_string
+            .coords()
+            .for_each(|coord| self.add_coord(&coord));
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = T>) {
+        // 内环位于外环之内，因此只需要外环
+        if let Some(exterior) = polygon.exterior() {
+            self.add_line_string(&exterior);
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = T>) {
+        match geometry.as_type() {
+            GeometryType::Point(point) => self.add_point(point),
+            GeometryType::LineString(line_string) => self.add_line_string(line_string),
+            GeometryType::Polygon(polygon) => self.add_polygon(polygon),
+            GeometryType::MultiPoint(multi_point) => multi_point
+                .points()
+                .for_each(|point| self.add_point(&point)),
+            GeometryType::MultiLineString(multi_line_string) => multi_line_string
+                .line_strings()
+                .for_each(|line_string| self.add_line_string(&line_string)),
+            GeometryType::MultiPolygon(multi_polygon) => multi_polygon
+                .polygons()
+                .for_each(|polygon| self.add_polygon(&polygon)),
+            GeometryType::GeometryCollection(collection) => collection
+                .geometries()
+                .for_each(|geometry| self.add_geometry(&geometry)),
+            GeometryType::Rect(rect) => {
+                self.add_coord(&rect.min());
+                self.add_coord(&rect.max());
+            }
+            GeometryType::Triangle(triangle) => triangle
+                .coords()
+                .iter()
+                .for_each(|coord| self.add_coord(coord)),
+            GeometryType::Line(line) => {
+                line.coords().iter().for_each(|coord| self.add_coord(coord))
+            }
+        }
+    }
+}
@@ -47,6 +47,41 @@ pub trait CoordTrait {
     /// 另请参阅 [`nth()`](Self::nth)。
     fn nth_or_panic(&self, n: usize) -> Self::T;
 
+    /// 将坐标的各个分量依次（x、y，然后是更高的维度）复制到 `out` 中，返回写入的分量数量。
+    ///
+    /// 写入的数量为 [`dim()`](Self::dim) 的大小与 `out.len()` 中的较小值：
+    /// 如果 `out` 比坐标的维度数短，则只写入前 `out.len()` 个分量，其余分量被忽略；
+    /// 如果 `out` 更长，则 `out` 中多余的元素保持不变。
+    ///
+    /// 适用于将坐标读入固定大小的缓冲区而无需分配内存。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_traits::CoordTrait;
+    ///
+    /// let coord = (1.0_f64, 2.0_f64);
+    ///
+    /// let mut buffer = [0.0; 3];
+    /// assert_eq!(coord.copy_to_slice(&mut buffer), 2);
+    /// assert_eq!(buffer, [1.0, 2.0, 0.0]);
+    ///
+    /// let mut short = [0.0; 1];
+    /// assert_eq!(coord.copy_to_slice(&mut short), 1);
+    /// assert_eq!(short, [1.0]);
+    /// ```
+    fn copy_to_slice(&self, out: &mut [Self::T]) -> usize {
+        let count = self.dim().size().min(out.len());
+        for (n, value) in out[..count].iter_mut().enumerate() {
+            *value = match n {
+                0 => self.x(),
+                1 => self.y(),
+                _ => self.nth_or_panic(n),
+            };
+        }
+        count
+    }
+
     /// 访问坐标元组的第n个（从0开始）元素。
     /// 如果 n >= DIMENSION，可能会恐慌。
     ///
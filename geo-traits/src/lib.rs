@@ -14,6 +14,7 @@
 
 #![deny(missing_docs)]
 
+pub use bounding_rect::bounding_rect;
 pub use coord::{CoordTrait, UnimplementedCoord};
 pub use dimension::Dimensions;
 pub use geometry::{GeometryTrait, GeometryType, UnimplementedGeometry};
@@ -28,6 +29,8 @@ pub use polygon::{PolygonTrait, UnimplementedPolygon};
 pub use rect::{RectTrait, UnimplementedRect};
 pub use triangle::{TriangleTrait, UnimplementedTriangle};
 
+// 边界矩形模块
+mod bounding_rect;
 // 坐标模块
 mod coord;
 // 维度模块
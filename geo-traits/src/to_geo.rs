@@ -212,7 +212,36 @@ impl<T: CoordNum, G: TriangleTrait<T = T>> ToGeoTriangle<T> for G {
 
 /// 将任何几何体转换为[`Geometry`]。
 ///
-/// 仅保留前两个维度。
+/// 根据 [`GeometryTrait::as_type`] 分派，转换所有几何类型，包括 `Rect`、`Line`、`Triangle`，
+/// 并递归转换 `GeometryCollection`。因此可以将任何实现了这些特征的数据源（例如 WKB 读取器）
+/// 桥接到 `geo` 的算法。
+///
+/// 仅保留前两个维度：Z 和 M 值会被直接丢弃，不会报错，
+/// 因此转换后的几何体与其在 XY 平面上的投影相同。
+///
+/// # 示例
+///
+/// ```
+/// use geo_traits::to_geo::ToGeoGeometry;
+/// use geo_traits::GeometryTrait;
+/// use geo_types::{coord, point, Geometry, GeometryCollection, Rect, Triangle};
+///
+/// fn bridge<G: GeometryTrait<T = f64>>(geometry: &G) -> Geometry<f64> {
+///     geometry.to_geometry()
+/// }
+///
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Point(point!(x: 1.0, y: 2.0)),
+///     Geometry::Rect(Rect::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 1.0, y: 1.0 })),
+///     Geometry::Triangle(Triangle::from([(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)])),
+///     Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+/// ]);
+///
+/// assert_eq!(
+///     bridge(&collection),
+///     Geometry::GeometryCollection(collection.clone())
+/// );
+/// ```
 pub trait ToGeoGeometry<T: CoordNum> {
     /// 转换为geo_types [`Geometry`]。
     ///
@@ -52,6 +52,24 @@ impl<T: CoordNum> From<[T; 2]> for Coord<T> {
     }
 }
 
+/// 从三维数组转换，丢弃 z 分量。
+///
+/// ```
+/// use geo_types::{coord, Coord};
+///
+/// let c: Coord<f64> = [1., 2., 3.].into();
+/// assert_eq!(c, coord! { x: 1., y: 2. });
+/// ```
+impl<T: CoordNum> From<[T; 3]> for Coord<T> {
+    #[inline]
+    fn from(coords: [T; 3]) -> Self {
+        coord! {
+            x: coords[0],
+            y: coords[1],
+        }
+    }
+}
+
 // 从 Point 实现 From trait
 impl<T: CoordNum> From<Point<T>> for Coord<T> {
     #[inline]
@@ -79,6 +97,21 @@ impl<T: CoordNum> From<Coord<T>> for [T; 2] {
     }
 }
 
+/// 转换为三维数组，z 分量为零。
+///
+/// ```
+/// use geo_types::coord;
+///
+/// let xyz: [f64; 3] = coord! { x: 1., y: 2. }.into();
+/// assert_eq!(xyz, [1., 2., 0.]);
+/// ```
+impl<T: CoordNum> From<Coord<T>> for [T; 3] {
+    #[inline]
+    fn from(coord: Coord<T>) -> Self {
+        [coord.x, coord.y, T::zero()]
+    }
+}
+
 impl<T: CoordNum> Coord<T> {
     /// 返回包含坐标的x/水平和y/垂直分量的元组。
     ///
@@ -100,6 +133,25 @@ impl<T: CoordNum> Coord<T> {
     pub fn x_y(&self) -> (T, T) {
         (self.x, self.y)
     }
+
+    /// 返回包含坐标的x/水平和y/垂直分量的数组。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::coord;
+    ///
+    /// let c = coord! {
+    ///     x: 40.02f64,
+    ///     y: 116.34,
+    /// };
+    ///
+    /// assert_eq!(c.to_array(), [40.02, 116.34]);
+    /// ```
+    #[inline]
+    pub fn to_array(&self) -> [T; 2] {
+        [self.x, self.y]
+    }
 }
 
 use core::ops::{Add, Div, Mul, Neg, Sub};
@@ -289,6 +341,29 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> Coord<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 对于经度和纬度需要不同容差的地理数据（例如比较重投影后的几何图形）很有用。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::coord;
+    ///
+    /// let a = coord! { x: 10.0, y: 50.0 };
+    /// let b = coord! { x: 10.05, y: 50.001 };
+    ///
+    /// assert!(a.abs_diff_eq_xy(&b, 0.1, 0.01));
+    /// assert!(!a.abs_diff_eq_xy(&b, 0.01, 0.01));
+    /// ```
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon_x) && self.y.abs_diff_eq(&other.y, epsilon_y)
+    }
+}
+
 // 实现 RelativeEq trait
 #[cfg(any(feature = "approx", test))]
 impl<T: CoordNum + RelativeEq> RelativeEq for Coord<T>
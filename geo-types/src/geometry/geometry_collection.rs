@@ -1,5 +1,6 @@
-use crate::{CoordNum, Geometry};
+use crate::{CoordNum, Geometry, LineString, Point, Polygon};
 
+use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
 #[cfg(any(feature = "approx", test))]
@@ -106,6 +107,131 @@ impl<T: CoordNum> GeometryCollection<T> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// 从任意可以转换为 [`Geometry`] 的值创建 GeometryCollection，无需对每个值手动调用 `.into()`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{line_string, point, Geometry, GeometryCollection, Rect};
+    ///
+    /// let gc = GeometryCollection::from_geometries([
+    ///     Geometry::from(point!(x: 1.0, y: 1.0)),
+    ///     line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)].into(),
+    ///     Rect::new((0.0, 0.0), (1.0, 1.0)).into(),
+    /// ]);
+    /// assert_eq!(gc.len(), 3);
+    /// ```
+    pub fn from_geometries(geometries: impl IntoIterator<Item = impl Into<Geometry<T>>>) -> Self {
+        geometries.into_iter().collect()
+    }
+
+    /// 从一组 [`Point`] 创建 GeometryCollection。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry, GeometryCollection};
+    ///
+    /// let gc = GeometryCollection::from_points([point!(x: 1.0, y: 1.0), point!(x: 2.0, y: 2.0)]);
+    /// assert_eq!(gc[1], Geometry::Point(point!(x: 2.0, y: 2.0)));
+    /// ```
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Self {
+        Self::from_geometries(points)
+    }
+
+    /// 从一组 [`LineString`] 创建 GeometryCollection。
+    pub fn from_line_strings(line_strings: impl IntoIterator<Item = LineString<T>>) -> Self {
+        Self::from_geometries(line_strings)
+    }
+
+    /// 从一组 [`Polygon`] 创建 GeometryCollection。
+    pub fn from_polygons(polygons: impl IntoIterator<Item = Polygon<T>>) -> Self {
+        Self::from_geometries(polygons)
+    }
+
+    /// 返回集合中所有 [`Point`] 的迭代器。
+    ///
+    /// 展平规则：[`Point`] 成员直接产生，[`MultiPoint`](crate::MultiPoint) 成员展开为其各个点，
+    /// 嵌套的 GeometryCollection 按顺序递归展开。其他类型的成员被跳过。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{line_string, point, Geometry, GeometryCollection, MultiPoint};
+    ///
+    /// let gc = GeometryCollection::from_geometries([
+    ///     Geometry::from(point!(x: 1.0, y: 1.0)),
+    ///     line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)].into(),
+    ///     MultiPoint::from(vec![(2.0, 2.0), (3.0, 3.0)]).into(),
+    /// ]);
+    /// let points: Vec<_> = gc.points().collect();
+    /// assert_eq!(points, vec![&point!(x: 1.0, y: 1.0), &point!(x: 2.0, y: 2.0), &point!(x: 3.0, y: 3.0)]);
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = &Point<T>> {
+        self.members(|geometry| match geometry {
+            Geometry::Point(point) => core::slice::from_ref(point),
+            Geometry::MultiPoint(multi_point) => &multi_point.0,
+            _ => &[],
+        })
+    }
+
+    /// 返回集合中所有 [`LineString`] 的迭代器。
+    ///
+    /// 展平规则：[`LineString`] 成员直接产生，[`MultiLineString`](crate::MultiLineString)
+    /// 成员展开为其各个线串，嵌套的 GeometryCollection 按顺序递归展开。
+    /// 其他类型的成员（包括 [`Line`](crate::Line)）被跳过。
+    pub fn line_strings(&self) -> impl Iterator<Item = &LineString<T>> {
+        self.members(|geometry| match geometry {
+            Geometry::LineString(line_string) => core::slice::from_ref(line_string),
+            Geometry::MultiLineString(multi_line_string) => &multi_line_string.0,
+            _ => &[],
+        })
+    }
+
+    /// 返回集合中所有 [`Polygon`] 的迭代器。
+    ///
+    /// 展平规则：[`Polygon`] 成员直接产生，[`MultiPolygon`](crate::MultiPolygon)
+    /// 成员展开为其各个多边形，嵌套的 GeometryCollection 按顺序递归展开。
+    /// 其他类型的成员（包括 [`Rect`](crate::Rect) 和 [`Triangle`](crate::Triangle)）被跳过。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{point, polygon, Geometry, GeometryCollection, MultiPolygon};
+    ///
+    /// let square = polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 1.0)];
+    /// let triangle = polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 0.0, y: 1.0)];
+    ///
+    /// let nested = GeometryCollection::from_geometries([MultiPolygon::new(vec![triangle.clone()])]);
+    /// let gc = GeometryCollection::from_geometries([
+    ///     Geometry::from(square.clone()),
+    ///     point!(x: 1.0, y: 1.0).into(),
+    ///     Geometry::GeometryCollection(nested),
+    /// ]);
+    /// assert_eq!(gc.polygons().collect::<Vec<_>>(), vec![&square, &triangle]);
+    /// ```
+    pub fn polygons(&self) -> impl Iterator<Item = &Polygon<T>> {
+        self.members(|geometry| match geometry {
+            Geometry::Polygon(polygon) => core::slice::from_ref(polygon),
+            Geometry::MultiPolygon(multi_polygon) => &multi_polygon.0,
+            _ => &[],
+        })
+    }
+
+    /// 按 `leaf` 选取每个成员中的几何体，并递归展开嵌套的集合。
+    fn members<'a, M>(
+        &'a self,
+        leaf: fn(&Geometry<T>) -> &[M],
+    ) -> Box<dyn Iterator<Item = &'a M> + 'a> {
+        Box::new(self.0.iter().flat_map(move |geometry| {
+            let nested = match geometry {
+                Geometry::GeometryCollection(collection) => Some(collection.members(leaf)),
+                _ => None,
+            };
+            leaf(geometry).iter().chain(nested.into_iter().flatten())
+        }))
+    }
 }
 
 /// **请勿使用！** 自 0.7.5 版本起已废弃。
@@ -323,11 +449,27 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> GeometryCollection<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+
+        let mut zipper = self.iter().zip(other.iter());
+        zipper.all(|(lhs, rhs)| lhs.abs_diff_eq_xy(rhs, epsilon_x, epsilon_y))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
 
-    use crate::{GeometryCollection, Point};
+    use crate::{Geometry, GeometryCollection, LineString, Point, Polygon};
 
     #[test]
     fn from_vec() {
@@ -335,4 +477,72 @@ mod tests {
         let p = Point::try_from(gc[0].clone()).unwrap();
         assert_eq!(p.y(), 2);
     }
+
+    #[test]
+    fn from_typed_iterators() {
+        let gc = GeometryCollection::from_points((0..3).map(|i| Point::new(i, i)));
+        assert_eq!(gc.len(), 3);
+        assert_eq!(gc[2], Geometry::Point(Point::new(2, 2)));
+
+        let line_string = LineString::from(vec![(0, 0), (1, 1)]);
+        let gc = GeometryCollection::from_line_strings(vec![line_string.clone()]);
+        assert_eq!(gc[0], Geometry::LineString(line_string.clone()));
+
+        let polygon = Polygon::new(LineString::from(vec![(0, 0), (1, 0), (0, 1)]), vec![]);
+        let gc = GeometryCollection::from_polygons(Some(polygon.clone()));
+        assert_eq!(gc[0], Geometry::Polygon(polygon.clone()));
+
+        let gc = GeometryCollection::from_geometries([
+            Geometry::from(Point::new(1, 2)),
+            line_string.clone().into(),
+            polygon.clone().into(),
+        ]);
+        assert_eq!(
+            gc,
+            GeometryCollection::new_from(vec![
+                Geometry::Point(Point::new(1, 2)),
+                Geometry::LineString(line_string),
+                Geometry::Polygon(polygon),
+            ])
+        );
+
+        let empty = GeometryCollection::<i32>::from_points(vec![]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn typed_members() {
+        use crate::{MultiLineString, MultiPoint, MultiPolygon, Rect};
+
+        let line_string = LineString::from(vec![(0, 0), (1, 1)]);
+        let polygon = Polygon::new(LineString::from(vec![(0, 0), (1, 0), (0, 1)]), vec![]);
+        let nested = GeometryCollection::from_geometries([
+            Geometry::from(Point::new(5, 5)),
+            MultiLineString::new(vec![line_string.clone(), line_string.clone()]).into(),
+        ]);
+        let gc = GeometryCollection::from_geometries([
+            Geometry::from(Point::new(1, 1)),
+            MultiPoint::from(vec![Point::new(2, 2), Point::new(3, 3)]).into(),
+            line_string.clone().into(),
+            Geometry::GeometryCollection(nested),
+            MultiPolygon::new(vec![polygon.clone()]).into(),
+            Rect::new((0, 0), (1, 1)).into(),
+        ]);
+
+        assert_eq!(
+            gc.points().copied().collect::<Vec<_>>(),
+            vec![
+                Point::new(1, 1),
+                Point::new(2, 2),
+                Point::new(3, 3),
+                Point::new(5, 5)
+            ]
+        );
+        assert_eq!(gc.line_strings().count(), 3);
+        assert!(gc.line_strings().all(|ls| ls == &line_string));
+        assert_eq!(gc.polygons().collect::<Vec<_>>(), vec![&polygon]);
+
+        let empty = GeometryCollection::<i32>::new_from(vec![]);
+        assert_eq!(empty.points().count(), 0);
+    }
 }
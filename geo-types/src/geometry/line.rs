@@ -223,6 +223,19 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Line<T> {
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> Line<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        self.start
+            .abs_diff_eq_xy(&other.start, epsilon_x, epsilon_y)
+            && self.end.abs_diff_eq_xy(&other.end, epsilon_x, epsilon_y)
+    }
+}
+
 #[cfg(any(
     feature = "rstar_0_8",
     feature = "rstar_0_9",
@@ -338,6 +338,47 @@ impl<T: CoordNum> LineString<T> {
     pub fn is_closed(&self) -> bool {
         self.0.first() == self.0.last()
     }
+
+    /// 检查linestring是否构成一个环；即它是闭合的，并且至少有4个坐标。
+    ///
+    /// 与[`is_closed`](Self::is_closed)不同，空的[`LineString`]以及只有一到三个坐标的
+    /// 闭合[`LineString`]（例如`(0 0, 1 1, 0 0)`）不被视为环，因为它们不围成任何区域。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{line_string, LineString};
+    ///
+    /// let ring = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.), (x: 0., y: 0.)];
+    /// assert!(ring.is_ring());
+    ///
+    /// let open = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)];
+    /// assert!(!open.is_ring());
+    ///
+    /// let empty: LineString<f64> = line_string![];
+    /// assert!(empty.is_closed());
+    /// assert!(!empty.is_ring());
+    /// ```
+    pub fn is_ring(&self) -> bool {
+        self.0.len() >= 4 && self.is_closed()
+    }
+
+    /// 如果[`LineString`]是一个环（参见[`is_ring`](Self::is_ring)），返回`Some(self)`，否则返回`None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let ring = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.), (x: 0., y: 0.)];
+    /// assert_eq!(ring.as_ring(), Some(&ring));
+    ///
+    /// let open = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)];
+    /// assert_eq!(open.as_ring(), None);
+    /// ```
+    pub fn as_ring(&self) -> Option<&LineString<T>> {
+        self.is_ring().then_some(self)
+    }
 }
 
 /// 将[`Point`]类对象的[`Vec`]转换为[`LineString`]。
@@ -489,6 +530,21 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for LineString<T> {
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> LineString<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+        let mut coords_zipper = self.coords().zip(other.coords());
+        coords_zipper.all(|(lhs, rhs)| lhs.abs_diff_eq_xy(rhs, epsilon_x, epsilon_y))
+    }
+}
+
 #[cfg(any(
     feature = "rstar_0_8",
     feature = "rstar_0_9",
@@ -572,6 +628,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_is_ring() {
+        let closed_triangle = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]);
+        assert!(closed_triangle.is_ring());
+        assert_eq!(closed_triangle.as_ring(), Some(&closed_triangle));
+
+        // 闭合但少于4个坐标
+        let degenerate = LineString::from(vec![(0., 0.), (1., 1.), (0., 0.)]);
+        assert!(degenerate.is_closed());
+        assert!(!degenerate.is_ring());
+        assert_eq!(degenerate.as_ring(), None);
+
+        let single = LineString::from(vec![(0., 0.)]);
+        assert!(single.is_closed());
+        assert!(!single.is_ring());
+
+        let open = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)]);
+        assert!(!open.is_ring());
+        assert_eq!(open.as_ring(), None);
+    }
+
     #[test]
     fn test_abs_diff_eq() {
         let delta = 1e-6;
@@ -600,6 +677,21 @@ mod test {
         assert!(ls.abs_diff_ne(&ls_oversized, 1.));
     }
 
+    #[test]
+    fn test_abs_diff_eq_xy() {
+        let ls: LineString = vec![(10., 50.), (11., 51.)].into();
+
+        // x 方向偏差较大，y 方向偏差较小
+        let shifted: LineString = vec![(10.05, 50.001), (11.05, 51.001)].into();
+        assert!(ls.abs_diff_eq_xy(&shifted, 0.1, 0.01));
+        assert!(!ls.abs_diff_eq_xy(&shifted, 0.01, 0.01));
+        assert!(!ls.abs_diff_eq_xy(&shifted, 0.1, 0.0001));
+
+        // 长度不同则不相等
+        let shorter: LineString = vec![(10., 50.)].into();
+        assert!(!ls.abs_diff_eq_xy(&shorter, 1., 1.));
+    }
+
     #[test]
     fn test_relative_eq() {
         let delta = 1e-6;
@@ -30,6 +30,7 @@ use crate::{CoordNum, Error};
 use approx::{AbsDiffEq, RelativeEq};
 use core::any::type_name;
 use core::convert::TryFrom;
+use core::fmt;
 
 /// 表示任何可能的几何类型的枚举。
 ///
@@ -61,6 +62,59 @@ pub enum Geometry<T: CoordNum = f64> {
     Triangle(Triangle<T>),
 }
 
+/// [`Geometry`] 的变体种类，不包含任何坐标数据。
+///
+/// 可以通过 [`Geometry::kind`] 获取，便于记录日志、统计指标，或作为按几何类型分派的查找表的键。
+///
+/// # 示例
+///
+/// ```
+/// use geo_types::{point, Geometry, GeometryKind};
+///
+/// let geometry: Geometry = point!(x: 1.0, y: 1.0).into();
+/// assert_eq!(geometry.kind(), GeometryKind::Point);
+/// assert_eq!(geometry.kind().name(), "Point");
+/// assert_eq!(GeometryKind::MultiLineString.to_string(), "MultiLineString");
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GeometryKind {
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle,
+}
+
+impl GeometryKind {
+    /// 几何类型的名称，与变体的名称相同，例如 `"LineString"`。
+    pub fn name(&self) -> &'static str {
+        match self {
+            GeometryKind::Point => "Point",
+            GeometryKind::Line => "Line",
+            GeometryKind::LineString => "LineString",
+            GeometryKind::Polygon => "Polygon",
+            GeometryKind::MultiPoint => "MultiPoint",
+            GeometryKind::MultiLineString => "MultiLineString",
+            GeometryKind::MultiPolygon => "MultiPolygon",
+            GeometryKind::GeometryCollection => "GeometryCollection",
+            GeometryKind::Rect => "Rect",
+            GeometryKind::Triangle => "Triangle",
+        }
+    }
+}
+
+impl fmt::Display for GeometryKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 // 为各种几何类型实现From trait
 impl<T: CoordNum> From<Point<T>> for Geometry<T> {
     fn from(x: Point<T>) -> Self {
@@ -118,6 +172,34 @@ impl<T: CoordNum> From<Triangle<T>> for Geometry<T> {
 }
 
 impl<T: CoordNum> Geometry<T> {
+    /// 返回这个Geometry的变体种类，无需匹配整个枚举或克隆。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{line_string, Geometry, GeometryCollection, GeometryKind};
+    ///
+    /// let line_string: Geometry = line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into();
+    /// assert_eq!(line_string.kind(), GeometryKind::LineString);
+    ///
+    /// let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![line_string]));
+    /// assert_eq!(collection.kind(), GeometryKind::GeometryCollection);
+    /// ```
+    pub fn kind(&self) -> GeometryKind {
+        match self {
+            Geometry::Point(_) => GeometryKind::Point,
+            Geometry::Line(_) => GeometryKind::Line,
+            Geometry::LineString(_) => GeometryKind::LineString,
+            Geometry::Polygon(_) => GeometryKind::Polygon,
+            Geometry::MultiPoint(_) => GeometryKind::MultiPoint,
+            Geometry::MultiLineString(_) => GeometryKind::MultiLineString,
+            Geometry::MultiPolygon(_) => GeometryKind::MultiPolygon,
+            Geometry::GeometryCollection(_) => GeometryKind::GeometryCollection,
+            Geometry::Rect(_) => GeometryKind::Rect,
+            Geometry::Triangle(_) => GeometryKind::Triangle,
+        }
+    }
+
     /// 如果这个Geometry是一个Point，则返回该Point，否则返回None。
     ///
     /// # 示例
@@ -357,3 +439,42 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Geometry<T> {
         }
     }
 }
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> Geometry<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        match (self, other) {
+            (Geometry::Point(g1), Geometry::Point(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (Geometry::Line(g1), Geometry::Line(g2)) => g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y),
+            (Geometry::LineString(g1), Geometry::LineString(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (Geometry::Polygon(g1), Geometry::Polygon(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (Geometry::MultiPoint(g1), Geometry::MultiPoint(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (Geometry::MultiLineString(g1), Geometry::MultiLineString(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (Geometry::MultiPolygon(g1), Geometry::MultiPolygon(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (Geometry::GeometryCollection(g1), Geometry::GeometryCollection(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (Geometry::Rect(g1), Geometry::Rect(g2)) => g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y),
+            (Geometry::Triangle(g1), Geometry::Triangle(g2)) => {
+                g1.abs_diff_eq_xy(g2, epsilon_x, epsilon_y)
+            }
+            (_, _) => false,
+        }
+    }
+}
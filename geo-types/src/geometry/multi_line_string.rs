@@ -216,6 +216,22 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> MultiLineString<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+
+        let mut mls_zipper = self.iter().zip(other.iter());
+        mls_zipper.all(|(lhs, rhs)| lhs.abs_diff_eq_xy(rhs, epsilon_x, epsilon_y))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -48,6 +48,13 @@ impl<T: CoordNum> From<[T; 2]> for Point<T> {
     }
 }
 
+/// 从三维数组转换，丢弃 z 分量。
+impl<T: CoordNum> From<[T; 3]> for Point<T> {
+    fn from(coords: [T; 3]) -> Self {
+        Point::new(coords[0], coords[1])
+    }
+}
+
 impl<T: CoordNum> From<Point<T>> for (T, T) {
     fn from(point: Point<T>) -> Self {
         point.0.into()
@@ -60,6 +67,13 @@ impl<T: CoordNum> From<Point<T>> for [T; 2] {
     }
 }
 
+/// 转换为三维数组，z 分量为零。
+impl<T: CoordNum> From<Point<T>> for [T; 3] {
+    fn from(point: Point<T>) -> Self {
+        point.0.into()
+    }
+}
+
 impl<T: CoordNum> Point<T> {
     /// 创建一个新点。
     ///
@@ -188,6 +202,22 @@ impl<T: CoordNum> Point<T> {
     pub fn x_y(self) -> (T, T) {
         (self.0.x, self.0.y)
     }
+
+    /// 返回包含点的 x/水平 和 y/垂直 分量的数组。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::Point;
+    ///
+    /// let p = Point::new(1.234, 2.345);
+    ///
+    /// assert_eq!(p.to_array(), [1.234, 2.345]);
+    /// ```
+    pub fn to_array(self) -> [T; 2] {
+        self.0.to_array()
+    }
+
     /// 返回点的经度/水平分量。
     ///
     /// # 示例
@@ -585,6 +615,27 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> Point<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::Point;
+    ///
+    /// let a = Point::new(10.0, 50.0);
+    /// let b = Point::new(10.05, 50.001);
+    ///
+    /// assert!(a.abs_diff_eq_xy(&b, 0.1, 0.01));
+    /// assert!(!a.abs_diff_eq_xy(&b, 0.1, 0.0001));
+    /// ```
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        self.0.abs_diff_eq_xy(&other.0, epsilon_x, epsilon_y)
+    }
+}
+
 #[cfg(feature = "rstar_0_8")]
 // These are required for rstar RTree
 impl<T> ::rstar_0_8::Point for Point<T>
@@ -758,6 +809,21 @@ mod test {
         assert!(p.abs_diff_ne(&p_inf, 1e-2));
     }
 
+    #[test]
+    fn test_array_conversions() {
+        let p = Point::new(1, 2);
+
+        let xy: [i32; 2] = p.into();
+        assert_eq!(xy, [1, 2]);
+        assert_eq!(p.to_array(), xy);
+        assert_eq!(Point::from(xy), p);
+
+        let xyz: [i32; 3] = p.into();
+        assert_eq!(xyz, [1, 2, 0]);
+        assert_eq!(Point::from([1, 2, 3]), p);
+        assert_eq!(Coord::from([1, 2, 3]), p.0);
+    }
+
     #[test]
     fn test_relative_eq() {
         let delta = 1e-6;
@@ -592,6 +592,28 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Polygon<T> {
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> Polygon<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        if !self
+            .exterior
+            .abs_diff_eq_xy(&other.exterior, epsilon_x, epsilon_y)
+        {
+            return false;
+        }
+
+        if self.interiors.len() != other.interiors.len() {
+            return false;
+        }
+        let mut zipper = self.interiors.iter().zip(other.interiors.iter());
+        zipper.all(|(lhs, rhs)| lhs.abs_diff_eq_xy(rhs, epsilon_x, epsilon_y))
+    }
+}
+
 #[cfg(any(
     feature = "rstar_0_8",
     feature = "rstar_0_9",
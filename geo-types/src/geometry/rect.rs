@@ -333,6 +333,54 @@ impl<T: CoordNum> Rect<T> {
         ]
     }
 
+    /// 检查坐标是否位于矩形的内部。
+    ///
+    /// 边界是不包含的：位于矩形边上的坐标返回`false`。这与`geo`中
+    /// `Contains<Coord>`的语义一致，但不需要导入该trait。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+    ///
+    /// assert!(rect.contains_coord(&coord! { x: 2., y: 2. }));
+    /// // 边界上的坐标不被包含
+    /// assert!(!rect.contains_coord(&coord! { x: 0., y: 2. }));
+    /// assert!(!rect.contains_coord(&coord! { x: 5., y: 2. }));
+    /// ```
+    #[inline]
+    pub fn contains_coord(&self, coord: &Coord<T>) -> bool {
+        coord.x > self.min.x && coord.x < self.max.x && coord.y > self.min.y && coord.y < self.max.y
+    }
+
+    /// 检查`other`是否完全位于矩形之内。
+    ///
+    /// 边界是包含的：`other`可以与矩形共享边，矩形总是包含它自身。这与`geo`中
+    /// `Contains<Rect>`的语义一致，但不需要导入该trait。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+    ///
+    /// assert!(rect.contains_rect(&Rect::new(coord! { x: 1., y: 1. }, coord! { x: 2., y: 2. })));
+    /// // 共享边界的矩形也被包含
+    /// assert!(rect.contains_rect(&Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 2. })));
+    /// assert!(rect.contains_rect(&rect));
+    /// assert!(!rect.contains_rect(&Rect::new(coord! { x: 3., y: 3. }, coord! { x: 5., y: 5. })));
+    /// ```
+    #[inline]
+    pub fn contains_rect(&self, other: &Rect<T>) -> bool {
+        self.min.x <= other.min.x
+            && self.max.x >= other.max.x
+            && self.min.y <= other.min.y
+            && self.max.y >= other.max.y
+    }
+
     fn assert_valid_bounds(&self) {
         if !self.has_valid_bounds() {
             panic!("{}", RECT_INVALID_BOUNDS_ERROR);
@@ -453,6 +501,18 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> Rect<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        self.min.abs_diff_eq_xy(&other.min, epsilon_x, epsilon_y)
+            && self.max.abs_diff_eq_xy(&other.max, epsilon_x, epsilon_y)
+    }
+}
+
 #[deprecated(
     since = "0.6.2",
     note = "使用 `Rect::new`，因为 `Rect::try_new` 不会出错"
@@ -518,4 +578,23 @@ mod test {
             Coord::from((0., 0.))
         );
     }
+
+    #[test]
+    fn rect_contains() {
+        let rect = Rect::new((0, 0), (10, 10));
+        assert!(rect.contains_coord(&coord! { x: 5, y: 5 }));
+        assert!(!rect.contains_coord(&coord! { x: 10, y: 5 }));
+        assert!(!rect.contains_coord(&coord! { x: 5, y: 0 }));
+        assert!(!rect.contains_coord(&coord! { x: -1, y: 5 }));
+
+        assert!(rect.contains_rect(&Rect::new((2, 2), (8, 8))));
+        assert!(rect.contains_rect(&Rect::new((0, 0), (10, 5))));
+        assert!(!rect.contains_rect(&Rect::new((-1, 2), (8, 8))));
+        assert!(!rect.contains_rect(&Rect::new((2, 2), (8, 11))));
+
+        // 退化的矩形（点）
+        let point_rect = Rect::new((5, 5), (5, 5));
+        assert!(rect.contains_rect(&point_rect));
+        assert!(!point_rect.contains_coord(&coord! { x: 5, y: 5 }));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{polygon, Coord, CoordNum, Line, Polygon};
+use crate::{polygon, Coord, CoordFloat, CoordNum, Line, Polygon};
 
 #[cfg(any(feature = "approx", test))]
 use approx::{AbsDiffEq, RelativeEq};
@@ -58,6 +58,56 @@ impl<T: CoordNum> Triangle<T> {
     }
 }
 
+impl<T: CoordFloat> Triangle<T> {
+    /// 计算坐标相对于三角形三个顶点的重心坐标 `[w0, w1, w2]`，三个权重之和为 1，
+    /// 且 `w0 * self.0 + w1 * self.1 + w2 * self.2` 等于 `coord`。
+    ///
+    /// 无论坐标是否位于三角形内，都会返回权重：当且仅当三个权重都不小于零时，坐标位于三角形内部或边界上。
+    /// 常用于在不规则三角网（TIN）上对顶点的属性值做线性插值。
+    ///
+    /// 如果三角形退化（三个顶点共线或重合），返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use geo_types::{coord, Triangle};
+    ///
+    /// let triangle = Triangle::new(
+    ///     coord! { x: 0., y: 0. },
+    ///     coord! { x: 4., y: 0. },
+    ///     coord! { x: 0., y: 4. },
+    /// );
+    ///
+    /// let weights = triangle.barycentric(coord! { x: 1., y: 2. }).unwrap();
+    /// assert_eq!(weights, [0.25, 0.25, 0.5]);
+    ///
+    /// // 在顶点上插值高程
+    /// let elevation = weights[0] * 10. + weights[1] * 20. + weights[2] * 30.;
+    /// assert_eq!(elevation, 22.5);
+    ///
+    /// // 三角形外的坐标有负的权重
+    /// let weights = triangle.barycentric(coord! { x: 4., y: 4. }).unwrap();
+    /// assert!(weights.iter().any(|w| *w < 0.));
+    ///
+    /// let degenerate = Triangle::new(
+    ///     coord! { x: 0., y: 0. },
+    ///     coord! { x: 1., y: 1. },
+    ///     coord! { x: 2., y: 2. },
+    /// );
+    /// assert_eq!(degenerate.barycentric(coord! { x: 1., y: 1. }), None);
+    /// ```
+    pub fn barycentric(&self, coord: Coord<T>) -> Option<[T; 3]> {
+        let Triangle(a, b, c) = *self;
+        let denominator = (b.y - c.y) * (a.x - c.x) + (c.x - b.x) * (a.y - c.y);
+        if denominator == T::zero() {
+            return None;
+        }
+        let w0 = ((b.y - c.y) * (coord.x - c.x) + (c.x - b.x) * (coord.y - c.y)) / denominator;
+        let w1 = ((c.y - a.y) * (coord.x - c.x) + (a.x - c.x) * (coord.y - c.y)) / denominator;
+        Some([w0, w1, T::one() - w0 - w1])
+    }
+}
+
 impl<IC: Into<Coord<T>> + Copy, T: CoordNum> From<[IC; 3]> for Triangle<T> {
     fn from(array: [IC; 3]) -> Self {
         Self(array[0].into(), array[1].into(), array[2].into())
@@ -149,3 +199,61 @@ where
         true
     }
 }
+
+#[cfg(any(feature = "approx", test))]
+impl<T: AbsDiffEq<Epsilon = T> + CoordNum> Triangle<T> {
+    /// 分别使用 x 轴和 y 轴上的绝对容差进行近似相等比较。
+    ///
+    /// 参见 [`Coord::abs_diff_eq_xy`](crate::Coord::abs_diff_eq_xy)。
+    #[inline]
+    pub fn abs_diff_eq_xy(&self, other: &Self, epsilon_x: T, epsilon_y: T) -> bool {
+        self.0.abs_diff_eq_xy(&other.0, epsilon_x, epsilon_y)
+            && self.1.abs_diff_eq_xy(&other.1, epsilon_x, epsilon_y)
+            && self.2.abs_diff_eq_xy(&other.2, epsilon_x, epsilon_y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn barycentric_reconstructs_coord() {
+        let triangle = Triangle::new(
+            coord! { x: 1., y: -2. },
+            coord! { x: 7., y: 3. },
+            coord! { x: -3., y: 5. },
+        );
+        for coord in [
+            coord! { x: 1., y: 2. },
+            coord! { x: 10., y: -10. },
+            triangle.1,
+        ] {
+            let [w0, w1, w2] = triangle.barycentric(coord).unwrap();
+            assert_relative_eq!(w0 + w1 + w2, 1.);
+            assert_relative_eq!(
+                triangle.0 * w0 + triangle.1 * w1 + triangle.2 * w2,
+                coord,
+                epsilon = 1e-12
+            );
+        }
+        assert_eq!(triangle.barycentric(triangle.1), Some([0., 1., 0.]));
+    }
+
+    #[test]
+    fn barycentric_on_edge() {
+        let triangle = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 2., y: 0. },
+            coord! { x: 0., y: 2. },
+        );
+        assert_eq!(
+            triangle.barycentric(coord! { x: 1., y: 0. }),
+            Some([0.5, 0.5, 0.])
+        );
+        let coincident = Triangle::new(triangle.0, triangle.0, triangle.0);
+        assert_eq!(coincident.barycentric(triangle.0), None);
+    }
+}
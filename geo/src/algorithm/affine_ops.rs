@@ -69,6 +69,11 @@ impl<T: CoordNum, M: MapCoordsInPlace<T> + MapCoords<T, T, Output = Self>> Affin
 ///
 /// `y' = dx + ey + yoff`
 ///
+/// 启用 `use-serde` 功能时，`AffineTransform` 被序列化为按上述顺序排列的六个系数组成的数组
+/// `[a, b, xoff, d, e, yoff]`，与 [`AffineTransform::new`] 的参数顺序相同（也与 Python `affine`
+/// 包的顺序相同）。注意它**不同于** GDAL 的 GeoTransform `[xoff, a, b, yoff, d, e]`
+/// 以及 ESRI world 文件的 `[a, d, b, e, xoff, yoff]` 顺序。最后一行总是 `[0, 0, 1]`，不会被序列化。
+///
 /// # 用法
 ///
 /// 提供了两种操作类型：构造和变异。**构造**函数创建一个*新的*变换，并用**现在时**表示：`scale()`、`translate()`、`rotate()` 和 `skew()`。
@@ -383,6 +388,30 @@ impl<T: CoordNum> From<(T, T, T, T, T, T)> for AffineTransform<T> {
     }
 }
 
+/// 序列化为六个系数 `[a, b, xoff, d, e, yoff]`。
+#[cfg(feature = "use-serde")]
+impl<T: CoordNum + serde::Serialize> serde::Serialize for AffineTransform<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [
+            self.a(),
+            self.b(),
+            self.xoff(),
+            self.d(),
+            self.e(),
+            self.yoff(),
+        ]
+        .serialize(serializer)
+    }
+}
+
+/// 从六个系数 `[a, b, xoff, d, e, yoff]` 反序列化。
+#[cfg(feature = "use-serde")]
+impl<'de, T: CoordNum + serde::Deserialize<'de>> serde::Deserialize<'de> for AffineTransform<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[T; 6]>::deserialize(deserializer).map(Self::from)
+    }
+}
+
 impl<U: CoordFloat> AffineTransform<U> {
     /// **创建**旋转的仿射变换，使用任意点作为中心。
     ///
@@ -625,4 +654,23 @@ mod tests {
 
         assert_eq!(point.affine_transform(&composed), Point::new(8., 0.));
     }
+
+    #[cfg(feature = "use-serde")]
+    #[test]
+    fn test_deserialize_coefficients() {
+        use serde::de::value::{Error, SeqDeserializer};
+        use serde::Deserialize;
+
+        let coefficients = [10.0, 0.0, 400_000.0, 0.0, -10.0, 500_000.0];
+        let transform = AffineTransform::<f64>::deserialize(SeqDeserializer::<_, Error>::new(
+            coefficients.into_iter(),
+        ))
+        .unwrap();
+        assert_eq!(transform, AffineTransform::from(coefficients));
+
+        let too_short = AffineTransform::<f64>::deserialize(SeqDeserializer::<_, Error>::new(
+            coefficients[..5].iter().copied(),
+        ));
+        assert!(too_short.is_err());
+    }
 }
@@ -34,6 +34,13 @@ where
 
 /// 几何形状的有符号和无符号的平面面积。
 ///
+/// [`signed_area`](Self::signed_area) 的符号由外环的绕行顺序决定：
+/// 外环逆时针时为正，顺时针时为负（假设 y 轴向上）。内环的面积总是从外环面积的绝对值中减去，
+/// 与内环自身的绕行顺序无关。对于包含多个成员的几何体，结果是各成员有符号面积之和，
+/// 因此方向不一致的成员会相互抵消。
+///
+/// 如需直接得到各个环的绕行顺序，请使用 [`RingWindingOrders`](crate::RingWindingOrders)。
+///
 /// # 示例
 ///
 /// ```
@@ -0,0 +1,138 @@
+use rstar::RTreeNum;
+
+use crate::{
+    BoundingRect, ConcaveHull, ConvexHull, GeoFloat, LineString, MultiPoint, Polygon, Rect,
+};
+
+/// 由一组点生成区域时使用的方法，参见 [`ToAoi`]。
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AoiMethod<T> {
+    /// 点的[凸包](ConvexHull)。
+    ConvexHull,
+    /// 点的轴对齐[边界矩形](BoundingRect)。
+    BoundingBox,
+    /// 以给定的 `concavity` 计算的[凹包](ConcaveHull)。
+    ConcaveHull(T),
+}
+
+/// 将一组点转换为一个感兴趣区域（area of interest）多边形。
+///
+/// 这只是对 [`ConvexHull`]、[`BoundingRect`] 和 [`ConcaveHull`] 的简单分派，
+/// 便于在一次调用中完成“把这些点变成一个区域”的常见操作。
+///
+/// 点数过少时，各方法的结果与底层算法一致：
+///
+/// - 空输入总是返回外环为空的多边形。
+/// - [`AoiMethod::BoundingBox`] 对任意非空输入都返回边界矩形，对单个点或共线的点，这个矩形的面积为零。
+/// - [`AoiMethod::ConvexHull`] 对单个点或共线的点返回退化的（面积为零的）多边形。
+/// - [`AoiMethod::ConcaveHull`] 在所有点都重合时返回空多边形，共线的点同样得到退化的多边形。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, AoiMethod, ToAoi};
+///
+/// let fixes = wkt!(MULTIPOINT(0. 0.,4. 1.,2. 5.,1. 2.));
+///
+/// assert_eq!(
+///     fixes.to_aoi(AoiMethod::BoundingBox),
+///     wkt!(POLYGON((0. 0.,0. 5.,4. 5.,4. 0.,0. 0.)))
+/// );
+/// assert_eq!(
+///     fixes.to_aoi(AoiMethod::ConvexHull),
+///     wkt!(POLYGON((4. 1.,2. 5.,0. 0.,4. 1.)))
+/// );
+/// ```
+pub trait ToAoi<T: GeoFloat> {
+    fn to_aoi(&self, method: AoiMethod<T>) -> Polygon<T>;
+}
+
+impl<T> ToAoi<T> for MultiPoint<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn to_aoi(&self, method: AoiMethod<T>) -> Polygon<T> {
+        match method {
+            AoiMethod::ConvexHull => self.convex_hull(),
+            AoiMethod::BoundingBox => bounding_box_polygon(self.bounding_rect()),
+            AoiMethod::ConcaveHull(concavity) => self.concave_hull(concavity),
+        }
+    }
+}
+
+impl<T> ToAoi<T> for LineString<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn to_aoi(&self, method: AoiMethod<T>) -> Polygon<T> {
+        match method {
+            AoiMethod::ConvexHull => self.convex_hull(),
+            AoiMethod::BoundingBox => bounding_box_polygon(self.bounding_rect()),
+            AoiMethod::ConcaveHull(concavity) => self.concave_hull(concavity),
+        }
+    }
+}
+
+fn bounding_box_polygon<T: GeoFloat>(rect: Option<Rect<T>>) -> Polygon<T> {
+    rect.map_or_else(
+        || Polygon::new(LineString::new(vec![]), vec![]),
+        |rect| rect.to_polygon(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Area};
+
+    #[test]
+    fn empty() {
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        let multi_point = MultiPoint::<f64>::new(vec![]);
+        for method in [
+            AoiMethod::ConvexHull,
+            AoiMethod::BoundingBox,
+            AoiMethod::ConcaveHull(2.0),
+        ] {
+            assert_eq!(multi_point.to_aoi(method), empty);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let single = wkt!(MULTIPOINT(1. 1.));
+        assert_eq!(
+            single.to_aoi(AoiMethod::BoundingBox),
+            wkt!(POLYGON((1. 1.,1. 1.,1. 1.,1. 1.,1. 1.)))
+        );
+        assert_eq!(single.to_aoi(AoiMethod::ConvexHull).unsigned_area(), 0.);
+        assert!(single
+            .to_aoi(AoiMethod::ConcaveHull(2.0))
+            .exterior()
+            .0
+            .is_empty());
+
+        let collinear = wkt!(MULTIPOINT(0. 0.,1. 1.,2. 2.));
+        assert_eq!(collinear.to_aoi(AoiMethod::BoundingBox).unsigned_area(), 4.);
+        assert_eq!(collinear.to_aoi(AoiMethod::ConvexHull).unsigned_area(), 0.);
+    }
+
+    #[test]
+    fn matches_line_string() {
+        let multi_point = wkt!(MULTIPOINT(0. 0.,4. 0.,4. 4.,3. 1.,3. 2.));
+        let line_string: LineString = multi_point.iter().map(|p| p.0).collect();
+        for method in [
+            AoiMethod::ConvexHull,
+            AoiMethod::BoundingBox,
+            AoiMethod::ConcaveHull(2.0),
+        ] {
+            assert_eq!(multi_point.to_aoi(method), line_string.to_aoi(method));
+        }
+        assert!(
+            multi_point
+                .to_aoi(AoiMethod::ConcaveHull(2.0))
+                .unsigned_area()
+                < multi_point.to_aoi(AoiMethod::ConvexHull).unsigned_area()
+        );
+    }
+}
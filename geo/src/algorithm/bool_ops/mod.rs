@@ -2,23 +2,27 @@ mod i_overlay_integration;
 #[cfg(test)]
 mod tests;
 
+pub use i_overlay::core::fill_rule::FillRule;
 use i_overlay_integration::convert::{multi_polygon_from_shapes, ring_to_shape_path};
 use i_overlay_integration::BoolOpsCoord;
 pub use i_overlay_integration::BoolOpsNum;
 
+use std::borrow::Borrow;
+
 use crate::geometry::{LineString, MultiLineString, MultiPolygon, Polygon};
+use crate::orient::{Direction, Orient};
 use crate::winding_order::{Winding, WindingOrder};
 
-use i_overlay::core::fill_rule::FillRule;
 use i_overlay::core::overlay_rule::OverlayRule;
 use i_overlay::float::clip::FloatClip;
 use i_overlay::float::overlay::FloatOverlay;
 use i_overlay::float::single::SingleFloatOverlay;
+use i_overlay::float::slice::FloatSlice;
 use i_overlay::string::clip::ClipRule;
 
 /// 几何体上的布尔运算。
 ///
-/// 布尔运算是将几何体视为二维平面子集的集合运算。支持的运算有：交集、并集、对称差（异或），以及一对二维几何体的差集和自我剪裁一个一维几何体。
+/// 布尔运算是将几何体视为二维平面子集的集合运算。支持的运算有：交集、并集、对称差（异或），以及一对二维几何体的差集、自我剪裁一个一维几何体和沿一维几何体切分自我。
 ///
 /// 这些操作是基于[`Polygon`]和[`MultiPolygon`]几何体实现的。
 ///
@@ -28,6 +32,13 @@ use i_overlay::string::clip::ClipRule;
 ///
 /// 算法会处理并忽略面积为0的退化二维几何体。特别是，与一个空几何体的`并集`操作应能去除退化并修复无效的多边形，只要内外条件如上所述得以满足。
 ///
+/// # 填充规则
+///
+/// [`intersection`](Self::intersection)、[`union`](Self::union) 等方法使用 [`FillRule::EvenOdd`]
+/// 判断环所围成的区域是否属于内部，这对有效几何体总是正确的。来自 SVG 或字体等使用非零环绕规则的数据中，
+/// 自相交或相互重叠的环应使用 [`FillRule::NonZero`]，参见
+/// [`boolean_op_with_fill_rule`](Self::boolean_op_with_fill_rule)。
+///
 /// # 性能
 ///
 /// 对于大量[`Polygon`]或[`MultiPolygon`]的并集操作，使用[`unary_union`]将获得更好的性能。
@@ -42,14 +53,60 @@ pub trait BooleanOps {
     /// 对于MultiPolygon，这要求其任何多边形的内部不可以重叠。
     fn rings(&self) -> impl Iterator<Item = &LineString<Self::Scalar>>;
 
+    /// 使用 [`FillRule::EvenOdd`] 填充规则执行布尔运算 `op`。
     fn boolean_op(
         &self,
         other: &impl BooleanOps<Scalar = Self::Scalar>,
         op: OpType,
+    ) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op_with_fill_rule(other, op, FillRule::EvenOdd)
+    }
+
+    /// 使用指定的 [`FillRule`] 执行布尔运算 `op`。
+    ///
+    /// 填充规则决定输入的环所围成的哪些区域被视为几何体的内部：
+    ///
+    /// - [`FillRule::EvenOdd`]：被奇数个环包围的区域是内部，与环的方向无关。
+    ///   对于符合 OGC 规范的有效几何体（环之间互不重叠，内环位于外环之内），无论环的方向如何，
+    ///   这正是其内部，因此它是 [`boolean_op`](Self::boolean_op) 使用的默认规则。
+    /// - [`FillRule::NonZero`]：环绕数非零的区域是内部。对于自相交的环或相互重叠的多边形（常见于外部数据），
+    ///   重叠部分仍被视为内部，而不是被“挖空”。注意内环必须与外环方向相反
+    ///   （例如先调用 [`Orient`](crate::Orient)），否则洞会被填充。
+    /// - [`FillRule::Positive`]、[`FillRule::Negative`]：仅环绕数为正（或为负）的区域是内部，
+    ///   因此结果取决于环的方向。在 y 轴向上的坐标系中，逆时针环的环绕数为负，
+    ///   即 `Negative` 填充逆时针的环，`Positive` 填充顺时针的环。
+    ///
+    /// 两个操作数使用同一个填充规则，例如使用 `Negative` 时，顺时针的 `other` 会被视为空。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{wkt, Area, BooleanOps, FillRule, OpType};
+    ///
+    /// // 两个相互重叠的正方形（无效的 MultiPolygon）
+    /// let overlapping = wkt!(MULTIPOLYGON(
+    ///     ((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)),
+    ///     ((1. 0.,3. 0.,3. 2.,1. 2.,1. 0.))
+    /// ));
+    /// let window = wkt!(POLYGON((0. 0.,3. 0.,3. 2.,0. 2.,0. 0.)));
+    ///
+    /// // EvenOdd 将重叠部分视为外部
+    /// let even_odd = overlapping.boolean_op_with_fill_rule(&window, OpType::Intersection, FillRule::EvenOdd);
+    /// assert_eq!(even_odd.unsigned_area(), 4.);
+    ///
+    /// // NonZero 将重叠部分视为内部
+    /// let non_zero = overlapping.intersection_with_fill_rule(&window, FillRule::NonZero);
+    /// assert_eq!(non_zero.unsigned_area(), 6.);
+    /// ```
+    fn boolean_op_with_fill_rule(
+        &self,
+        other: &impl BooleanOps<Scalar = Self::Scalar>,
+        op: OpType,
+        fill_rule: FillRule,
     ) -> MultiPolygon<Self::Scalar> {
         let subject = self.rings().map(ring_to_shape_path).collect::<Vec<_>>();
         let clip = other.rings().map(ring_to_shape_path).collect::<Vec<_>>();
-        let shapes = subject.overlay(&clip, op.into(), FillRule::EvenOdd);
+        let shapes = subject.overlay(&clip, op.into(), fill_rule);
         multi_polygon_from_shapes(shapes)
     }
 
@@ -61,6 +118,16 @@ pub trait BooleanOps {
         self.boolean_op(other, OpType::Intersection)
     }
 
+    /// 与 [`intersection`](Self::intersection) 相同，但使用指定的 [`FillRule`]，
+    /// 参见 [`boolean_op_with_fill_rule`](Self::boolean_op_with_fill_rule)。
+    fn intersection_with_fill_rule(
+        &self,
+        other: &impl BooleanOps<Scalar = Self::Scalar>,
+        fill_rule: FillRule,
+    ) -> MultiPolygon<Self::Scalar> {
+        self.boolean_op_with_fill_rule(other, OpType::Intersection, fill_rule)
+    }
+
     /// 将`self`和`other`的区域合并成一个单一的几何体，消除重叠并合并边界。
     fn union(&self, other: &impl BooleanOps<Scalar = Self::Scalar>) -> MultiPolygon<Self::Scalar> {
         self.boolean_op(other, OpType::Union)
@@ -101,6 +168,38 @@ pub trait BooleanOps {
         let paths = subject.clip_by(&clip, FillRule::EvenOdd, clip_rule);
         i_overlay_integration::convert::multi_line_string_from_paths(paths)
     }
+
+    /// 沿着`cutter`将`self`切分为若干多边形。
+    ///
+    /// `self`的边界与`cutter`相交处会被打断，然后重新构建出位于切线两侧的子多边形。
+    ///
+    /// 只有完全穿过几何体（从边界的一侧进入并从另一侧穿出）的那部分切线才会产生切分。
+    /// 没有完全穿过几何体的切线（例如仅在内部终止，或完全位于几何体外部）不会改变结果，
+    /// 此时返回的`MultiPolygon`仅包含原始区域。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{wkt, Area, BooleanOps};
+    ///
+    /// let square = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+    /// let cutter = wkt!(LINESTRING(2. -1.,2. 5.));
+    ///
+    /// let pieces = square.split_by_line(&cutter);
+    /// assert_eq!(pieces.0.len(), 2);
+    /// assert_eq!(pieces.0[0].unsigned_area(), 8.);
+    /// assert_eq!(pieces.0[1].unsigned_area(), 8.);
+    ///
+    /// // 没有完全穿过多边形的切线不会切分它
+    /// let dangling = wkt!(LINESTRING(2. -1.,2. 2.));
+    /// assert_eq!(square.split_by_line(&dangling).0.len(), 1);
+    /// ```
+    fn split_by_line(&self, cutter: &LineString<Self::Scalar>) -> MultiPolygon<Self::Scalar> {
+        let subject = self.rings().map(ring_to_shape_path).collect::<Vec<_>>();
+        let cutter: Vec<_> = cutter.coords().map(|c| BoolOpsCoord(*c)).collect();
+        let shapes = subject.slice_by(&cutter, FillRule::EvenOdd);
+        multi_polygon_from_shapes(shapes)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -152,12 +251,45 @@ pub enum OpType {
 /// ```
 pub fn unary_union<'a, B: BooleanOps + 'a>(
     boppables: impl IntoIterator<Item = &'a B>,
+) -> MultiPolygon<B::Scalar> {
+    unary_union_impl::<B, _>(boppables)
+}
+
+/// 与 [`unary_union`] 相同，但接受拥有所有权的几何体。
+///
+/// 每个几何体在其环被转换后立即被丢弃，因此可以直接传入惰性生成几何体的迭代器
+/// （例如逐条解码的要素），而无需先把所有几何体收集到 `Vec` 中。
+/// 对于大量输入，这可以显著降低峰值内存。结果与 [`unary_union`] 完全相同。
+///
+/// # 例子
+///
+/// ```
+/// use geo::algorithm::{unary_union, unary_union_owned};
+/// use geo::{Polygon, Rect};
+///
+/// let pieces = (0..4).map(|i| {
+///     let x = i as f64;
+///     Rect::new((x, 0.), (x + 1., 1.)).to_polygon()
+/// });
+///
+/// let union = unary_union_owned(pieces.clone());
+/// assert_eq!(union.0.len(), 1);
+/// assert_eq!(union, unary_union(&pieces.collect::<Vec<Polygon>>()));
+/// ```
+pub fn unary_union_owned<B: BooleanOps>(
+    boppables: impl IntoIterator<Item = B>,
+) -> MultiPolygon<B::Scalar> {
+    unary_union_impl::<B, _>(boppables)
+}
+
+fn unary_union_impl<B: BooleanOps, R: Borrow<B>>(
+    boppables: impl IntoIterator<Item = R>,
 ) -> MultiPolygon<B::Scalar> {
     let mut winding_order: Option<WindingOrder> = None;
     let subject = boppables
         .into_iter()
         .flat_map(|boppable| {
-            let rings = boppable.rings();
+            let rings = boppable.borrow().rings();
             rings
                 .map(|ring| {
                     if winding_order.is_none() {
@@ -179,6 +311,44 @@ pub fn unary_union<'a, B: BooleanOps + 'a>(
     multi_polygon_from_shapes(shapes)
 }
 
+/// 将[`MultiPolygon`]的所有成员合并为一个干净、互不重叠的结果。
+///
+/// 这相当于对其自身的多边形调用[`unary_union`]，但在合并前会先统一各成员的方向，
+/// 因此成员的缠绕顺序可以是任意的。
+///
+/// 当`MultiPolygon`的成员相互重叠或接触（因而无效）时，这是常用的修复步骤。
+/// 与[`BooleanOps`]中描述的一样，结果会去除面积为0的退化部分；
+/// 此外结果采用标准缠绕顺序：外环为逆时针方向，内环为顺时针方向。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, Area, Dissolve};
+///
+/// // 两个重叠的正方形，其中一个为顺时针方向
+/// let overlapping = wkt!(MULTIPOLYGON(
+///     ((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)),
+///     ((2. 2.,2. 6.,6. 6.,6. 2.,2. 2.))
+/// ));
+///
+/// let dissolved = overlapping.dissolve();
+/// assert_eq!(dissolved.0.len(), 1);
+/// assert_eq!(dissolved.unsigned_area(), 28.);
+/// ```
+pub trait Dissolve {
+    type Scalar: BoolOpsNum;
+
+    fn dissolve(&self) -> MultiPolygon<Self::Scalar>;
+}
+
+impl<T: BoolOpsNum> Dissolve for MultiPolygon<T> {
+    type Scalar = T;
+
+    fn dissolve(&self) -> MultiPolygon<T> {
+        unary_union(self.orient(Direction::Default).iter()).orient(Direction::Default)
+    }
+}
+
 impl<T: BoolOpsNum> BooleanOps for Polygon<T> {
     type Scalar = T;
 
@@ -1,4 +1,4 @@
-use super::{unary_union, BooleanOps};
+use super::{unary_union, unary_union_owned, BooleanOps};
 use crate::{wkt, Convert, MultiPolygon, Polygon, Relate};
 use std::time::Instant;
 use wkt::ToWkt;
@@ -20,6 +20,14 @@ fn test_unary_union() {
     assert_eq!(multi_poly_union.0.len(), 1);
 }
 
+#[test]
+fn test_unary_union_owned_matches_borrowed() {
+    let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
+    let borrowed = unary_union(&input);
+    let owned = unary_union_owned(input.0);
+    assert_eq!(owned, borrowed);
+}
+
 #[test]
 fn test_unary_union_errors() {
     let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
@@ -146,6 +154,143 @@ fn jts_test_overlay_la_1() {
     );
 }
 
+mod split_by_line {
+    use super::*;
+    use crate::Area;
+
+    #[test]
+    fn line_fully_crossing_polygon() {
+        let square: Polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        let cutter = wkt!(LINESTRING(-1. 1.,5. 3.));
+
+        let pieces = square.split_by_line(&cutter);
+        assert_eq!(pieces.0.len(), 2);
+        assert_relative_eq!(pieces.unsigned_area(), square.unsigned_area());
+        assert_relative_eq!(pieces.0[0].unsigned_area(), 8.0, epsilon = 1e-6);
+        assert_relative_eq!(pieces.0[1].unsigned_area(), 8.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn line_crossing_polygon_several_times() {
+        let square: Polygon = wkt!(POLYGON((0. 0.,6. 0.,6. 6.,0. 6.,0. 0.)));
+        // 穿过多边形两次的折线将其切成三块
+        let cutter = wkt!(LINESTRING(2. -1.,2. 7.,4. 7.,4. -1.));
+
+        let pieces = square.split_by_line(&cutter);
+        assert_eq!(pieces.0.len(), 3);
+        assert_relative_eq!(pieces.unsigned_area(), square.unsigned_area());
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let polygon: Polygon = wkt!(POLYGON(
+            (0. 0.,6. 0.,6. 6.,0. 6.,0. 0.),
+            (2. 2.,4. 2.,4. 4.,2. 4.,2. 2.)
+        ));
+        let cutter = wkt!(LINESTRING(3. -1.,3. 7.));
+
+        let pieces = polygon.split_by_line(&cutter);
+        assert_eq!(pieces.0.len(), 2);
+        assert!(pieces.iter().all(|piece| piece.interiors().is_empty()));
+        assert_relative_eq!(pieces.unsigned_area(), polygon.unsigned_area());
+    }
+
+    #[test]
+    fn line_not_dividing_polygon() {
+        let square: Polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+
+        // 在内部终止
+        let dangling = wkt!(LINESTRING(2. -1.,2. 2.));
+        let pieces = square.split_by_line(&dangling);
+        assert_eq!(pieces.0.len(), 1);
+        assert_relative_eq!(pieces.unsigned_area(), square.unsigned_area());
+
+        // 完全位于外部
+        let outside = wkt!(LINESTRING(5. 0.,5. 4.));
+        let pieces = square.split_by_line(&outside);
+        assert_eq!(pieces.0.len(), 1);
+        assert_relative_eq!(pieces.unsigned_area(), square.unsigned_area());
+    }
+
+    #[test]
+    fn split_multi_polygon() {
+        let multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)),
+            ((4. 0.,6. 0.,6. 2.,4. 2.,4. 0.))
+        ));
+        let cutter = wkt!(LINESTRING(-1. 1.,7. 1.));
+
+        let pieces = multi_polygon.split_by_line(&cutter);
+        assert_eq!(pieces.0.len(), 4);
+        assert_relative_eq!(pieces.unsigned_area(), multi_polygon.unsigned_area());
+    }
+}
+
+mod dissolve {
+    use super::*;
+    use crate::orient::{Direction, Orient};
+    use crate::{Area, Dissolve, Winding};
+
+    #[test]
+    fn overlapping_members() {
+        let input: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)),
+            ((2. 2.,6. 2.,6. 6.,2. 6.,2. 2.)),
+            ((10. 10.,12. 10.,12. 12.,10. 12.,10. 10.))
+        ));
+        let dissolved = input.dissolve();
+        assert_eq!(dissolved.0.len(), 2);
+        assert_relative_eq!(dissolved.unsigned_area(), 32.);
+        assert_relative_eq!(
+            dissolved.unsigned_area(),
+            unary_union(input.iter()).unsigned_area()
+        );
+    }
+
+    #[test]
+    fn mixed_winding_members() {
+        let counter_clockwise: Polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        let clockwise: Polygon = wkt!(POLYGON((2. 2.,2. 6.,6. 6.,6. 2.,2. 2.)));
+        let input = MultiPolygon::new(vec![counter_clockwise, clockwise]);
+
+        let dissolved = input.dissolve();
+        assert_eq!(dissolved.0.len(), 1);
+        assert_relative_eq!(dissolved.unsigned_area(), 28.);
+        assert!(dissolved.0[0].exterior().is_ccw());
+    }
+
+    #[test]
+    fn result_has_standard_winding() {
+        let input: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,0. 6.,6. 6.,6. 0.,0. 0.),(2. 2.,4. 2.,4. 4.,2. 4.,2. 2.))
+        ));
+        let dissolved = input.dissolve();
+        assert!(dissolved.0[0].exterior().is_ccw());
+        assert!(dissolved.0[0].interiors()[0].is_cw());
+    }
+
+    #[test]
+    fn member_hole_filled_by_other_member() {
+        let input: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,6. 0.,6. 6.,0. 6.,0. 0.),(2. 2.,2. 4.,4. 4.,4. 2.,2. 2.)),
+            ((1. 1.,3. 1.,3. 3.,1. 3.,1. 1.))
+        ));
+        let dissolved = input.dissolve();
+        assert_eq!(dissolved.0.len(), 1);
+        assert_eq!(dissolved.0[0].interiors().len(), 1);
+        assert_relative_eq!(dissolved.unsigned_area(), 33.);
+    }
+
+    #[test]
+    fn winding_independent() {
+        let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
+        assert_eq!(
+            input.orient(Direction::Default).dissolve(),
+            input.orient(Direction::Reversed).dissolve()
+        );
+    }
+}
+
 mod gh_issues {
     use super::super::{BooleanOps, OpType};
     use crate::{geometry::*, wkt};
@@ -364,3 +509,86 @@ mod gh_issues {
         // 目标只是到达这里而不出现崩溃
     }
 }
+
+#[test]
+fn fill_rules() {
+    use super::{FillRule, OpType};
+    use crate::winding_order::WindingOrder;
+    use crate::{Area, Winding};
+
+    let window = wkt!(POLYGON((-1. -1.,10. -1.,10. 10.,-1. 10.,-1. -1.)));
+
+    // 对于内环与外环方向相反的有效几何体，EvenOdd 与 NonZero 的结果相同
+    let valid = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),(1. 1.,1. 2.,2. 2.,2. 1.,1. 1.)));
+    for op in [
+        OpType::Intersection,
+        OpType::Union,
+        OpType::Difference,
+        OpType::Xor,
+    ] {
+        assert_relative_eq!(
+            valid
+                .boolean_op_with_fill_rule(&window, op, FillRule::EvenOdd)
+                .unsigned_area(),
+            valid
+                .boolean_op_with_fill_rule(&window, op, FillRule::NonZero)
+                .unsigned_area(),
+        );
+        assert_eq!(
+            valid.boolean_op(&window, op),
+            valid.boolean_op_with_fill_rule(&window, op, FillRule::EvenOdd)
+        );
+    }
+
+    // 内环与外环方向相同时，NonZero 会填充洞，而 EvenOdd 不受方向影响
+    let same_winding =
+        wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 2.,1. 1.)));
+    assert_relative_eq!(
+        same_winding
+            .intersection_with_fill_rule(&window, FillRule::EvenOdd)
+            .unsigned_area(),
+        15.0
+    );
+    assert_relative_eq!(
+        same_winding
+            .intersection_with_fill_rule(&window, FillRule::NonZero)
+            .unsigned_area(),
+        16.0
+    );
+
+    // 自相交的环绕了中间区域两次
+    let self_overlapping = wkt!(POLYGON((0. 0.,3. 0.,3. 3.,1. 3.,1. 1.,2. 1.,2. 2.,0. 2.,0. 0.)));
+    let even_odd = self_overlapping.intersection_with_fill_rule(&window, FillRule::EvenOdd);
+    let non_zero = self_overlapping.intersection_with_fill_rule(&window, FillRule::NonZero);
+    assert_relative_eq!(non_zero.unsigned_area() - even_odd.unsigned_area(), 1.0);
+
+    // Positive 和 Negative 取决于环的方向
+    let ccw = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)));
+    assert!(ccw.exterior().is_ccw());
+    let positive = ccw.intersection_with_fill_rule(&window, FillRule::Positive);
+    let negative = ccw.intersection_with_fill_rule(&window, FillRule::Negative);
+    assert!(positive.0.is_empty());
+    assert_relative_eq!(negative.unsigned_area(), 1.0);
+
+    // 填充规则同时作用于两个操作数，因此窗口也要使用顺时针方向
+    let cw = Polygon::new(
+        ccw.exterior()
+            .clone_to_winding_order(WindingOrder::Clockwise),
+        vec![],
+    );
+    let cw_window = Polygon::new(
+        window
+            .exterior()
+            .clone_to_winding_order(WindingOrder::Clockwise),
+        vec![],
+    );
+    assert_relative_eq!(
+        cw.intersection_with_fill_rule(&cw_window, FillRule::Positive)
+            .unsigned_area(),
+        1.0
+    );
+    assert!(cw
+        .intersection_with_fill_rule(&cw_window, FillRule::Negative)
+        .0
+        .is_empty());
+}
@@ -1,5 +1,5 @@
 use crate::utils::{partial_max, partial_min};
-use crate::{coord, geometry::*, CoordNum, GeometryCow};
+use crate::{coord, geometry::*, CoordNum, CoordsIter, GeometryCow};
 use geo_types::private_utils::{get_bounding_rect, line_string_bounding_rect};
 
 /// 计算几何图形的边界矩形。
@@ -197,6 +197,160 @@ where
     }
 }
 
+/// 逐个累积几何图形的边界矩形，而无需先把它们收集起来。
+///
+/// 适用于几何图形以流的形式到达（例如逐条读取要素）并且需要计算它们共同的范围的场景，
+/// 例如“让地图适应所有这些要素”。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{coord, wkt, BoundingRectAccumulator, Rect};
+///
+/// let mut accumulator = BoundingRectAccumulator::new();
+/// assert_eq!(accumulator.rect(), None);
+///
+/// accumulator.add(&wkt!(POINT(1. 2.)));
+/// accumulator.add(&wkt!(LINESTRING(-1. 0.,3. 1.)));
+/// // 空几何图形没有边界矩形，会被忽略
+/// accumulator.add(&wkt!(LINESTRING EMPTY));
+///
+/// assert_eq!(
+///     accumulator.finish(),
+///     Some(Rect::new(coord! { x: -1., y: 0. }, coord! { x: 3., y: 2. })),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingRectAccumulator<T: CoordNum> {
+    rect: Option<Rect<T>>,
+}
+
+impl<T: CoordNum> BoundingRectAccumulator<T> {
+    /// 创建一个尚未包含任何几何图形的累加器。
+    pub fn new() -> Self {
+        Self { rect: None }
+    }
+
+    /// 将 `geometry` 的边界矩形合并到当前的边界矩形中。
+    pub fn add<G>(&mut self, geometry: &G)
+    where
+        G: BoundingRect<T> + ?Sized,
+    {
+        if let Some(next) = geometry.bounding_rect().into() {
+            self.rect = Some(match self.rect {
+                Some(rect) => bounding_rect_merge(rect, next),
+                None => next,
+            });
+        }
+    }
+
+    /// 目前为止所有几何图形的边界矩形，如果还没有添加任何非空几何图形，则为 `None`。
+    pub fn rect(&self) -> Option<Rect<T>> {
+        self.rect
+    }
+
+    /// 消耗累加器，返回所有已添加几何图形的边界矩形。
+    pub fn finish(self) -> Option<Rect<T>> {
+        self.rect
+    }
+}
+
+impl<T: CoordNum> Default for BoundingRectAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 逐个坐标地累积边界矩形，适用于坐标分批到达、不便先构造出完整几何图形的场景（例如分块处理）。
+///
+/// 与 [`BoundingRectAccumulator`] 合并各个几何图形的边界矩形不同，此构建器直接维护每个坐标的最小值和最大值。
+///
+/// # NaN 的处理
+///
+/// 任一分量为 NaN 的坐标会被忽略，因此不会污染结果；如果所有坐标都被忽略，[`finish`](Self::finish)
+/// 返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{coord, wkt, BoundingRectBuilder, Rect};
+///
+/// let mut builder = BoundingRectBuilder::new();
+///
+/// // 坐标分块到达
+/// builder.extend(vec![coord! { x: 1., y: 2. }, coord! { x: f64::NAN, y: 100. }]);
+/// builder.add_coord(coord! { x: -1., y: 0. });
+/// builder.add_geometry(&wkt!(LINESTRING(3. 1.,2. 5.)));
+///
+/// assert_eq!(
+///     builder.finish(),
+///     Some(Rect::new(coord! { x: -1., y: 0. }, coord! { x: 3., y: 5. })),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingRectBuilder<T: CoordNum> {
+    bounds: Option<(Coord<T>, Coord<T>)>,
+}
+
+impl<T: CoordNum> BoundingRectBuilder<T> {
+    /// 创建一个尚未包含任何坐标的构建器。
+    pub fn new() -> Self {
+        Self { bounds: None }
+    }
+
+    /// 将 `coord` 加入边界矩形。任一分量为 NaN 的坐标会被忽略。
+    pub fn add_coord(&mut self, coord: Coord<T>) {
+        // NaN 不等于自身
+        #[allow(clippy::eq_op)]
+        if coord.x != coord.x || coord.y != coord.y {
+            return;
+        }
+        let Some((min, max)) = &mut self.bounds else {
+            self.bounds = Some((coord, coord));
+            return;
+        };
+        if coord.x < min.x {
+            min.x = coord.x;
+        } else if coord.x > max.x {
+            max.x = coord.x;
+        }
+        if coord.y < min.y {
+            min.y = coord.y;
+        } else if coord.y > max.y {
+            max.y = coord.y;
+        }
+    }
+
+    /// 将 `geometry` 的坐标加入边界矩形。
+    ///
+    /// 与 [`BoundingRect`] 一样，多边形只考虑外环的坐标。
+    pub fn add_geometry<G>(&mut self, geometry: &G)
+    where
+        G: CoordsIter<Scalar = T>,
+    {
+        self.extend(geometry.exterior_coords_iter());
+    }
+
+    /// 消耗构建器，返回所有已添加坐标的边界矩形；如果没有添加任何（非 NaN 的）坐标，则为 `None`。
+    pub fn finish(self) -> Option<Rect<T>> {
+        self.bounds.map(|(min, max)| Rect::new(min, max))
+    }
+}
+
+impl<T: CoordNum> Default for BoundingRectBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> Extend<Coord<T>> for BoundingRectBuilder<T> {
+    fn extend<I: IntoIterator<Item = Coord<T>>>(&mut self, coords: I) {
+        for coord in coords {
+            self.add_coord(coord);
+        }
+    }
+}
+
 // 返回一个新的矩形，该矩形包含提供的矩形。
 fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
     Rect::new(
@@ -215,11 +369,11 @@ fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
 mod test {
     use super::bounding_rect_merge;
     use crate::line_string;
-    use crate::BoundingRect;
     use crate::{
         coord, point, polygon, Geometry, GeometryCollection, Line, LineString, MultiLineString,
         MultiPoint, MultiPolygon, Polygon, Rect,
     };
+    use crate::{BoundingRect, BoundingRectAccumulator, BoundingRectBuilder};
 
     #[test]
     fn empty_linestring_test() {
@@ -318,6 +472,79 @@ mod test {
         );
     }
 
+    #[test]
+    fn bounding_rect_accumulator_test() {
+        let geometries: Vec<Geometry> = vec![
+            point! { x: 1., y: 2. }.into(),
+            Line::new(coord! { x: -1., y: 5. }, coord! { x: 0., y: 0. }).into(),
+            LineString::new(vec![]).into(),
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: -3.), (x: 0., y: 0.)].into(),
+        ];
+        let mut accumulator = BoundingRectAccumulator::default();
+        for geometry in &geometries {
+            accumulator.add(geometry);
+        }
+        assert_eq!(
+            accumulator.finish(),
+            GeometryCollection::new_from(geometries).bounding_rect()
+        );
+        assert_eq!(
+            accumulator.finish(),
+            Some(Rect::new(
+                coord! { x: -1., y: -3. },
+                coord! { x: 4., y: 5. }
+            ))
+        );
+
+        let empty = BoundingRectAccumulator::<f64>::new();
+        assert_eq!(empty.finish(), None);
+    }
+
+    #[test]
+    fn bounding_rect_builder_test() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+        );
+        let line_string = line_string![(x: -1., y: 2.), (x: 6., y: 3.), (x: 5., y: -2.)];
+
+        // 分块添加的结果与一次性计算的结果相同
+        let mut builder = BoundingRectBuilder::default();
+        builder.add_geometry(&polygon);
+        for chunk in line_string.0.chunks(2) {
+            builder.extend(chunk.iter().copied());
+        }
+        assert_eq!(
+            builder.finish(),
+            GeometryCollection::new_from(vec![polygon.into(), line_string.into()]).bounding_rect()
+        );
+
+        assert_eq!(BoundingRectBuilder::<f64>::new().finish(), None);
+    }
+
+    #[test]
+    fn bounding_rect_builder_nan_test() {
+        let mut builder = BoundingRectBuilder::new();
+        // 开头的 NaN 也不会污染结果
+        builder.add_coord(coord! { x: f64::NAN, y: 0. });
+        assert_eq!(builder.finish(), None);
+        builder.add_coord(coord! { x: 1., y: 1. });
+        builder.add_coord(coord! { x: 0., y: f64::NAN });
+        builder.add_coord(coord! { x: 3., y: -1. });
+        assert_eq!(
+            builder.finish(),
+            Some(Rect::new(coord! { x: 1., y: -1. }, coord! { x: 3., y: 1. }))
+        );
+
+        // 整数坐标同样适用
+        let mut builder = BoundingRectBuilder::new();
+        builder.add_geometry(&MultiPoint::from(vec![(3, 1), (-2, 7)]));
+        assert_eq!(
+            builder.finish(),
+            Some(Rect::new(coord! { x: -2, y: 1 }, coord! { x: 3, y: 7 }))
+        );
+    }
+
     #[test]
     fn point_bounding_rect_test() {
         assert_eq!(
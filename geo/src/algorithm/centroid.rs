@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 
 use crate::area::{get_linestring_area, Area};
+use crate::coords_iter::CoordsIter;
 use crate::dimensions::{Dimensions, Dimensions::*, HasDimensions};
 use crate::geometry::*;
 use crate::line_measures::{Euclidean, Length};
@@ -341,9 +342,17 @@ where
     /// assert_eq!(points.centroid(), Some(Point::new(3., 2.)));
     /// ```
     fn centroid(&self) -> Self::Output {
-        let mut operation = CentroidOperation::new();
-        operation.add_multi_point(self);
-        operation.centroid()
+        // 点集总是零维的，因此无需经过跟踪维度的 `CentroidOperation`，直接求平均值即可
+        if self.0.is_empty() {
+            return None;
+        }
+        let (sum, count) = self
+            .0
+            .iter()
+            .fold((Coord::zero(), T::zero()), |(sum, count), point| {
+                (sum + point.0, count + T::one())
+            });
+        Some(Point::from(sum / count))
     }
 }
 
@@ -433,6 +442,56 @@ where
     }
 }
 
+/// 不区分维度，计算所有坐标的算术平均值。
+///
+/// [`Centroid`] 对 [`GeometryCollection`] 只考虑维度最高的成员：只要集合中有一条长度不为零的线，
+/// 所有的点就都会被忽略。这对于混合类型的集合是正确的，但有时需要的只是所有坐标的简单平均值，
+/// 此时可以使用此方法。
+///
+/// 每个坐标都计一次，包括多边形闭合环中重复的首尾坐标；[`Rect`] 和 [`Triangle`] 只计算其角点。
+/// 集合中没有坐标时返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{point, wkt, Centroid, CentroidAllDimensions, Geometry, GeometryCollection};
+///
+/// let collection = GeometryCollection::<f64>::new_from(vec![
+///     wkt!(POINT(0. 0.)).into(),
+///     wkt!(POINT(4. 0.)).into(),
+///     wkt!(POINT(4. 4.)).into(),
+///     wkt!(LINESTRING(0. 3.,0. 4.)).into(),
+/// ]);
+///
+/// // 默认按维度加权，点被忽略
+/// assert_eq!(collection.centroid(), Some(point!(x: 0., y: 3.5)));
+///
+/// assert_eq!(
+///     collection.centroid_all_dimensions(),
+///     Some(point!(x: 1.6, y: 2.2))
+/// );
+/// ```
+pub trait CentroidAllDimensions<T: GeoFloat> {
+    fn centroid_all_dimensions(&self) -> Option<Point<T>>;
+}
+
+impl<T> CentroidAllDimensions<T> for GeometryCollection<T>
+where
+    T: GeoFloat,
+{
+    fn centroid_all_dimensions(&self) -> Option<Point<T>> {
+        let (sum, count) = self
+            .coords_iter()
+            .fold((Coord::zero(), T::zero()), |(sum, count), coord| {
+                (sum + coord, count + T::one())
+            });
+        if count == T::zero() {
+            return None;
+        }
+        Some(Point::from(sum / count))
+    }
+}
+
 struct CentroidOperation<T: GeoFloat>(Option<WeightedCentroid<T>>);
 impl<T: GeoFloat> CentroidOperation<T> {
     fn new() -> Self {
@@ -998,6 +1057,15 @@ mod test {
         assert_eq!(collection.centroid().unwrap(), point!(x: 1.0, y: 1.0));
     }
     #[test]
+    fn multi_point_matches_collection() {
+        let multi_point: MultiPoint = wkt!(MULTIPOINT(0.1 0.7,3.3 -1.9,2.2 5.5,-4.1 0.3,1.0 1.0));
+        let collection = GeometryCollection::new_from(vec![multi_point.clone().into()]);
+        assert_eq!(multi_point.centroid(), collection.centroid());
+
+        let empty = MultiPoint::<f64>::new(vec![]);
+        assert_eq!(empty.centroid(), None);
+    }
+    #[test]
     fn triangles() {
         // 普通三角形
         assert_eq!(
@@ -1099,4 +1167,33 @@ mod test {
             .push(Rect::new(c(10., 10.), c(11., 11.)).into());
         assert_eq!(collection.centroid().unwrap(), point!(x: 10.5, y: 10.5));
     }
+
+    #[test]
+    fn collection_all_dimensions() {
+        // 点和一条长度为零的退化线
+        let collection: GeometryCollection = GeometryCollection::new_from(vec![
+            wkt!(POINT(0. 0.)).into(),
+            wkt!(POINT(2. 0.)).into(),
+            wkt!(LINESTRING(2. 2.,2. 2.)).into(),
+        ]);
+        assert_eq!(
+            collection.centroid_all_dimensions(),
+            Some(point!(x: 1.5, y: 1.))
+        );
+
+        let mixed: GeometryCollection = GeometryCollection::new_from(vec![
+            wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))).into(),
+            wkt!(POINT(10. 10.)).into(),
+        ]);
+        assert_eq!(mixed.centroid(), Some(point!(x: 1., y: 1.)));
+        assert_eq!(
+            mixed.centroid_all_dimensions(),
+            Some(point!(x: 14. / 6., y: 14. / 6.))
+        );
+
+        assert_eq!(
+            GeometryCollection::<f64>::new_from(vec![]).centroid_all_dimensions(),
+            None
+        );
+    }
 }
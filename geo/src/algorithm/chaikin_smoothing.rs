@@ -3,7 +3,8 @@ use std::ops::Mul;
 use num_traits::FromPrimitive;
 
 use crate::{
-    coord, Coord, CoordFloat, Geometry, LineString, MultiLineString, MultiPolygon, Polygon,
+    coord, Coord, CoordFloat, Geometry, Line, LineString, MultiLineString, MultiPolygon, Polygon,
+    Triangle,
 };
 
 /// 使用 Chaikin 算法平滑 `LineString`、`Polygon`、`MultiLineString` 和 `MultiPolygon`。
@@ -13,25 +14,87 @@ use crate::{
 /// 平滑的每次迭代都会使几何体的顶点数加倍，因此某些情况下，之后应用简化以移除无关紧要的坐标可能是有意义的。
 ///
 /// 此实现保留开放线串的起始和结束顶点，并平滑闭合线串起始和结束之间的角部。
+///
+/// [`Line`] 没有可平滑的角部，平滑结果就是它本身。[`Triangle`] 被视为闭合的环进行平滑，
+/// 结果为圆润的 [`Polygon`]。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{coord, ChaikinSmoothing, Triangle};
+///
+/// let triangle = Triangle::new(
+///     coord! { x: 0., y: 0. },
+///     coord! { x: 4., y: 0. },
+///     coord! { x: 0., y: 4. },
+/// );
+/// let smoothed = triangle.chaikin_smoothing(1);
+/// // 每条边被替换为两个点，三个角都被切掉
+/// assert_eq!(smoothed.exterior().0.len(), 7);
+/// ```
 pub trait ChaikinSmoothing<T>
 where
     T: CoordFloat + FromPrimitive,
 {
+    type Output;
+
     /// 创建新的几何体，应用 Chaikin 平滑 `n_iterations` 次。
-    fn chaikin_smoothing(&self, n_iterations: usize) -> Self;
+    ///
+    /// 使用经典的 1/4–3/4 切角比例，等同于 `chaikin_smoothing_with_ratio(n_iterations, 0.25)`。
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self::Output {
+        self.chaikin_smoothing_with_ratio(n_iterations, T::from(0.25).unwrap())
+    }
+
+    /// 创建新的几何体，使用给定的切角比例应用 Chaikin 平滑 `n_iterations` 次。
+    ///
+    /// 每条线段被替换为位于其 `ratio` 和 `1 - ratio` 处的两个点，因此 `ratio` 越大，角部被切得越多。
+    /// `ratio` 应位于 `(0, 0.5)` 之内；超出范围的值（包括 NaN）会被截断到 `[0, 0.5]`，
+    /// 在两个端点处会产生重复的坐标。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{wkt, ChaikinSmoothing};
+    ///
+    /// let line_string = wkt!(LINESTRING(0. 0.,10. 0.,10. 10.));
+    ///
+    /// assert_eq!(
+    ///     line_string.chaikin_smoothing_with_ratio(1, 0.1),
+    ///     wkt!(LINESTRING(0. 0.,1. 0.,9. 0.,10. 1.,10. 9.,10. 10.))
+    /// );
+    /// assert_eq!(
+    ///     line_string.chaikin_smoothing_with_ratio(1, 0.25),
+    ///     line_string.chaikin_smoothing(1)
+    /// );
+    /// ```
+    fn chaikin_smoothing_with_ratio(&self, n_iterations: usize, ratio: T) -> Self::Output;
+}
+
+impl<T> ChaikinSmoothing<T> for Line<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = Self;
+
+    fn chaikin_smoothing_with_ratio(&self, _n_iterations: usize, _ratio: T) -> Self {
+        *self
+    }
 }
 
 impl<T> ChaikinSmoothing<T> for LineString<T>
 where
     T: CoordFloat + FromPrimitive,
 {
-    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+    type Output = Self;
+
+    fn chaikin_smoothing_with_ratio(&self, n_iterations: usize, ratio: T) -> Self {
+        let ratio = ratio.max(T::zero()).min(T::from(0.5).unwrap());
         if n_iterations == 0 {
             self.clone()
         } else {
-            let mut smooth = smoothen_linestring(self);
+            let mut smooth = smoothen_linestring(self, ratio);
             for _ in 0..(n_iterations - 1) {
-                smooth = smoothen_linestring(&smooth);
+                smooth = smoothen_linestring(&smooth, ratio);
             }
             smooth
         }
@@ -42,11 +105,13 @@ impl<T> ChaikinSmoothing<T> for MultiLineString<T>
 where
     T: CoordFloat + FromPrimitive,
 {
-    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+    type Output = Self;
+
+    fn chaikin_smoothing_with_ratio(&self, n_iterations: usize, ratio: T) -> Self {
         MultiLineString::new(
             self.0
                 .iter()
-                .map(|ls| ls.chaikin_smoothing(n_iterations))
+                .map(|ls| ls.chaikin_smoothing_with_ratio(n_iterations, ratio))
                 .collect(),
         )
     }
@@ -56,34 +121,51 @@ impl<T> ChaikinSmoothing<T> for Polygon<T>
 where
     T: CoordFloat + FromPrimitive,
 {
-    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+    type Output = Self;
+
+    fn chaikin_smoothing_with_ratio(&self, n_iterations: usize, ratio: T) -> Self {
         Polygon::new(
-            self.exterior().chaikin_smoothing(n_iterations),
+            self.exterior()
+                .chaikin_smoothing_with_ratio(n_iterations, ratio),
             self.interiors()
                 .iter()
-                .map(|ls| ls.chaikin_smoothing(n_iterations))
+                .map(|ls| ls.chaikin_smoothing_with_ratio(n_iterations, ratio))
                 .collect(),
         )
     }
 }
 
+impl<T> ChaikinSmoothing<T> for Triangle<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = Polygon<T>;
+
+    fn chaikin_smoothing_with_ratio(&self, n_iterations: usize, ratio: T) -> Self::Output {
+        self.to_polygon()
+            .chaikin_smoothing_with_ratio(n_iterations, ratio)
+    }
+}
+
 impl<T> ChaikinSmoothing<T> for MultiPolygon<T>
 where
     T: CoordFloat + FromPrimitive,
 {
-    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+    type Output = Self;
+
+    fn chaikin_smoothing_with_ratio(&self, n_iterations: usize, ratio: T) -> Self {
         MultiPolygon::new(
             self.0
                 .iter()
-                .map(|poly| poly.chaikin_smoothing(n_iterations))
+                .map(|poly| poly.chaikin_smoothing_with_ratio(n_iterations, ratio))
                 .collect(),
         )
     }
 }
 
 macro_rules! blanket_run_chaikin_smoothing {
-    ($geo:expr, $n_iter:expr) => {{
-        let smooth = $geo.chaikin_smoothing($n_iter);
+    ($geo:expr, $n_iter:expr, $ratio:expr) => {{
+        let smooth = $geo.chaikin_smoothing_with_ratio($n_iter, $ratio);
         let geo: Geometry<T> = smooth.into();
         geo
     }};
@@ -93,18 +175,27 @@ impl<T> ChaikinSmoothing<T> for Geometry<T>
 where
     T: CoordFloat + FromPrimitive,
 {
-    fn chaikin_smoothing(&self, n_iterations: usize) -> Geometry<T> {
+    type Output = Self;
+
+    fn chaikin_smoothing_with_ratio(&self, n_iterations: usize, ratio: T) -> Geometry<T> {
         match self {
-            Geometry::LineString(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
-            Geometry::MultiLineString(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
-            Geometry::Polygon(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
-            Geometry::MultiPolygon(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
+            Geometry::LineString(child) => {
+                blanket_run_chaikin_smoothing!(child, n_iterations, ratio)
+            }
+            Geometry::MultiLineString(child) => {
+                blanket_run_chaikin_smoothing!(child, n_iterations, ratio)
+            }
+            Geometry::Polygon(child) => blanket_run_chaikin_smoothing!(child, n_iterations, ratio),
+            Geometry::MultiPolygon(child) => {
+                blanket_run_chaikin_smoothing!(child, n_iterations, ratio)
+            }
+            Geometry::Triangle(child) => blanket_run_chaikin_smoothing!(child, n_iterations, ratio),
             _ => self.clone(),
         }
     }
 }
 
-fn smoothen_linestring<T>(linestring: &LineString<T>) -> LineString<T>
+fn smoothen_linestring<T>(linestring: &LineString<T>, ratio: T) -> LineString<T>
 where
     T: CoordFloat + Mul<T> + FromPrimitive,
 {
@@ -117,7 +208,7 @@ where
         }
     }
     for window_coordinates in linestring.0.windows(2) {
-        let (q, r) = smoothen_coordinates(window_coordinates[0], window_coordinates[1]);
+        let (q, r) = smoothen_coordinates(window_coordinates[0], window_coordinates[1], ratio);
         out_coords.push(q);
         out_coords.push(r);
     }
@@ -136,17 +227,18 @@ where
     out_coords.into()
 }
 
-fn smoothen_coordinates<T>(c0: Coord<T>, c1: Coord<T>) -> (Coord<T>, Coord<T>)
+fn smoothen_coordinates<T>(c0: Coord<T>, c1: Coord<T>, ratio: T) -> (Coord<T>, Coord<T>)
 where
     T: CoordFloat + Mul<T> + FromPrimitive,
 {
+    let keep = T::one() - ratio;
     let q = coord! {
-        x: (T::from(0.75).unwrap() * c0.x) + (T::from(0.25).unwrap() * c1.x),
-        y: (T::from(0.75).unwrap() * c0.y) + (T::from(0.25).unwrap() * c1.y),
+        x: (keep * c0.x) + (ratio * c1.x),
+        y: (keep * c0.y) + (ratio * c1.y),
     };
     let r = coord! {
-        x: (T::from(0.25).unwrap() * c0.x) + (T::from(0.75).unwrap() * c1.x),
-        y: (T::from(0.25).unwrap() * c0.y) + (T::from(0.75).unwrap() * c1.y),
+        x: (ratio * c0.x) + (keep * c1.x),
+        y: (ratio * c0.y) + (keep * c1.y),
     };
     (q, r)
 }
@@ -154,7 +246,7 @@ where
 #[cfg(test)]
 mod test {
     use crate::ChaikinSmoothing;
-    use crate::{Geometry, LineString, Point, Polygon};
+    use crate::{Geometry, Line, LineString, Point, Polygon, Triangle};
 
     #[test]
     fn geometry() {
@@ -185,6 +277,43 @@ mod test {
         assert_eq!(pt_out, Point::from((3.0, 0.0)));
     }
 
+    #[test]
+    fn line() {
+        let line = Line::new((0.0, 0.0), (3.0, 4.0));
+        assert_eq!(line.chaikin_smoothing(3), line);
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle = Triangle::new((0.0, 0.0).into(), (4.0, 0.0).into(), (0.0, 4.0).into());
+        let smoothed = triangle.chaikin_smoothing(1);
+        assert_eq!(
+            smoothed,
+            Polygon::new(
+                LineString::from(vec![
+                    (1.0, 0.0),
+                    (3.0, 0.0),
+                    (3.0, 1.0),
+                    (1.0, 3.0),
+                    (0.0, 3.0),
+                    (0.0, 1.0),
+                    (1.0, 0.0),
+                ]),
+                vec![]
+            )
+        );
+        assert_eq!(
+            triangle.chaikin_smoothing(2),
+            triangle.to_polygon().chaikin_smoothing(2)
+        );
+
+        let triangle_geo: Geometry = triangle.into();
+        assert_eq!(
+            triangle_geo.chaikin_smoothing(1),
+            Geometry::Polygon(smoothed)
+        );
+    }
+
     #[test]
     fn linestring_open() {
         let ls = LineString::from(vec![(3.0, 0.0), (6.0, 3.0), (3.0, 6.0), (0.0, 3.0)]);
@@ -258,4 +387,49 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn custom_ratio() {
+        let poly = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (8.0, 0.0), (8.0, 8.0), (0.0, 0.0)]),
+            vec![],
+        );
+        let poly_out = poly.chaikin_smoothing_with_ratio(1, 0.125);
+        assert_eq!(
+            poly_out.exterior(),
+            &LineString::from(vec![
+                (1.0, 0.0),
+                (7.0, 0.0),
+                (8.0, 1.0),
+                (8.0, 7.0),
+                (7.0, 7.0),
+                (1.0, 1.0),
+                (1.0, 0.0),
+            ])
+        );
+
+        // 超出范围的比例被截断到 [0, 0.5]
+        let ls = LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0)]);
+        assert_eq!(
+            ls.chaikin_smoothing_with_ratio(2, 0.9),
+            ls.chaikin_smoothing_with_ratio(2, 0.5)
+        );
+        assert_eq!(
+            ls.chaikin_smoothing_with_ratio(1, -1.0),
+            LineString::from(vec![
+                (0.0, 0.0),
+                (0.0, 0.0),
+                (4.0, 0.0),
+                (4.0, 0.0),
+                (4.0, 4.0),
+                (4.0, 4.0),
+            ])
+        );
+
+        let geometry: Geometry = ls.clone().into();
+        assert_eq!(
+            geometry.chaikin_smoothing_with_ratio(1, 0.3),
+            Geometry::from(ls.chaikin_smoothing_with_ratio(1, 0.3))
+        );
+    }
 }
@@ -60,8 +60,8 @@ where
     fn chamberlain_duquette_signed_area(&self) -> T {
         self.interiors()
             .iter()
-            .fold(ring_area(self.exterior()), |total, next| {
-                total - ring_area(next)
+            .fold(ring_area(&self.exterior().0), |total, next| {
+                total - ring_area(&next.0)
             })
     }
 
@@ -70,12 +70,12 @@ where
     }
 }
 
-fn ring_area<T>(coords: &LineString<T>) -> T
+fn ring_area<T>(coords: &[Coord<T>]) -> T
 where
     T: CoordFloat,
 {
     let mut total = T::zero();
-    let coords_len = coords.0.len();
+    let coords_len = coords.len();
 
     if coords_len > 2 {
         for i in 0..coords_len {
@@ -167,10 +167,23 @@ zero_impl!(LineString);
 zero_impl!(MultiPoint);
 zero_impl!(MultiLineString);
 to_polygon_impl!(Rect);
-to_polygon_impl!(Triangle);
 sum_impl!(GeometryCollection);
 sum_impl!(MultiPolygon);
 
+/// 直接根据三个顶点计算面积，无需为每个三角形分配一个 `Polygon`，适用于大量三角形（例如 TIN）。
+impl<T> ChamberlainDuquetteArea<T> for Triangle<T>
+where
+    T: CoordFloat,
+{
+    fn chamberlain_duquette_signed_area(&self) -> T {
+        ring_area(&[self.0, self.1, self.2, self.0])
+    }
+
+    fn chamberlain_duquette_unsigned_area(&self) -> T {
+        self.chamberlain_duquette_signed_area().abs()
+    }
+}
+
 impl<T> ChamberlainDuquetteArea<T> for Geometry<T>
 where
     T: CoordFloat,
@@ -184,7 +197,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::polygon;
+    use crate::{coord, polygon};
 
     #[test]
     fn test_negative() {
@@ -222,6 +235,28 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_triangle() {
+        let triangle = Triangle::new(
+            coord! { x: 125., y: -15. },
+            coord! { x: 144., y: -15. },
+            coord! { x: 154., y: -27. },
+        );
+        let polygon = triangle.to_polygon();
+        assert_eq!(
+            polygon.chamberlain_duquette_signed_area(),
+            triangle.chamberlain_duquette_signed_area()
+        );
+        assert!(triangle.chamberlain_duquette_signed_area() < 0.);
+
+        let reversed = Triangle::new(triangle.2, triangle.1, triangle.0);
+        assert_relative_eq!(
+            triangle.chamberlain_duquette_unsigned_area(),
+            reversed.chamberlain_duquette_signed_area(),
+            max_relative = 1e-12
+        );
+    }
+
     #[test]
     fn test_holes() {
         let poly = polygon![
@@ -0,0 +1,280 @@
+use crate::{
+    CoordNum, CoordsIter, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// 几何体的结构复杂度度量，计算成本很低。
+///
+/// 可用于为处理策略设定阈值，例如“顶点超过 1000 个时使用预处理的几何体”，
+/// 而无需针对每种几何类型编写 `match`。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, Complexity, Geometry};
+///
+/// let geometry: Geometry = wkt!(MULTIPOLYGON(
+///     ((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.)),
+///     ((20. 20.,30. 20.,30. 30.,20. 20.))
+/// ))
+/// .into();
+///
+/// assert_eq!(geometry.part_count(), 2);
+/// assert_eq!(geometry.ring_count(), 3);
+/// assert_eq!(geometry.vertex_count(), 13);
+/// ```
+pub trait Complexity {
+    /// 坐标的数量，与 [`CoordsIter::coords_count`] 相同：多边形闭合环的首尾坐标各计一次，
+    /// [`Rect`] 和 [`Triangle`] 只计算其角点。
+    fn vertex_count(&self) -> usize;
+
+    /// 所有多边形的环（外环和内环）的数量。[`Rect`] 和 [`Triangle`] 各有一个环，
+    /// 非面状几何体没有环。
+    fn ring_count(&self) -> usize;
+
+    /// 顶层组成部分的数量：多几何体和 [`GeometryCollection`] 为成员的数量（不递归），
+    /// 空的单一几何体为 0，其他单一几何体为 1。
+    fn part_count(&self) -> usize;
+}
+
+impl<T: CoordNum> Complexity for Point<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        0
+    }
+
+    fn part_count(&self) -> usize {
+        1
+    }
+}
+
+impl<T: CoordNum> Complexity for Line<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        0
+    }
+
+    fn part_count(&self) -> usize {
+        1
+    }
+}
+
+impl<T: CoordNum> Complexity for LineString<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        0
+    }
+
+    fn part_count(&self) -> usize {
+        usize::from(!self.0.is_empty())
+    }
+}
+
+impl<T: CoordNum> Complexity for Polygon<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        if self.exterior().0.is_empty() {
+            0
+        } else {
+            1 + self.interiors().len()
+        }
+    }
+
+    fn part_count(&self) -> usize {
+        usize::from(!self.exterior().0.is_empty())
+    }
+}
+
+impl<T: CoordNum> Complexity for Rect<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        1
+    }
+
+    fn part_count(&self) -> usize {
+        1
+    }
+}
+
+impl<T: CoordNum> Complexity for Triangle<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        1
+    }
+
+    fn part_count(&self) -> usize {
+        1
+    }
+}
+
+impl<T: CoordNum> Complexity for MultiPoint<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        0
+    }
+
+    fn part_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: CoordNum> Complexity for MultiLineString<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        0
+    }
+
+    fn part_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: CoordNum> Complexity for MultiPolygon<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        self.iter().map(Complexity::ring_count).sum()
+    }
+
+    fn part_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: CoordNum> Complexity for GeometryCollection<T> {
+    fn vertex_count(&self) -> usize {
+        self.coords_count()
+    }
+
+    fn ring_count(&self) -> usize {
+        self.iter().map(Complexity::ring_count).sum()
+    }
+
+    fn part_count(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: CoordNum> Complexity for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn vertex_count(&self) -> usize;
+        fn ring_count(&self) -> usize;
+        fn part_count(&self) -> usize;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt};
+
+    #[test]
+    fn single_geometries() {
+        let point: Geometry = wkt!(POINT(1. 2.)).into();
+        assert_eq!(
+            (point.vertex_count(), point.ring_count(), point.part_count()),
+            (1, 0, 1)
+        );
+
+        let line_string: Geometry = wkt!(LINESTRING(0. 0.,1. 1.,2. 0.)).into();
+        assert_eq!(
+            (
+                line_string.vertex_count(),
+                line_string.ring_count(),
+                line_string.part_count()
+            ),
+            (3, 0, 1)
+        );
+
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        assert_eq!(
+            (rect.vertex_count(), rect.ring_count(), rect.part_count()),
+            (4, 1, 1)
+        );
+
+        let triangle: Geometry = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 0., y: 1. },
+        )
+        .into();
+        assert_eq!(
+            (
+                triangle.vertex_count(),
+                triangle.ring_count(),
+                triangle.part_count()
+            ),
+            (3, 1, 1)
+        );
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let line_string = LineString::<f64>::new(vec![]);
+        assert_eq!(
+            (
+                line_string.vertex_count(),
+                line_string.ring_count(),
+                line_string.part_count()
+            ),
+            (0, 0, 0)
+        );
+
+        let polygon = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(
+            (
+                polygon.vertex_count(),
+                polygon.ring_count(),
+                polygon.part_count()
+            ),
+            (0, 0, 0)
+        );
+
+        // 包含空成员的多几何体仍然计算成员数量
+        let multi_polygon = MultiPolygon::new(vec![polygon]);
+        assert_eq!(multi_polygon.part_count(), 1);
+        assert_eq!(multi_polygon.ring_count(), 0);
+    }
+
+    #[test]
+    fn geometry_collection_is_not_recursive() {
+        let collection = GeometryCollection::new_from(vec![
+            wkt!(POINT(0. 0.)).into(),
+            wkt!(MULTIPOINT(0. 0.,1. 1.)).into(),
+            wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.),(0.1 0.1,0.2 0.1,0.2 0.2,0.1 0.1))).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                wkt!(POLYGON((5. 5.,6. 5.,6. 6.,5. 5.))).into(),
+            ])),
+        ]);
+
+        assert_eq!(collection.part_count(), 4);
+        assert_eq!(collection.ring_count(), 3);
+        assert_eq!(collection.vertex_count(), collection.coords_count());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::convex_hull::qhull;
+use crate::lex_order::lex_cmp;
 use crate::utils::partial_min;
 use crate::{
     coord, Centroid, Coord, CoordNum, Distance, Euclidean, GeoFloat, Length, Line, LineString,
@@ -13,6 +14,8 @@ use std::collections::VecDeque;
 /// 该实现受到 <https://github.com/mapbox/concaveman> 的启发
 /// 并使用了以下论文中的一些想法： www.iis.sinica.edu.tw/page/jise/2012/201205_10.pdf
 ///
+/// 重复的点只参与一次计算。空的输入以及所有点都重合的输入返回空多边形。
+///
 /// # 示例
 /// ```
 /// use geo::{line_string, polygon};
@@ -182,75 +185,152 @@ where
     }
 }
 
-// 这一函数在很大程度上得到了以下代码的启发:
-// https://github.com/mapbox/concaveman/blob/54838e1/index.js#L11
-fn concave_hull<T>(coords: &mut [Coord<T>], concavity: T) -> LineString<T>
+/// 对同一组点以不同的 `concavity` 多次计算凹包。
+///
+/// 构建时只计算一次凸包和内部点的空间索引；之后每次调用 [`HullBuilder::concave_hull`]
+/// 只需复制索引并执行凹化步骤。在调整 `concavity` 参数时，这比对同一几何体反复调用
+/// [`ConcaveHull::concave_hull`] 快得多，且结果与之相同。
+///
+/// # 示例
+/// ```
+/// use geo::{wkt, ConcaveHull, CoordsIter, HullBuilder};
+///
+/// let multi_point = wkt!(MULTIPOINT(0. 0.,4. 0.,4. 4.,3. 1.,3. 2.,0. 4.));
+/// let builder = HullBuilder::new(multi_point.coords_iter());
+///
+/// for concavity in [1.0, 2.0, 4.0] {
+///     assert_eq!(
+///         builder.concave_hull(concavity),
+///         multi_point.concave_hull(concavity)
+///     );
+/// }
+/// assert_eq!(builder.convex_hull().exterior().0.len(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HullBuilder<T>
 where
     T: GeoFloat + RTreeNum,
 {
-    let hull = qhull::quick_hull(coords);
+    convex_hull: LineString<T>,
+    // 不在凸包上的点；点数少于4时为 `None`，此时凹包即凸包
+    interior_points_tree: Option<RTree<Coord<T>>>,
+}
 
-    if coords.len() < 4 {
-        return hull;
+impl<T> HullBuilder<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    /// 由一组坐标构建，计算凸包和内部点的空间索引。
+    pub fn new(coords: impl IntoIterator<Item = Coord<T>>) -> Self {
+        let mut coords: Vec<Coord<T>> = coords.into_iter().collect();
+        Self::from_coords(&mut coords)
     }
 
-    // 获取不在壳体外部线串上的整个数据集中点
-    let hull_tree: RTree<Coord<T>> = RTree::bulk_load(hull.clone().0);
+    fn from_coords(coords: &mut [Coord<T>]) -> Self {
+        let mut convex_hull = qhull::quick_hull(coords);
+
+        // 所有点都重合时，`quick_hull` 返回只有两个相同坐标的退化环，此时凹包为空
+        if convex_hull.0.len() < 3 {
+            convex_hull = LineString::new(vec![]);
+        }
 
-    let interior_coords: Vec<Coord<T>> = coords
-        .iter()
-        .filter(|coord| !hull_tree.contains(coord))
-        .copied()
-        .collect();
-    let mut interior_points_tree: RTree<Coord<T>> = RTree::bulk_load(interior_coords);
-    let mut line_tree: RTree<Line<T>> = RTree::new();
+        if coords.len() < 4 {
+            return HullBuilder {
+                convex_hull,
+                interior_points_tree: None,
+            };
+        }
 
-    let mut concave_list: Vec<Point<T>> = vec![];
-    let lines = hull.lines();
-    let mut line_queue: VecDeque<Line<T>> = VecDeque::new();
+        // 获取不在壳体外部线串上的整个数据集中点
+        let hull_tree: RTree<Coord<T>> = RTree::bulk_load(convex_hull.clone().0);
 
-    for line in lines {
-        line_queue.push_back(line);
-        line_tree.insert(line);
+        let mut interior_coords: Vec<Coord<T>> = coords
+            .iter()
+            .filter(|coord| !hull_tree.contains(coord))
+            .copied()
+            .collect();
+        // 重复的内部点会在凹化时被多次选中，在结果中产生长度为零的边
+        interior_coords.sort_unstable_by(lex_cmp);
+        interior_coords.dedup();
+        HullBuilder {
+            convex_hull,
+            interior_points_tree: Some(RTree::bulk_load(interior_coords)),
+        }
     }
-    while let Some(line) = line_queue.pop_front() {
-        let edge_length = line.length::<Euclidean>();
-        let dist = edge_length / concavity;
-        let possible_closest_point = find_point_closest_to_line(
-            &interior_points_tree,
-            line,
-            dist,
-            edge_length,
-            concavity,
-            &line_tree,
-        );
 
-        if let Some(closest_point) = possible_closest_point {
-            interior_points_tree.remove(&closest_point);
-            line_tree.remove(&line);
-            let point = Point::new(closest_point.x, closest_point.y);
-            let start_line = Line::new(line.start_point(), point);
-            let end_line = Line::new(point, line.end_point());
-            line_tree.insert(start_line);
-            line_tree.insert(end_line);
-            line_queue.push_front(end_line);
-            line_queue.push_front(start_line);
-        } else {
-            // 确保我们不添加重复项
-            if concave_list.is_empty() || !concave_list.ends_with(&[line.start_point()]) {
-                concave_list.push(line.start_point());
+    /// 返回这组点的凸包。
+    pub fn convex_hull(&self) -> Polygon<T> {
+        Polygon::new(self.convex_hull.clone(), vec![])
+    }
+
+    /// 以给定的 `concavity` 计算凹包，结果与 [`ConcaveHull::concave_hull`] 相同。
+    pub fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        Polygon::new(self.concave_hull_exterior(concavity), vec![])
+    }
+
+    // 这一函数在很大程度上得到了以下代码的启发:
+    // https://github.com/mapbox/concaveman/blob/54838e1/index.js#L11
+    fn concave_hull_exterior(&self, concavity: T) -> LineString<T> {
+        let Some(interior_points_tree) = &self.interior_points_tree else {
+            return self.convex_hull.clone();
+        };
+        let mut interior_points_tree = interior_points_tree.clone();
+        let mut line_tree: RTree<Line<T>> = RTree::new();
+
+        let mut concave_list: Vec<Point<T>> = vec![];
+        let lines = self.convex_hull.lines();
+        let mut line_queue: VecDeque<Line<T>> = VecDeque::new();
+
+        for line in lines {
+            line_queue.push_back(line);
+            line_tree.insert(line);
+        }
+        while let Some(line) = line_queue.pop_front() {
+            let edge_length = line.length::<Euclidean>();
+            let dist = edge_length / concavity;
+            let possible_closest_point = find_point_closest_to_line(
+                &interior_points_tree,
+                line,
+                dist,
+                edge_length,
+                concavity,
+                &line_tree,
+            );
+
+            if let Some(closest_point) = possible_closest_point {
+                interior_points_tree.remove(&closest_point);
+                line_tree.remove(&line);
+                let point = Point::new(closest_point.x, closest_point.y);
+                let start_line = Line::new(line.start_point(), point);
+                let end_line = Line::new(point, line.end_point());
+                line_tree.insert(start_line);
+                line_tree.insert(end_line);
+                line_queue.push_front(end_line);
+                line_queue.push_front(start_line);
+            } else {
+                // 确保我们不添加重复项
+                if concave_list.is_empty() || !concave_list.ends_with(&[line.start_point()]) {
+                    concave_list.push(line.start_point());
+                }
+                concave_list.push(line.end_point());
             }
-            concave_list.push(line.end_point());
         }
+
+        concave_list.into()
     }
+}
 
-    concave_list.into()
+fn concave_hull<T>(coords: &mut [Coord<T>], concavity: T) -> LineString<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    HullBuilder::from_coords(coords).concave_hull_exterior(concavity)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{line_string, polygon};
+    use crate::{line_string, polygon, wkt};
     use geo_types::Coord;
 
     #[test]
@@ -375,6 +455,26 @@ mod test {
         assert_eq!(res.exterior(), &norway_concave_hull);
     }
 
+    #[test]
+    fn hull_builder_matches_concave_hull() {
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let builder = HullBuilder::new(norway.0.iter().copied());
+        for concavity in [0.5, 1.0, 2.0, 5.0] {
+            assert_eq!(
+                builder.concave_hull(concavity),
+                norway.concave_hull(concavity)
+            );
+        }
+        // 后续的计算不受之前计算的影响
+        assert_eq!(
+            builder.concave_hull(2.0).exterior(),
+            &geo_test_fixtures::norway_concave_hull::<f64>()
+        );
+
+        let builder = HullBuilder::new(vec![coord! { x: 0.0, y: 0.0 }, coord! { x: 1.0, y: 1.0 }]);
+        assert_eq!(builder.concave_hull(2.0), builder.convex_hull());
+    }
+
     #[test]
     fn concave_hull_linestring_test() {
         let linestring = line_string![
@@ -443,4 +543,32 @@ mod test {
         ];
         assert_eq!(res.exterior().0, correct);
     }
+
+    #[test]
+    fn concave_hull_multipoint_test() {
+        let multi_point = wkt!(MULTIPOINT(0. 0.,4. 0.,4. 4.,3. 1.,3. 2.));
+        assert_eq!(
+            multi_point.concave_hull(2.0),
+            line_string![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 3.0, y: 1.0), (x: 3.0, y: 2.0)]
+                .concave_hull(2.0)
+        );
+
+        // 重复的点不影响结果
+        let with_duplicates = wkt!(MULTIPOINT(0. 0.,4. 0.,3. 1.,4. 4.,3. 1.,3. 2.,0. 0.,3. 1.));
+        assert_eq!(
+            with_duplicates.concave_hull(2.0),
+            multi_point.concave_hull(2.0)
+        );
+    }
+
+    #[test]
+    fn concave_hull_degenerate_multipoint_test() {
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        assert_eq!(MultiPoint::<f64>::new(vec![]).concave_hull(2.0), empty);
+        assert_eq!(wkt!(MULTIPOINT(1. 1.)).concave_hull(2.0), empty);
+        assert_eq!(
+            wkt!(MULTIPOINT(1. 1.,1. 1.,1. 1.,1. 1.,1. 1.)).concave_hull(2.0),
+            empty
+        );
+    }
 }
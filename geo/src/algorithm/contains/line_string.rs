@@ -99,6 +99,12 @@ where
     }
 }
 
+/// 按照 DE-9IM 的语义，当 `rhs` 的每个点都位于 `self` 上（外部交集为空），
+/// 且 `rhs` 的内部与 `self` 的内部相交时，`self` 包含 `rhs`。
+///
+/// 这与 `self.relate(rhs).is_contains()` 的结果相同，但不需要构建完整的交集矩阵，
+/// 并且也适用于整数坐标。顶点不必相同：只要 `rhs` 的每条线段都被 `self` 覆盖即可，
+/// 即使该线段跨越 `self` 的多个顶点，或者方向相反。仅共享端点的线串不互相包含。
 impl<T> Contains<LineString<T>> for LineString<T>
 where
     T: GeoNum,
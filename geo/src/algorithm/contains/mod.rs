@@ -9,7 +9,7 @@
 ///
 /// ```
 /// use geo::Contains;
-/// use geo::{line_string, point, Polygon};
+/// use geo::{coord, line_string, point, Polygon};
 ///
 /// let line_string = line_string![
 ///     (x: 0., y: 0.),
@@ -29,6 +29,10 @@
 ///
 /// // 点包含在多边形中
 /// assert!(polygon.contains(&point!(x: 1., y: 1.)));
+///
+/// // 也可以直接检查坐标，无需包装为 `Point`；边界上的坐标不被包含
+/// assert!(polygon.contains(&coord! { x: 1., y: 1. }));
+/// assert!(!polygon.contains(&coord! { x: 2., y: 1. }));
 /// ```
 pub trait Contains<Rhs = Self> {
     fn contains(&self, rhs: &Rhs) -> bool;
@@ -269,6 +273,70 @@ mod test {
         assert!(!poly.contains(&Point::new(1.5, 1.)));
     }
 
+    #[test]
+    fn coord_in_polygon_matches_point_test() {
+        let linestring = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]);
+        let inner_linestring = LineString::from(vec![
+            [0.5, 0.5],
+            [1.5, 0.5],
+            [1.5, 1.5],
+            [0.5, 1.5],
+            [0.5, 0.5],
+        ]);
+        let poly = Polygon::new(linestring, vec![inner_linestring]);
+        let multipoly = MultiPolygon::new(vec![
+            poly.clone(),
+            Polygon::new(
+                LineString::from(vec![(3., 0.), (4., 0.), (4., 1.), (3., 0.)]),
+                vec![],
+            ),
+        ]);
+        let triangle = Triangle::from([(0., 0.), (2., 0.), (0., 2.)]);
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 2. });
+
+        let coords = [
+            // 内部
+            coord! { x: 0.25, y: 0.25 },
+            coord! { x: 3.75, y: 0.5 },
+            // 外环和内环的边界
+            coord! { x: 1., y: 0. },
+            coord! { x: 2., y: 1. },
+            coord! { x: 0.5, y: 1. },
+            coord! { x: 1., y: 1. },
+            // 顶点
+            coord! { x: 0., y: 0. },
+            coord! { x: 1.5, y: 1.5 },
+            // 孔内和外部
+            coord! { x: 1., y: 1.2 },
+            coord! { x: 2.1, y: 1. },
+            coord! { x: -1., y: -1. },
+        ];
+        for coord in coords {
+            let point = Point::from(coord);
+            assert_eq!(poly.contains(&coord), poly.contains(&point));
+            assert_eq!(poly.contains(&coord), poly.relate(&point).is_contains());
+            assert_eq!(multipoly.contains(&coord), multipoly.contains(&point));
+            assert_eq!(
+                multipoly.contains(&coord),
+                multipoly.relate(&point).is_contains()
+            );
+            assert_eq!(
+                triangle.contains(&coord),
+                triangle.relate(&point).is_contains()
+            );
+            assert_eq!(rect.contains(&coord), rect.relate(&point).is_contains());
+        }
+
+        assert!(poly.contains(&coord! { x: 0.25, y: 0.25 }));
+        assert!(!poly.contains(&coord! { x: 1., y: 0. }));
+        assert!(!poly.contains(&coord! { x: 1., y: 1.2 }));
+        assert!(multipoly.contains(&coord! { x: 3.75, y: 0.5 }));
+        assert!(triangle.contains(&coord! { x: 0.5, y: 0.5 }));
+        assert!(!triangle.contains(&coord! { x: 1., y: 1. }));
+        assert!(rect.contains(&coord! { x: 1., y: 1. }));
+        assert!(!rect.contains(&coord! { x: 2., y: 1. }));
+    }
+
     /// 测试：多边形中包含点
     #[test]
     fn empty_multipolygon_test() {
@@ -482,6 +550,41 @@ mod test {
         assert!(!linestring2.contains(&line0));
     }
 
+    #[test]
+    fn linestring_in_linestring_test() {
+        let assert_contains = |a: &LineString, b: &LineString, expected: bool| {
+            assert_eq!(a.contains(b), expected, "{a:?} contains {b:?}");
+            assert_eq!(a.relate(b).is_contains(), expected, "{a:?} relate {b:?}");
+        };
+
+        let long = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (4., 2.)]);
+
+        // 沿着较长线串的一部分，跨越一个顶点
+        let sub_path = LineString::from(vec![(1., 0.), (2., 0.), (2., 1.)]);
+        assert_contains(&long, &sub_path, true);
+        assert_contains(&sub_path, &long, false);
+
+        // 顶点不同，但点集是较长线串的子集
+        let sub_path_extra_vertices =
+            LineString::from(vec![(0.5, 0.), (1., 0.), (1.5, 0.), (2., 0.), (2., 2.)]);
+        assert_contains(&long, &sub_path_extra_vertices, true);
+
+        // 反方向的子路径
+        let reversed = LineString::from(vec![(3., 2.), (2., 2.), (2., 0.5)]);
+        assert_contains(&long, &reversed, true);
+
+        // 与自身
+        assert_contains(&long, &long, true);
+
+        // 仅共享端点
+        let sharing_endpoints = LineString::from(vec![(0., 0.), (0., 5.), (4., 5.), (4., 2.)]);
+        assert_contains(&long, &sharing_endpoints, false);
+
+        // 部分重叠后离开
+        let leaving = LineString::from(vec![(1., 0.), (2., 0.), (3., 0.)]);
+        assert_contains(&long, &leaving, false);
+    }
+
     #[test]
     fn integer_bounding_rects() {
         let p: Point<i32> = Point::new(10, 20);
@@ -13,10 +13,7 @@ where
     T: CoordNum,
 {
     fn contains(&self, coord: &Coord<T>) -> bool {
-        coord.x > self.min().x
-            && coord.x < self.max().x
-            && coord.y > self.min().y
-            && coord.y < self.max().y
+        self.contains_coord(coord)
     }
 }
 
@@ -36,10 +33,7 @@ where
     fn contains(&self, other: &Rect<T>) -> bool {
         // TODO: 检查退化矩形（即线或点）
         // LineString 的所有点必须在多边形内？
-        self.min().x <= other.min().x
-            && self.max().x >= other.max().x
-            && self.min().y <= other.min().y
-            && self.max().y >= other.max().y
+        self.contains_rect(other)
     }
 }
 
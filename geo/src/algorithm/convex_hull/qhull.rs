@@ -1,6 +1,6 @@
 use super::{swap_with_first_and_remove, trivial_hull};
 use crate::kernels::{Kernel, Orientation};
-use crate::utils::partition_slice;
+use crate::utils::{lex_cmp, partition_slice};
 use crate::{coord, Coord, GeoNum, LineString};
 
 // 判断 `p_c` 是否位于线段 `p_a` 到  `p_b` 的正侧。
@@ -79,6 +79,8 @@ where
         y: p_b.x - p_a.x,
     };
 
+    // 距离相同的点位于一条与 a-b 平行的直线上。按字典序打破平局，
+    // 选取其中的端点而非中间的共线点，使结果不依赖输入顺序。
     let furthest_idx = set
         .iter()
         .map(|pt| {
@@ -86,10 +88,10 @@ where
                 x: pt.x - p_a.x,
                 y: pt.y - p_a.y,
             };
-            p_orth.x * p_diff.x + p_orth.y * p_diff.y
+            (p_orth.x * p_diff.x + p_orth.y * p_diff.y, pt)
         })
         .enumerate()
-        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .max_by(|(_, (a, p)), (_, (b, q))| a.partial_cmp(b).unwrap().then_with(|| lex_cmp(p, q)))
         .unwrap()
         .0;
 
@@ -111,6 +113,36 @@ mod test {
     use super::*;
     use crate::IsConvex;
 
+    #[test]
+    fn quick_hull_collinear_ties_are_deterministic() {
+        // 上边与最小点和最大点的连线平行，其上的共线点到该连线的距离相同
+        let points = vec![
+            coord! { x: 0, y: 0 },
+            coord! { x: 5, y: -3 },
+            coord! { x: 10, y: 0 },
+            coord! { x: 2, y: 5 },
+            coord! { x: 4, y: 5 },
+            coord! { x: 6, y: 5 },
+            coord! { x: 8, y: 5 },
+            coord! { x: 3, y: 1 },
+        ];
+        let correct = vec![
+            coord! { x: 5, y: -3 },
+            coord! { x: 10, y: 0 },
+            coord! { x: 8, y: 5 },
+            coord! { x: 2, y: 5 },
+            coord! { x: 0, y: 0 },
+            coord! { x: 5, y: -3 },
+        ];
+        for rotation in 0..points.len() {
+            let mut v = points.clone();
+            v.rotate_left(rotation);
+            assert_eq!(quick_hull(&mut v).0, correct);
+            v.reverse();
+            assert_eq!(quick_hull(&mut v).0, correct);
+        }
+    }
+
     #[test]
     fn quick_hull_test1() {
         let mut v = vec![
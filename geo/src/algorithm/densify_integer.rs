@@ -0,0 +1,177 @@
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{Coord, GeoNum, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// 使用欧几里得距离对整数坐标的几何体进行密集化，插入的中间坐标会被四舍五入到最接近的整数。
+///
+/// [`Densify`](crate::Densify) 要求浮点坐标，因此无法直接用于整数几何体（例如瓦片坐标）。
+/// `densify_integer` 适用于任何 [`GeoNum`]，不需要将整个几何体转换为浮点数。
+///
+/// 每条线段被等分为 `ceil(length / max_segment_length)` 份，等分点再四舍五入到整数。
+/// 由于精确的等分点通常不是整数，**插入的点并不完全均匀**：相邻点之间的距离可能略有不同，
+/// 并且可能比 `max_segment_length` 最多长约 `√2`（每个端点各有半个单位的舍入误差）。
+/// 舍入后与前一个点重合的点会被丢弃。原有的顶点保持不变。
+///
+/// 对于浮点坐标，插入的点同样会被四舍五入到整数；如果不需要舍入，请使用 [`Densify`](crate::Densify)。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, DensifyInteger};
+///
+/// let line_string: geo::LineString<i32> = wkt!(LINESTRING(0 0,10 0,10 5));
+/// assert_eq!(
+///     line_string.densify_integer(4),
+///     // 10 被分为 3 段，等分点 3.33 和 6.67 分别舍入为 3 和 7
+///     wkt!(LINESTRING(0 0,3 0,7 0,10 0,10 3,10 5))
+/// );
+/// ```
+///
+/// # Panics
+///
+/// 如果 `max_segment_length` 不大于 0，则会触发 panic。
+pub trait DensifyInteger<T: GeoNum> {
+    type Output;
+
+    fn densify_integer(&self, max_segment_length: T) -> Self::Output;
+}
+
+/// 将 `start` 和 `end` 之间（不含端点）舍入后的中间坐标追加到 `coords` 中。
+fn densify_between<T: GeoNum>(
+    start: Coord<T>,
+    end: Coord<T>,
+    max_segment_length: f64,
+    coords: &mut Vec<Coord<T>>,
+) {
+    let to_f64 = |value: T| value.to_f64().expect("坐标无法表示为 f64");
+    let round =
+        |value: f64| -> T { NumCast::from(value.round()).expect("舍入后的坐标超出范围") };
+    let (x0, y0) = (to_f64(start.x), to_f64(start.y));
+    let (dx, dy) = (to_f64(end.x) - x0, to_f64(end.y) - y0);
+    let num_segments = (dx.hypot(dy) / max_segment_length)
+        .ceil()
+        .to_u64()
+        .expect("段数不合理");
+
+    for segment_num in 1..num_segments {
+        let ratio = segment_num as f64 / num_segments as f64;
+        let coord = Coord {
+            x: round(x0 + dx * ratio),
+            y: round(y0 + dy * ratio),
+        };
+        if coords.last() != Some(&coord) && coord != end {
+            coords.push(coord);
+        }
+    }
+}
+
+impl<T: GeoNum> DensifyInteger<T> for Line<T> {
+    type Output = LineString<T>;
+
+    fn densify_integer(&self, max_segment_length: T) -> Self::Output {
+        LineString::from(*self).densify_integer(max_segment_length)
+    }
+}
+
+impl<T: GeoNum> DensifyInteger<T> for LineString<T> {
+    type Output = Self;
+
+    fn densify_integer(&self, max_segment_length: T) -> Self::Output {
+        let max_segment_length = max_segment_length
+            .to_f64()
+            .expect("max_segment_length 无法表示为 f64");
+        assert!(max_segment_length > 0.0);
+
+        let Some(final_coord) = self.0.last() else {
+            return LineString::new(vec![]);
+        };
+
+        let mut coords = vec![];
+        for line in self.lines() {
+            coords.push(line.start);
+            densify_between(line.start, line.end, max_segment_length, &mut coords);
+        }
+        coords.push(*final_coord);
+        LineString::new(coords)
+    }
+}
+
+impl<T: GeoNum> DensifyInteger<T> for MultiLineString<T> {
+    type Output = Self;
+
+    fn densify_integer(&self, max_segment_length: T) -> Self::Output {
+        MultiLineString::new(
+            self.iter()
+                .map(|line_string| line_string.densify_integer(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoNum> DensifyInteger<T> for Polygon<T> {
+    type Output = Self;
+
+    fn densify_integer(&self, max_segment_length: T) -> Self::Output {
+        Polygon::new(
+            self.exterior().densify_integer(max_segment_length),
+            self.interiors()
+                .iter()
+                .map(|interior| interior.densify_integer(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoNum> DensifyInteger<T> for MultiPolygon<T> {
+    type Output = Self;
+
+    fn densify_integer(&self, max_segment_length: T) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.densify_integer(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn densify_integer_polygon() {
+        let polygon: Polygon<i64> = wkt!(POLYGON((0 0,6 0,6 6,0 0)));
+        assert_eq!(
+            polygon.densify_integer(2),
+            // 对角线长约 8.49，分为 5 段
+            wkt!(POLYGON((0 0,2 0,4 0,6 0,6 2,6 4,6 6,5 5,4 4,2 2,1 1,0 0)))
+        );
+    }
+
+    #[test]
+    fn densify_integer_segments_within_rounding() {
+        let line_string: LineString<i32> = wkt!(LINESTRING(-7 3,25 -14,31 40));
+        let max_segment_length = 5;
+        let densified = line_string.densify_integer(max_segment_length);
+
+        // 原有顶点保持不变
+        for coord in line_string.coords() {
+            assert!(densified.0.contains(coord));
+        }
+        for line in densified.lines() {
+            assert_ne!(line.start, line.end);
+            let (dx, dy) = (line.dx() as f64, line.dy() as f64);
+            assert!(dx.hypot(dy) <= max_segment_length as f64 + 2f64.sqrt());
+        }
+    }
+
+    #[test]
+    fn densify_integer_short_and_empty() {
+        let line_string: LineString<i32> = wkt!(LINESTRING(0 0,1 1));
+        assert_eq!(line_string.densify_integer(10), line_string);
+
+        let empty: LineString<i32> = wkt!(LINESTRING EMPTY);
+        assert_eq!(empty.densify_integer(1), empty);
+    }
+}
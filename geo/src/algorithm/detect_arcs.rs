@@ -0,0 +1,283 @@
+use crate::{Coord, GeoFloat, LineString, Vector2DOps};
+
+/// 构成一段圆弧所需的最少点数。任意三个不共线的点都在某个圆上，因此至少需要四个点
+/// 才能说明它们“确实”来自一段圆弧。
+const MIN_ARC_POINTS: usize = 4;
+
+/// [`DetectArcs`] 找到的一段圆弧。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArcSegment<T: GeoFloat> {
+    /// 圆弧第一个点在原始线串中的索引。
+    pub start_index: usize,
+    /// 圆弧最后一个点在原始线串中的索引（包含）。
+    pub end_index: usize,
+    /// 圆心。
+    pub center: Coord<T>,
+    /// 半径。
+    pub radius: T,
+    /// 起点相对于圆心的角度，以弧度表示，从 x 轴正方向逆时针测量，范围为 `[-π, π]`。
+    pub start_angle: T,
+    /// 从起点到终点扫过的角度，以弧度表示。逆时针为正，顺时针为负。
+    pub sweep_angle: T,
+}
+
+impl<T: GeoFloat> ArcSegment<T> {
+    /// 终点相对于圆心的角度，即 `start_angle + sweep_angle`（未归一化）。
+    pub fn end_angle(&self) -> T {
+        self.start_angle + self.sweep_angle
+    }
+}
+
+/// 在线串中查找近似位于同一圆弧上的连续点，即加密（densify）的逆操作。
+///
+/// 许多数据以加密后的折线存储圆弧。此方法找出这些点，返回每段圆弧的圆心、半径和角度，
+/// 以便导出到支持圆弧的格式（例如 CAD 格式）。
+///
+/// 一段圆弧至少包含四个连续的点，所有点到拟合圆的距离都不超过 `tolerance`，
+/// 转向一致（全部逆时针或全部顺时针），且总扫角小于一整圈。
+/// 在 `tolerance` 内可以视为直线的点不会被当作圆弧。
+///
+/// 使用贪心算法：从每个起点尽可能延长圆弧，拟合圆经过当前这段的首、中、尾三点。
+/// 相邻的圆弧可以共享端点。
+///
+/// # 示例
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{wkt, DetectArcs};
+///
+/// // 一段直线，接着一个以 (2, 0) 为圆心、半径为 2 的半圆
+/// let line_string = wkt!(LINESTRING(
+///     -3. 0.,
+///     0. 0.,
+///     0.5857864376269049 1.414213562373095,
+///     2. 2.,
+///     3.414213562373095 1.414213562373095,
+///     4. 0.
+/// ));
+///
+/// let arcs = line_string.detect_arcs(1e-9);
+/// assert_eq!(arcs.len(), 1);
+/// assert_eq!((arcs[0].start_index, arcs[0].end_index), (1, 5));
+/// assert_relative_eq!(arcs[0].center.x, 2.0, epsilon = 1e-9);
+/// assert_relative_eq!(arcs[0].center.y, 0.0, epsilon = 1e-9);
+/// assert_relative_eq!(arcs[0].radius, 2.0, epsilon = 1e-9);
+/// assert_relative_eq!(arcs[0].start_angle, std::f64::consts::PI, epsilon = 1e-9);
+/// // 顺时针
+/// assert_relative_eq!(arcs[0].sweep_angle, -std::f64::consts::PI, epsilon = 1e-9);
+/// ```
+pub trait DetectArcs<T: GeoFloat> {
+    fn detect_arcs(&self, tolerance: T) -> Vec<ArcSegment<T>>;
+}
+
+impl<T: GeoFloat> DetectArcs<T> for LineString<T> {
+    fn detect_arcs(&self, tolerance: T) -> Vec<ArcSegment<T>> {
+        let coords = &self.0;
+        let mut arcs = vec![];
+        let mut start = 0;
+        while start + MIN_ARC_POINTS <= coords.len() {
+            let mut best = None;
+            let mut end = start + MIN_ARC_POINTS - 1;
+            while end < coords.len() {
+                match fit_arc(&coords[start..=end], tolerance) {
+                    Some(arc) => best = Some((end, arc)),
+                    None => break,
+                }
+                end += 1;
+            }
+            match best {
+                Some((end, (center, radius, start_angle, sweep_angle))) => {
+                    arcs.push(ArcSegment {
+                        start_index: start,
+                        end_index: end,
+                        center,
+                        radius,
+                        start_angle,
+                        sweep_angle,
+                    });
+                    start = end;
+                }
+                None => start += 1,
+            }
+        }
+        arcs
+    }
+}
+
+/// 如果 `coords` 可以视为一段圆弧，返回 `(圆心, 半径, 起始角, 扫角)`。
+fn fit_arc<T: GeoFloat>(coords: &[Coord<T>], tolerance: T) -> Option<(Coord<T>, T, T, T)> {
+    let first = coords[0];
+    let last = coords[coords.len() - 1];
+
+    // 所有点都在首尾连线附近时是直线，不是圆弧
+    let chord = last - first;
+    let chord_length = chord.magnitude();
+    let is_straight = coords.iter().all(|coord| {
+        let deviation = if chord_length == T::zero() {
+            (*coord - first).magnitude()
+        } else {
+            chord.wedge_product(*coord - first).abs() / chord_length
+        };
+        deviation <= tolerance
+    });
+    if is_straight {
+        return None;
+    }
+
+    let center = circumcenter(first, coords[coords.len() / 2], last)?;
+    let radius = (first - center).magnitude();
+    if coords
+        .iter()
+        .any(|coord| ((*coord - center).magnitude() - radius).abs() > tolerance)
+    {
+        return None;
+    }
+
+    // 相邻点之间的角度变化必须同向，且总和小于一整圈
+    let angle_of = |coord: Coord<T>| {
+        let offset = coord - center;
+        offset.y.atan2(offset.x)
+    };
+    let pi = T::from(std::f64::consts::PI).unwrap();
+    let two_pi = pi + pi;
+    let mut sweep = T::zero();
+    let mut direction = T::zero();
+    for pair in coords.windows(2) {
+        let mut step = angle_of(pair[1]) - angle_of(pair[0]);
+        if step > pi {
+            step = step - two_pi;
+        } else if step < -pi {
+            step = step + two_pi;
+        }
+        if step == T::zero() {
+            // 重复的点
+            continue;
+        }
+        if direction == T::zero() {
+            direction = step.signum();
+        } else if step.signum() != direction {
+            return None;
+        }
+        sweep = sweep + step;
+    }
+    if sweep.abs() >= two_pi {
+        return None;
+    }
+
+    Some((center, radius, angle_of(first), sweep))
+}
+
+/// 经过三个点的圆的圆心。三点共线时返回 `None`。
+fn circumcenter<T: GeoFloat>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Option<Coord<T>> {
+    let ab = b - a;
+    let ac = c - a;
+    let d = (ab.wedge_product(ac)) * (T::one() + T::one());
+    if d == T::zero() {
+        return None;
+    }
+    let ab_sq = ab.dot_product(ab);
+    let ac_sq = ac.dot_product(ac);
+    let offset = Coord {
+        x: (ac.y * ab_sq - ab.y * ac_sq) / d,
+        y: (ab.x * ac_sq - ac.x * ab_sq) / d,
+    };
+    let center = a + offset;
+    (center.x.is_finite() && center.y.is_finite()).then_some(center)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Densify, Euclidean, Line};
+
+    fn arc(center: Coord, radius: f64, start_angle: f64, sweep_angle: f64, n: usize) -> Vec<Coord> {
+        (0..=n)
+            .map(|i| {
+                let angle = start_angle + sweep_angle * i as f64 / n as f64;
+                center
+                    + Coord {
+                        x: radius * angle.cos(),
+                        y: radius * angle.sin(),
+                    }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn detects_arc_between_straight_segments() {
+        let center = Coord { x: 10.0, y: 5.0 };
+        let mut coords = Line::new((0.0, 0.0), (7.0, 0.0))
+            .densify::<Euclidean>(1.0)
+            .0;
+        coords.pop();
+        // 从 (7, 0) 附近开始的逆时针四分之三圆弧
+        coords.extend(arc(center, 5.0, -2.2, 4.0, 20));
+        let arc_end = coords.len() - 1;
+        coords.push(coords[arc_end] + Coord { x: 0.0, y: 5.0 });
+
+        let arcs = LineString::new(coords).detect_arcs(1e-6);
+        assert_eq!(arcs.len(), 1);
+        let detected = arcs[0];
+        assert_eq!(detected.end_index, arc_end);
+        assert_eq!(detected.end_index - detected.start_index, 20);
+        assert_relative_eq!(detected.center, center, epsilon = 1e-9);
+        assert_relative_eq!(detected.radius, 5.0, epsilon = 1e-9);
+        assert_relative_eq!(detected.start_angle, -2.2, epsilon = 1e-9);
+        assert_relative_eq!(detected.sweep_angle, 4.0, epsilon = 1e-9);
+        assert_relative_eq!(detected.end_angle(), 1.8, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn tolerance() {
+        let center = Coord { x: 0.0, y: 0.0 };
+        let mut coords = arc(center, 100.0, 0.0, -1.0, 10);
+        // 扰动中间的点
+        coords[5].x += 0.01;
+        let line_string = LineString::new(coords);
+
+        assert_eq!(line_string.detect_arcs(0.1).len(), 1);
+        assert_eq!(line_string.detect_arcs(0.1)[0].end_index, 10);
+        assert!(line_string.detect_arcs(0.1)[0].sweep_angle < 0.0);
+        // 严格的容差下只能找到不包含扰动点的部分
+        for detected in line_string.detect_arcs(1e-6) {
+            assert!(detected.end_index <= 5 || detected.start_index >= 5);
+        }
+    }
+
+    #[test]
+    fn not_arcs() {
+        // 直线
+        assert!(wkt!(LINESTRING(0. 0.,1. 0.,2. 0.,3. 0.,4. 0.))
+            .detect_arcs(1e-9)
+            .is_empty());
+        // 转向交替的锯齿线
+        assert!(wkt!(LINESTRING(0. 0.,1. 1.,2. 0.,3. 1.,4. 0.))
+            .detect_arcs(1e-9)
+            .is_empty());
+        // 只有三个点
+        assert!(wkt!(LINESTRING(0. 0.,1. 1.,2. 0.))
+            .detect_arcs(1e-9)
+            .is_empty());
+        assert!(LineString::<f64>::new(vec![]).detect_arcs(1e-9).is_empty());
+    }
+
+    #[test]
+    fn closed_circle() {
+        let coords = arc(
+            Coord { x: 0.0, y: 0.0 },
+            1.0,
+            0.0,
+            std::f64::consts::TAU,
+            16,
+        );
+        let arcs = LineString::new(coords).detect_arcs(1e-9);
+        // 首尾重合时无法确定经过首、中、尾三点的圆，因此圆弧在闭合点之前结束
+        assert_eq!(arcs.len(), 1);
+        assert_eq!((arcs[0].start_index, arcs[0].end_index), (0, 15));
+        assert_relative_eq!(
+            arcs[0].sweep_angle,
+            std::f64::consts::TAU * 15.0 / 16.0,
+            epsilon = 1e-9
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::CoordsIter;
-use crate::{Coord, CoordNum};
+use crate::{Coord, CoordNum, MultiPolygon};
 
 /// 找到几何体的极端坐标和索引。
 ///
@@ -82,10 +82,104 @@ where
     }
 }
 
+/// 找到 [`MultiPolygon`] 的极端坐标，并同时给出它们来自哪个多边形。
+///
+/// 与 [`Extremes`] 一样只考虑外环。每个极值包含多边形在 [`MultiPolygon`] 中的索引，
+/// 以及坐标在该多边形外环中的索引。多个坐标并列为极值时，取最先出现的一个。
+/// 空的 [`MultiPolygon`] 返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use geo::extremes::ExtremesIndexed;
+/// use geo::wkt;
+///
+/// let multi_polygon = wkt!(MULTIPOLYGON(
+///     ((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)),
+///     ((5. 1.,6. 0.,7. 1.,6. 3.,5. 1.))
+/// ));
+///
+/// let extremes = multi_polygon.extremes_indexed().unwrap();
+///
+/// assert_eq!(extremes.x_max.polygon_index, 1);
+/// assert_eq!(extremes.x_max.index, 2);
+/// assert_eq!(extremes.y_max.polygon_index, 1);
+/// assert_eq!(extremes.y_max.index, 3);
+/// assert_eq!(extremes.x_min.polygon_index, 0);
+/// ```
+pub trait ExtremesIndexed<T: CoordNum> {
+    /// 计算极值坐标及其所属多边形的索引。
+    fn extremes_indexed(&self) -> Option<IndexedOutcome<T>>;
+}
+
+/// 表示一个极值坐标及其所属多边形的结构体
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexedExtreme<T: CoordNum> {
+    pub polygon_index: usize, // 多边形的索引
+    pub index: usize,         // 坐标在多边形外环中的索引
+    pub coord: Coord<T>,      // 坐标值
+}
+
+/// 包含四个带多边形索引的极值的结构体
+#[derive(Debug, PartialEq, Eq)]
+pub struct IndexedOutcome<T: CoordNum> {
+    pub x_min: IndexedExtreme<T>, // x轴最小值
+    pub y_min: IndexedExtreme<T>, // y轴最小值
+    pub x_max: IndexedExtreme<T>, // x轴最大值
+    pub y_max: IndexedExtreme<T>, // y轴最大值
+}
+
+impl<T: CoordNum> ExtremesIndexed<T> for MultiPolygon<T> {
+    fn extremes_indexed(&self) -> Option<IndexedOutcome<T>> {
+        let mut iter = self
+            .iter()
+            .enumerate()
+            .flat_map(|(polygon_index, polygon)| {
+                polygon
+                    .exterior()
+                    .coords()
+                    .enumerate()
+                    .map(move |(index, coord)| IndexedExtreme {
+                        polygon_index,
+                        index,
+                        coord: *coord,
+                    })
+            });
+
+        let first = iter.next()?;
+        let mut outcome = IndexedOutcome {
+            x_min: first,
+            y_min: first,
+            x_max: first,
+            y_max: first,
+        };
+
+        for extreme in iter {
+            if extreme.coord.x < outcome.x_min.coord.x {
+                outcome.x_min = extreme;
+            }
+
+            if extreme.coord.y < outcome.y_min.coord.y {
+                outcome.y_min = extreme;
+            }
+
+            if extreme.coord.x > outcome.x_max.coord.x {
+                outcome.x_max = extreme;
+            }
+
+            if extreme.coord.y > outcome.y_max.coord.y {
+                outcome.y_max = extreme;
+            }
+        }
+
+        Some(outcome)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{coord, polygon, MultiPoint};
+    use crate::{coord, polygon, wkt, MultiPoint};
 
     #[test]
     fn polygon() {
@@ -132,4 +226,54 @@ mod test {
 
         assert!(actual.is_none());
     }
+
+    #[test]
+    fn multi_polygon_indexed() {
+        let multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)),
+            ((5. 1.,6. -1.,7. 1.,6. 3.,5. 1.)),
+            ((-3. 0.,-2. 0.,-2. 1.,-3. 0.))
+        ));
+
+        let actual = multi_polygon.extremes_indexed().unwrap();
+
+        assert_eq!(
+            actual,
+            IndexedOutcome {
+                x_min: IndexedExtreme {
+                    polygon_index: 2,
+                    index: 0,
+                    coord: coord! { x: -3.0, y: 0.0 }
+                },
+                y_min: IndexedExtreme {
+                    polygon_index: 1,
+                    index: 1,
+                    coord: coord! { x: 6.0, y: -1.0 }
+                },
+                x_max: IndexedExtreme {
+                    polygon_index: 1,
+                    index: 2,
+                    coord: coord! { x: 7.0, y: 1.0 }
+                },
+                y_max: IndexedExtreme {
+                    polygon_index: 1,
+                    index: 3,
+                    coord: coord! { x: 6.0, y: 3.0 }
+                },
+            }
+        );
+
+        // 坐标与 `Extremes` 的结果一致
+        let extremes = multi_polygon.extremes().unwrap();
+        assert_eq!(actual.x_min.coord, extremes.x_min.coord);
+        assert_eq!(actual.y_min.coord, extremes.y_min.coord);
+        assert_eq!(actual.x_max.coord, extremes.x_max.coord);
+        assert_eq!(actual.y_max.coord, extremes.y_max.coord);
+    }
+
+    #[test]
+    fn multi_polygon_indexed_empty() {
+        let multi_polygon: MultiPolygon<f64> = MultiPolygon::new(vec![]);
+        assert!(multi_polygon.extremes_indexed().is_none());
+    }
 }
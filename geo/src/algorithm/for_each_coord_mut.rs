@@ -0,0 +1,198 @@
+use crate::{
+    Coord, CoordNum, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+};
+
+/// 就地修改几何图形的坐标，只需处理感兴趣的坐标。
+///
+/// 与 [`MapCoordsInPlace`](crate::MapCoordsInPlace) 不同，闭包接收 `&mut Coord<T>`，
+/// 不需要为每个坐标都返回一个新值，因此适合只修改少数顶点的场景，例如将特定顶点吸附到网格上。
+///
+/// [`Polygon`] 只允许通过闭包修改其环，以保证环始终闭合，因此这里同样使用闭包而不是可变迭代器。
+/// 对于闭合的环，闭包对每个不同的顶点调用一次（不包括闭合坐标），之后闭合坐标被设为修改后的第一个坐标，
+/// 所以坐标数量保持不变，环仍然闭合。开放的 [`LineString`] 的所有坐标都会被访问。
+///
+/// [`Rect`](crate::Rect) 和 [`Triangle`](crate::Triangle) 不以坐标序列存储，不支持此操作。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, ForEachCoordMut, Polygon};
+///
+/// let mut polygon: Polygon = wkt!(POLYGON(
+///     (0. 0.,10.2 0.,10. 10.,0. 10.,0. 0.),
+///     (2. 2.,4.1 2.,4. 3.9,2. 2.)
+/// ));
+///
+/// // 只将偏离网格的坐标吸附到整数网格上
+/// polygon.for_each_coord_mut(|coord| {
+///     if coord.x.fract() != 0. || coord.y.fract() != 0. {
+///         coord.x = coord.x.round();
+///         coord.y = coord.y.round();
+///     }
+/// });
+///
+/// assert_eq!(
+///     polygon,
+///     wkt!(POLYGON(
+///         (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+///         (2. 2.,4. 2.,4. 4.,2. 2.)
+///     ))
+/// );
+/// ```
+pub trait ForEachCoordMut<T: CoordNum> {
+    /// 对几何图形的每个坐标调用 `f`，`f` 可以就地修改坐标。
+    fn for_each_coord_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Coord<T>);
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for Coord<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        f(self)
+    }
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for Point<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        f(&mut self.0)
+    }
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for Line<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        f(&mut self.start);
+        f(&mut self.end);
+    }
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for LineString<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        if self.0.len() > 1 && self.is_closed() {
+            let last = self.0.len() - 1;
+            self.0[..last].iter_mut().for_each(&mut f);
+            self.0[last] = self.0[0];
+        } else {
+            self.0.iter_mut().for_each(f);
+        }
+    }
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for MultiPoint<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        self.iter_mut().for_each(|point| f(&mut point.0));
+    }
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for MultiLineString<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        self.iter_mut()
+            .for_each(|line_string| line_string.for_each_coord_mut(&mut f));
+    }
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for Polygon<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        self.exterior_mut(|exterior| exterior.for_each_coord_mut(&mut f));
+        self.interiors_mut(|interiors| {
+            interiors
+                .iter_mut()
+                .for_each(|interior| interior.for_each_coord_mut(&mut f))
+        });
+    }
+}
+
+impl<T: CoordNum> ForEachCoordMut<T> for MultiPolygon<T> {
+    fn for_each_coord_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Coord<T>),
+    {
+        self.iter_mut()
+            .for_each(|polygon| polygon.for_each_coord_mut(&mut f));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, CoordsIter};
+
+    #[test]
+    fn polygon_interior_ring() {
+        let mut polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (2. 2.,4. 2.,4. 4.,2. 2.)
+        ));
+
+        // 只移动内环的第一个顶点
+        let mut visited = 0;
+        polygon.for_each_coord_mut(|coord| {
+            visited += 1;
+            if *coord == coord! { x: 2., y: 2. } {
+                *coord = coord! { x: 3., y: 1. };
+            }
+        });
+
+        // 每个环的闭合坐标不会被单独访问
+        assert_eq!(visited, 4 + 3);
+        assert_eq!(
+            polygon,
+            wkt!(POLYGON(
+                (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+                (3. 1.,4. 2.,4. 4.,3. 1.)
+            ))
+        );
+        assert!(polygon.interiors()[0].is_closed());
+    }
+
+    #[test]
+    fn open_and_multi_geometries() {
+        let mut line_string = wkt!(LINESTRING(0. 0.,1. 1.,0. 0.5));
+        let mut visited = 0;
+        line_string.for_each_coord_mut(|coord| {
+            visited += 1;
+            coord.y += 1.;
+        });
+        assert_eq!(visited, 3);
+        assert_eq!(line_string, wkt!(LINESTRING(0. 1.,1. 2.,0. 1.5)));
+
+        let mut multi_point = wkt!(MULTIPOINT(0. 0.,1. 1.));
+        multi_point.for_each_coord_mut(|coord| coord.x = -coord.x);
+        assert_eq!(multi_point, wkt!(MULTIPOINT(-0. 0.,-1. 1.)));
+
+        let mut multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,1. 0.,1. 1.,0. 0.)),
+            ((5. 5.,6. 5.,6. 6.,5. 5.))
+        ));
+        let original_count = multi_polygon.coords_count();
+        multi_polygon.for_each_coord_mut(|coord| *coord = *coord * 2.);
+        assert_eq!(multi_polygon.coords_count(), original_count);
+        assert_eq!(
+            multi_polygon,
+            wkt!(MULTIPOLYGON(
+                ((0. 0.,2. 0.,2. 2.,0. 0.)),
+                ((10. 10.,12. 10.,12. 12.,10. 10.))
+            ))
+        );
+    }
+}
@@ -39,6 +39,41 @@ pub trait FrechetDistance<T, Rhs = Self> {
     ///
     /// [Frechet距离]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
     fn frechet_distance(&self, rhs: &Rhs) -> T;
+
+    /// 计算[Frechet距离]，并同时返回最优的耦合路径，可用于可视化两条轨迹之间的对齐方式。
+    ///
+    /// 路径是一系列 `(i, j)` 索引对，表示 `self` 的第 `i` 个坐标与 `rhs` 的第 `j` 个坐标配对。
+    /// 路径从 `(0, 0)` 开始，到 `(n - 1, m - 1)` 结束，每一步中两个索引各自增加 0 或 1（至少有一个增加）。
+    /// 路径上配对坐标之间的最大距离等于返回的距离，该距离与 [`frechet_distance`](Self::frechet_distance)
+    /// 的结果完全相同。最优路径不唯一时返回其中之一。
+    ///
+    /// 任一输入为空时，距离为 0，路径为空。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::FrechetDistance;
+    /// use geo::line_string;
+    ///
+    /// let line_string_a = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: 2., y: 0.),
+    /// ];
+    ///
+    /// let line_string_b = line_string![
+    ///     (x: 0., y: 1.),
+    ///     (x: 2., y: 1.),
+    /// ];
+    ///
+    /// let (distance, path) = line_string_a.frechet_distance_with_path(&line_string_b);
+    ///
+    /// assert_eq!(distance, line_string_a.frechet_distance(&line_string_b));
+    /// assert_eq!(path, vec![(0, 0), (1, 0), (2, 1)]);
+    /// ```
+    ///
+    /// [Frechet距离]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
+    fn frechet_distance_with_path(&self, rhs: &Rhs) -> (T, Vec<(usize, usize)>);
 }
 
 impl<T> FrechetDistance<T, LineString<T>> for LineString<T>
@@ -46,15 +81,19 @@ where
     T: GeoFloat + FromPrimitive,
 {
     fn frechet_distance(&self, ls: &LineString<T>) -> T {
-        if self.coords_count() != 0 && ls.coords_count() != 0 {
-            Data {
-                cache: vec![T::zero(); self.coords_count() * ls.coords_count()],
-                ls_a: self,
-                ls_b: ls,
+        match Data::new(self, ls) {
+            Some(mut data) => data.compute_linear(),
+            None => T::zero(),
+        }
+    }
+
+    fn frechet_distance_with_path(&self, ls: &LineString<T>) -> (T, Vec<(usize, usize)>) {
+        match Data::new(self, ls) {
+            Some(mut data) => {
+                let distance = data.compute_linear();
+                (distance, data.coupling_path())
             }
-            .compute_linear()
-        } else {
-            T::zero()
+            None => (T::zero(), vec![]),
         }
     }
 }
@@ -68,10 +107,22 @@ where
     ls_b: &'a LineString<T>,
 }
 
-impl<T> Data<'_, T>
+impl<'a, T> Data<'a, T>
 where
     T: GeoFloat + FromPrimitive,
 {
+    /// 为两条非空的 `LineString` 分配距离矩阵；任一输入为空时返回 `None`。
+    fn new(ls_a: &'a LineString<T>, ls_b: &'a LineString<T>) -> Option<Self> {
+        if ls_a.coords_count() == 0 || ls_b.coords_count() == 0 {
+            return None;
+        }
+        Some(Data {
+            cache: vec![T::zero(); ls_a.coords_count() * ls_b.coords_count()],
+            ls_a,
+            ls_b,
+        })
+    }
+
     /// [参考实现]: https://github.com/joaofig/discrete-frechet/tree/master
     fn compute_linear(&mut self) -> T {
         let columns_count = self.ls_b.coords_count();
@@ -94,6 +145,36 @@ where
 
         self.cache[self.cache.len() - 1]
     }
+
+    /// 从 `compute_linear` 填好的矩阵中回溯最优耦合路径。
+    fn coupling_path(&self) -> Vec<(usize, usize)> {
+        let columns_count = self.ls_b.coords_count();
+        let value = |i: usize, j: usize| self.cache[i * columns_count + j];
+
+        let mut i = self.ls_a.coords_count() - 1;
+        let mut j = columns_count - 1;
+        let mut path = vec![(i, j)];
+        while (i, j) != (0, 0) {
+            (i, j) = match (i, j) {
+                (_, 0) => (i - 1, 0),
+                (0, _) => (0, j - 1),
+                // 与 `compute_linear` 相同，选择值最小的前驱；相等时优先对角线
+                (_, _) => [(i - 1, j - 1), (i - 1, j), (i, j - 1)]
+                    .into_iter()
+                    .reduce(|best, candidate| {
+                        if value(candidate.0, candidate.1) < value(best.0, best.1) {
+                            candidate
+                        } else {
+                            best
+                        }
+                    })
+                    .expect("候选前驱不为空"),
+            };
+            path.push((i, j));
+        }
+        path.reverse();
+        path
+    }
 }
 
 #[cfg(test)]
@@ -160,4 +241,39 @@ mod test {
 
         assert_relative_eq!(ls.frechet_distance(&ls.clone()), 0.0);
     }
+
+    #[test]
+    fn test_frechet_distance_with_path() {
+        let ls_a = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (3., 3.), (5., 3.)]);
+        let ls_b = LineString::from(vec![(2., 2.), (0., 1.), (2., 4.), (3., 4.)]);
+
+        let (distance, path) = ls_a.frechet_distance_with_path(&ls_b);
+        assert_eq!(distance, ls_a.frechet_distance(&ls_b));
+
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 3)));
+        for window in path.windows(2) {
+            let (step_i, step_j) = (window[1].0 - window[0].0, window[1].1 - window[0].1);
+            assert!(step_i <= 1 && step_j <= 1 && step_i + step_j >= 1);
+        }
+        // 路径上的最大配对距离就是Frechet距离
+        let max_coupled = path
+            .iter()
+            .map(|&(i, j)| Euclidean::distance(ls_a.0[i], ls_b.0[j]))
+            .fold(0., f64::max);
+        assert_eq!(max_coupled, distance);
+    }
+
+    #[test]
+    fn test_frechet_distance_with_path_degenerate() {
+        let ls_a = LineString::from(vec![(1., 1.)]);
+        let ls_b = LineString::from(vec![(2., 2.), (0., 1.)]);
+        assert_eq!(
+            ls_a.frechet_distance_with_path(&ls_b),
+            (ls_a.frechet_distance(&ls_b), vec![(0, 0), (0, 1)])
+        );
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.frechet_distance_with_path(&ls_b), (0., vec![]));
+    }
 }
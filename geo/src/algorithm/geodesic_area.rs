@@ -190,14 +190,8 @@ fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool)
     };
 
     // 添加外环
-    let (outer_perimeter, outer_area) = {
-        let mut pa = PolygonArea::new(&g, exterior_winding);
-        poly.exterior().points().for_each(|p| {
-            pa.add_point(p.y(), p.x());
-        });
-        let (perimeter, area, _) = pa.compute(sign);
-        (perimeter, area)
-    };
+    let (outer_perimeter, outer_area) =
+        ring_perimeter_area(&g, poly.exterior().coords(), exterior_winding, sign);
 
     // 添加内环
     let (interior_perimeter, mut inner_area) = if exterior_only {
@@ -206,11 +200,7 @@ fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool)
         let mut inner_area = 0.;
         let mut inner_perimeter = 0.;
         poly.interiors().iter().for_each(|ring| {
-            let mut pa = PolygonArea::new(&g, interior_winding);
-            ring.points().for_each(|p| {
-                pa.add_point(p.y(), p.x());
-            });
-            let (perimeter, area, _) = pa.compute(sign);
+            let (perimeter, area) = ring_perimeter_area(&g, ring.coords(), interior_winding, sign);
             inner_area += area.abs();
             inner_perimeter += perimeter;
         });
@@ -227,6 +217,58 @@ fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool)
     )
 }
 
+fn ring_perimeter_area<'a>(
+    g: &Geodesic,
+    coords: impl Iterator<Item = &'a Coord>,
+    winding: Winding,
+    sign: bool,
+) -> (f64, f64) {
+    let mut pa = PolygonArea::new(g, winding);
+    coords.for_each(|c| {
+        pa.add_point(c.y, c.x);
+    });
+    let (perimeter, area, _) = pa.compute(sign);
+    (perimeter, area)
+}
+
+/// 直接根据三个顶点计算，无需为每个三角形分配一个 `Polygon`，适用于大量三角形（例如 TIN）。
+impl GeodesicArea<f64> for Triangle {
+    fn geodesic_perimeter(&self) -> f64 {
+        let (perimeter, _area) = self.geodesic_perimeter_area_signed();
+        perimeter
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        let (_perimeter, area) = self.geodesic_perimeter_area_signed();
+        area
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        let (_perimeter, area) = self.geodesic_perimeter_area_unsigned();
+        area
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        let coords = [self.0, self.1, self.2, self.0];
+        ring_perimeter_area(
+            &Geodesic::wgs84(),
+            coords.iter(),
+            Winding::CounterClockwise,
+            true,
+        )
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        let coords = [self.0, self.1, self.2, self.0];
+        ring_perimeter_area(
+            &Geodesic::wgs84(),
+            coords.iter(),
+            Winding::CounterClockwise,
+            false,
+        )
+    }
+}
+
 /// 生成结果为零的`GeodesicArea`实现。
 macro_rules! zero_impl {
     ($type:ident) => {
@@ -325,7 +367,6 @@ zero_impl!(LineString);
 zero_impl!(MultiPoint);
 zero_impl!(MultiLineString);
 to_polygon_impl!(Rect);
-to_polygon_impl!(Triangle);
 sum_impl!(GeometryCollection);
 sum_impl!(MultiPolygon);
 
@@ -343,7 +384,7 @@ impl GeodesicArea<f64> for Geometry<f64> {
 mod test {
     use super::*;
     use crate::algorithm::line_measures::{Geodesic, Length};
-    use crate::polygon;
+    use crate::{coord, polygon};
 
     #[test]
     fn test_negative() {
@@ -615,6 +656,38 @@ mod test {
         assert_relative_eq!(941333.0085011568, perimeter);
     }
 
+    #[test]
+    fn test_triangle() {
+        // 来自 TIN 的一个三角形，顶点为顺时针顺序
+        let triangle = Triangle::new(
+            coord! { x: 0.00388383, y: 51.501574 },
+            coord! { x: 0.00467777, y: 51.504181 },
+            coord! { x: 0.00553607, y: 51.503299 },
+        );
+        let polygon = triangle.to_polygon();
+
+        assert_eq!(
+            polygon.geodesic_perimeter_area_signed(),
+            triangle.geodesic_perimeter_area_signed()
+        );
+        assert_eq!(
+            polygon.geodesic_perimeter_area_unsigned(),
+            triangle.geodesic_perimeter_area_unsigned()
+        );
+        assert_eq!(polygon.geodesic_perimeter(), triangle.geodesic_perimeter());
+        assert!(triangle.geodesic_area_signed() < 0.);
+
+        let reversed = Triangle::new(triangle.2, triangle.1, triangle.0);
+        assert_relative_eq!(
+            -triangle.geodesic_area_signed(),
+            reversed.geodesic_area_signed()
+        );
+        assert_relative_eq!(
+            reversed.geodesic_area_unsigned(),
+            Geometry::from(reversed).geodesic_area_unsigned()
+        );
+    }
+
     #[test]
     fn test_very_large_polygon() {
         // 描述一个覆盖除这个小正方形外的整个地球的多边形。
@@ -17,6 +17,28 @@ where
     fn hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
     where
         Rhs: CoordsIter<Scalar = T>;
+
+    /// 从 `self` 到 `rhs` 的有向 Hausdorff 距离：`self` 中每个点到 `rhs` 中最近点的距离的最大值。
+    ///
+    /// 与 [`hausdorff_distance`](Self::hausdorff_distance) 不同，此距离不对称，可用于非对称的比较，
+    /// 例如检查 `self` 是否整体上靠近 `rhs`。对称的距离等于两个方向上有向距离的最大值。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{HausdorffDistance, MultiPoint};
+    ///
+    /// let a: MultiPoint = vec![(0., 0.), (1., 0.)].into();
+    /// let b: MultiPoint = vec![(0., 0.), (1., 0.), (5., 0.)].into();
+    ///
+    /// // `a` 的每个点都在 `b` 中，但 `b` 中的 (5, 0) 离 `a` 很远
+    /// assert_eq!(a.directed_hausdorff_distance(&b), 0.);
+    /// assert_eq!(b.directed_hausdorff_distance(&a), 4.);
+    /// assert_eq!(a.hausdorff_distance(&b), 4.);
+    /// ```
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
+    where
+        Rhs: CoordsIter<Scalar = T>;
 }
 
 impl<T, G> HausdorffDistance<T> for G
@@ -28,28 +50,22 @@ where
     where
         Rhs: CoordsIter<Scalar = T>,
     {
-        // 从 A -> B 计算距离
-        let hd1 = self
-            .coords_iter()
-            .map(|c| {
-                rhs.coords_iter()
-                    .map(|c2| Euclidean::distance(c, c2))
-                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
-            })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
+        // 取 A -> B 和 B -> A 两者最大值
+        self.directed_hausdorff_distance(rhs)
+            .max(rhs.directed_hausdorff_distance(self))
+    }
 
-        // 从 B -> A 计算距离
-        let hd2 = rhs
-            .coords_iter()
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        self.coords_iter()
             .map(|c| {
-                self.coords_iter()
+                rhs.coords_iter()
                     .map(|c2| Euclidean::distance(c, c2))
                     .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
             })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
-
-        // 取两者最大值
-        hd1.max(hd2)
+            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val))
     }
 }
 
@@ -67,12 +83,19 @@ where
     {
         Point::from(*self).hausdorff_distance(rhs)
     }
+
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        Point::from(*self).directed_hausdorff_distance(rhs)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::HausdorffDistance;
-    use crate::{line_string, polygon, MultiPoint, MultiPolygon};
+    use crate::{coord, line_string, polygon, MultiPoint, MultiPolygon, Point};
 
     #[test]
     fn hd_mpnt_mpnt() {
@@ -131,4 +154,37 @@ mod test {
             epsilon = 1.0e-6
         )
     }
+
+    #[test]
+    fn hd_is_max_of_directed() {
+        let p1: MultiPoint<f64> = vec![(0., 0.), (1., 2.)].into();
+        let poly = polygon![
+        (x: 1., y: -3.1), (x: 3.7, y: 2.7),
+        (x: 0.9, y: 7.6), (x: -4.8, y: 6.7),
+        (x: -7.5, y: 0.9), (x: -4.7, y: -4.),
+        (x: 1., y: -3.1)
+        ];
+        let lns = line_string![(x: 0., y: 0.), (x: 1., y: 2.), (x: 3., y: 1.)];
+
+        let directed = p1.directed_hausdorff_distance(&poly);
+        let reverse = poly.directed_hausdorff_distance(&p1);
+        assert_ne!(directed, reverse);
+        assert_eq!(p1.hausdorff_distance(&poly), directed.max(reverse));
+
+        // `p1` 的点都在 `lns` 上，反方向则不然
+        assert_eq!(p1.directed_hausdorff_distance(&lns), 0.);
+        assert_relative_eq!(lns.directed_hausdorff_distance(&p1), 5f64.sqrt());
+        assert_eq!(
+            lns.hausdorff_distance(&p1),
+            lns.directed_hausdorff_distance(&p1)
+                .max(p1.directed_hausdorff_distance(&lns))
+        );
+
+        let coord = coord! { x: 3., y: 1. };
+        assert_eq!(coord.directed_hausdorff_distance(&lns), 0.);
+        assert_relative_eq!(
+            lns.directed_hausdorff_distance(&Point::from(coord)),
+            10f64.sqrt()
+        );
+    }
 }
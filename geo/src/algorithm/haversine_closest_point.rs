@@ -234,6 +234,8 @@ where
     }
 }
 
+/// 点在多边形内部时返回 `Closest::Intersection(from)`；否则返回所有环（包括内环）上
+/// 最近的点。因此，位于洞内的点会得到洞边界上的点，而不是其本身。
 impl<T> HaversineClosestPoint<T> for Polygon<T>
 where
     T: GeoFloat + FromPrimitive,
@@ -270,8 +272,8 @@ where
                         rv = Closest::SinglePoint(pt);
                     }
                 }
-                // 也不会发生。
-                (Closest::Indeterminate, _) => unreachable!(),
+                // 退化的内环（例如空环）没有线段，忽略它
+                (Closest::Indeterminate, _) => {}
             }
         }
 
@@ -355,6 +357,7 @@ mod test {
     use wkt::TryFromWkt;
 
     use super::*;
+    use crate::wkt;
 
     #[test]
     fn point_to_point() {
@@ -602,6 +605,41 @@ mod test {
         }
     }
 
+    #[test]
+    fn point_to_poly_in_hole() {
+        let poly = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)
+        ));
+        let p_from = Point::new(5.0, 5.8);
+
+        if let Closest::SinglePoint(pt) = poly.haversine_closest_point(&p_from) {
+            assert_relative_eq!(pt, Point::new(5.0, 6.0), epsilon = 1.0e-2);
+        } else {
+            panic!("没有得到 Closest::SinglePoint！");
+        }
+
+        // 洞边界上的点
+        let on_hole_boundary = Point::new(4.0, 5.0);
+        assert_eq!(
+            poly.haversine_closest_point(&on_hole_boundary),
+            Closest::Intersection(on_hole_boundary)
+        );
+    }
+
+    #[test]
+    fn point_to_poly_with_degenerate_interior() {
+        let mut poly = wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)));
+        poly.interiors_push(LineString::new(vec![]));
+        let p_from = Point::new(12.0, 5.0);
+
+        if let Closest::SinglePoint(pt) = poly.haversine_closest_point(&p_from) {
+            assert_relative_eq!(pt, Point::new(10.0, 5.0), epsilon = 1.0e-1);
+        } else {
+            panic!("没有得到 Closest::SinglePoint！");
+        }
+    }
+
     #[test]
     fn point_to_multi_polygon() {
         let wkt = "MultiPolygon (((-10.99779296875000156 13.36373945312502087, -11.05049804687500092 13.85565351562501846,
@@ -3,8 +3,11 @@ use std::cmp::{Ordering, Reverse};
 use crate::algorithm::{
     bounding_rect::BoundingRect,
     centroid::Centroid,
+    contains::Contains,
     coords_iter::CoordsIter,
     dimensions::HasDimensions,
+    is_convex::IsConvex,
+    kernels::Orientation,
     line_intersection::LineIntersection,
     line_measures::{Distance, Euclidean},
     lines_iter::LinesIter,
@@ -21,6 +24,7 @@ use crate::GeoFloat;
 ///
 /// 对于多边形，此点通过绘制一条大致将多边形的边界框一分为二的直线，
 /// 再与多边形相交，并计算由此相交产生的最长线段的中点来确定。
+/// 没有内环的凸多边形是例外：其质心一定位于内部，因此直接返回质心。
 /// 对于线，如果线具有内部点，则返回最接近线的质心的非端点顶点，否则返回端点。
 ///
 /// 对于多几何体或组合，计算组成部分的内部点，并返回其中的一个
@@ -42,8 +46,9 @@ use crate::GeoFloat;
 ///     (x: -2., y: 1.),
 /// ];
 ///
+/// // 凸多边形直接返回质心
 /// assert_eq!(
-///     Some(point!(x: 1., y: 2.)),
+///     Some(point!(x: 1., y: 1.)),
 ///     polygon.interior_point(),
 /// );
 /// ```
@@ -142,6 +147,10 @@ fn polygon_interior_point_with_segment_length<T: GeoFloat>(
         return Some((polygon.exterior().0[0].into(), T::zero()));
     }
 
+    if let Some(result) = convex_polygon_interior_point(polygon) {
+        return Some(result);
+    }
+
     let two = T::one() + T::one();
 
     let bounds = polygon.bounding_rect()?;
@@ -238,6 +247,39 @@ where
     }
 }
 
+// 没有内环的凸多边形的质心一定位于其内部，计算代价也远低于扫描线方法。
+// 由于浮点数的舍入，极细长的多边形的质心可能落在边界上，此时仍然退回到扫描线方法。
+// 与扫描线方法一样，返回穿过该点的水平线段的长度，以便在多多边形中比较各个成员
+fn convex_polygon_interior_point<T: GeoFloat>(polygon: &Polygon<T>) -> Option<(Point<T>, T)> {
+    if !polygon.interiors().is_empty() {
+        return None;
+    }
+    if polygon.exterior().convex_orientation(true, None)? == Orientation::Collinear {
+        return None;
+    }
+    let centroid = polygon
+        .centroid()
+        .filter(|centroid| polygon.contains(centroid))?;
+
+    let y = centroid.y();
+    let (mut min_x, mut max_x) = (centroid.x(), centroid.x());
+    for line in polygon.exterior().lines() {
+        let (start, end) = (line.start, line.end);
+        if (start.y < y) == (end.y < y) && start.y != y && end.y != y {
+            continue;
+        }
+        let (x1, x2) = if start.y == end.y {
+            (start.x, end.x)
+        } else {
+            let x = start.x + (y - start.y) * (end.x - start.x) / (end.y - start.y);
+            (x, x)
+        };
+        min_x = min_x.min(x1).min(x2);
+        max_x = max_x.max(x1).max(x2);
+    }
+    Some((centroid, max_x - min_x))
+}
+
 impl<T> InteriorPoint for MultiPolygon<T>
 where
     T: GeoFloat,
@@ -604,6 +646,59 @@ mod test {
         let multipoly = MultiPolygon::new(vec![normal.clone(), flat]);
         assert_eq!(multipoly.interior_point(), normal.interior_point());
     }
+    #[test]
+    fn convex_polygon_fast_path() {
+        let shapes = [
+            // 三角形
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 1., y: 3.)],
+            // 顺时针方向的菱形
+            polygon![(x: 0., y: 1.), (x: 1., y: 2.), (x: 2., y: 1.), (x: 1., y: 0.)],
+            // 带有共线顶点的矩形
+            polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 2.), (x: 0., y: 2.)],
+            // 狭长的六边形
+            polygon![
+                (x: 0., y: 0.),
+                (x: 100., y: 0.1),
+                (x: 101., y: 0.5),
+                (x: 100., y: 1.),
+                (x: 0., y: 0.9),
+                (x: -1., y: 0.5),
+            ],
+        ];
+        for polygon in shapes {
+            let (fast, _length) = convex_polygon_interior_point(&polygon).unwrap();
+            assert_eq!(polygon.interior_point(), Some(fast));
+            assert_eq!(Some(fast), polygon.centroid());
+            assert!(polygon.contains(&fast));
+            // 多多边形与单个多边形使用同一捷径
+            let multi_polygon = MultiPolygon::new(vec![polygon.clone()]);
+            assert_eq!(multi_polygon.interior_point(), Some(fast));
+        }
+
+        // 得分是穿过质心的水平线段的长度
+        let rect = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 2.), (x: 0., y: 2.)];
+        assert_eq!(
+            convex_polygon_interior_point(&rect),
+            Some((point!(x: 5., y: 1.), 10.))
+        );
+        // 较宽的成员胜出
+        let narrow = polygon![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 2.), (x: 20., y: 2.)];
+        assert_eq!(
+            MultiPolygon::new(vec![narrow, rect]).interior_point(),
+            Some(point!(x: 5., y: 1.))
+        );
+
+        // 非凸多边形和带有内环的多边形不使用捷径
+        let concave = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 1.), (x: 0., y: 4.)];
+        assert!(convex_polygon_interior_point(&concave).is_none());
+        let with_hole = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        );
+        assert!(convex_polygon_interior_point(&with_hole).is_none());
+        assert!(with_hole.contains(&with_hole.interior_point().unwrap()));
+    }
+
     #[test]
     fn polygon_flat_interior_test() {
         let poly = Polygon::new(
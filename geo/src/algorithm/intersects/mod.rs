@@ -9,6 +9,10 @@ use crate::*;
 /// 这个谓词是对称的：`a.intersects(b)`当且仅当
 /// `b.intersects(a)`。
 ///
+/// 注意与[`Contains`](crate::Contains)的区别：`intersects`包含边界，
+/// 因此位于[`LineString`]任意位置（包括其端点）的点都与之相交；
+/// 而`contains`只考虑内部，开放[`LineString`]的端点属于其边界，不被其包含。
+///
 /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
 ///
 /// # 示例
@@ -34,6 +38,12 @@ use crate::*;
 ///
 /// assert!(line_string_a.intersects(&line_string_b));
 /// assert!(!line_string_a.intersects(&line_string_c));
+///
+/// // 端点与线串相交，但不被线串包含
+/// use geo::{point, Contains};
+/// let endpoint = point!(x: 3., y: 2.);
+/// assert!(endpoint.intersects(&line_string_a));
+/// assert!(!line_string_a.contains(&endpoint));
 /// ```
 pub trait Intersects<Rhs = Self> {
     fn intersects(&self, rhs: &Rhs) -> bool;
@@ -125,11 +135,11 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::Intersects;
     use crate::{
         coord, line_string, polygon, Geometry, Line, LineString, MultiLineString, MultiPoint,
-        MultiPolygon, Point, Polygon, Rect,
+        MultiPolygon, Point, Polygon, Rect, Triangle,
     };
+    use crate::{Contains, Intersects};
 
     /// 测试：LineString和LineString的相交
     #[test]
@@ -418,6 +428,72 @@ mod test {
             .intersects(&bounding_rect_sm.to_polygon()));
     }
     #[test]
+    fn rect_rect_matches_relate_test() {
+        use crate::Relate;
+
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. });
+        let cases = [
+            // 分离
+            (
+                Rect::new(coord! { x: 11., y: 0. }, coord! { x: 20., y: 10. }),
+                false,
+            ),
+            (
+                Rect::new(coord! { x: 0., y: -5. }, coord! { x: 10., y: -0.1 }),
+                false,
+            ),
+            // x 方向重叠但 y 方向分离
+            (
+                Rect::new(coord! { x: 5., y: 11. }, coord! { x: 15., y: 20. }),
+                false,
+            ),
+            // 边相接
+            (
+                Rect::new(coord! { x: 10., y: 2. }, coord! { x: 20., y: 8. }),
+                true,
+            ),
+            (
+                Rect::new(coord! { x: -5., y: 10. }, coord! { x: 15., y: 20. }),
+                true,
+            ),
+            // 角点相接
+            (
+                Rect::new(coord! { x: 10., y: 10. }, coord! { x: 20., y: 20. }),
+                true,
+            ),
+            (
+                Rect::new(coord! { x: -5., y: -5. }, coord! { x: 0., y: 0. }),
+                true,
+            ),
+            // 部分重叠
+            (
+                Rect::new(coord! { x: 5., y: 5. }, coord! { x: 15., y: 15. }),
+                true,
+            ),
+            // 完全包含与被包含
+            (
+                Rect::new(coord! { x: 2., y: 2. }, coord! { x: 8., y: 8. }),
+                true,
+            ),
+            (
+                Rect::new(coord! { x: -2., y: -2. }, coord! { x: 12., y: 12. }),
+                true,
+            ),
+        ];
+        for (other, expected) in cases {
+            assert_eq!(rect.intersects(&other), expected, "{other:?}");
+            assert_eq!(other.intersects(&rect), expected, "{other:?}");
+            // 与转换为多边形后的一般路径一致
+            assert_eq!(rect.to_polygon().intersects(&other.to_polygon()), expected);
+            assert_eq!(
+                rect.to_polygon()
+                    .relate(&other.to_polygon())
+                    .is_intersects(),
+                expected
+            );
+        }
+    }
+    #[test]
     fn point_intersects_line_test() {
         let p0 = Point::new(2., 4.);
         // 竖直线
@@ -450,6 +526,68 @@ mod test {
         assert!(!p0.intersects(&line7));
     }
     #[test]
+    fn multi_point_intersects_areal_test() {
+        let polygon: Polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.), (x: 1., y: 1.)]],
+        ];
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone()]);
+        let rect = Rect::new((0., 0.), (4., 4.));
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+
+        // 都在外面，或在多边形的洞里
+        let outside = MultiPoint::from(vec![(5., 5.), (-1., 2.), (2., 2.)]);
+        assert!(!outside.intersects(&polygon));
+        assert!(!polygon.intersects(&outside));
+        assert!(!outside.intersects(&multi_polygon));
+        assert!(!Geometry::MultiPoint(outside.clone()).intersects(&polygon));
+
+        // 只有最后一个点落在边界上
+        let boundary_hit = MultiPoint::from(vec![(5., 5.), (2., 2.), (4., 2.)]);
+        assert!(boundary_hit.intersects(&polygon));
+        assert!(boundary_hit.intersects(&multi_polygon));
+        assert!(boundary_hit.intersects(&rect));
+        assert!(multi_polygon.intersects(&boundary_hit));
+        assert!(rect.intersects(&boundary_hit));
+
+        // (2, 2) 在三角形的斜边上
+        assert!(outside.intersects(&triangle));
+        assert!(triangle.intersects(&outside));
+        assert!(!MultiPoint::from(vec![(3., 3.), (5., 0.)]).intersects(&triangle));
+
+        assert!(!MultiPoint::<f64>::new(vec![]).intersects(&polygon));
+    }
+    #[test]
+    fn point_intersects_linestring_endpoints_test() {
+        let line_string = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.)]);
+        let first = Point::new(0., 0.);
+        let last = Point::new(2., 2.);
+        let vertex = Point::new(2., 0.);
+        let on_segment = Point::new(1., 0.);
+        let off_line = Point::new(1., 1.);
+
+        // 边界（端点）也参与相交
+        assert!(first.intersects(&line_string));
+        assert!(line_string.intersects(&first));
+        assert!(last.intersects(&line_string));
+        assert!(line_string.intersects(&last));
+        assert!(vertex.intersects(&line_string));
+        assert!(on_segment.intersects(&line_string));
+        assert!(!off_line.intersects(&line_string));
+        assert!(!line_string.intersects(&off_line));
+
+        // 与 `Contains` 不同：端点不在内部
+        assert!(!line_string.contains(&first));
+        assert!(!line_string.contains(&last));
+        assert!(line_string.contains(&vertex));
+        assert!(line_string.contains(&on_segment));
+
+        // 闭合线串没有边界，所有顶点都在内部
+        let ring = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 0.)]);
+        assert!(first.intersects(&ring));
+        assert!(ring.contains(&first));
+    }
+    #[test]
     fn line_intersects_line_test() {
         let line0 = Line::from([(0., 0.), (3., 4.)]);
         let line1 = Line::from([(2., 0.), (2., 5.)]);
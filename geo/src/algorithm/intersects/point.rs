@@ -14,6 +14,9 @@ where
 }
 
 // 从 Point<T> 的 blanket 实现
+//
+// 对于面状几何体（Polygon、MultiPolygon、Rect、Triangle），每个点走点在多边形内的快速判断，
+// 并在第一个位于内部或边界上的点处立即返回，不会对整个多点集合做 Relate。
 impl<T, G> Intersects<G> for MultiPoint<T>
 where
     T: CoordNum,
@@ -17,6 +17,7 @@ symmetric_intersects_impl!(Coord<T>, Rect<T>);
 symmetric_intersects_impl!(Rect<T>, Point<T>);
 symmetric_intersects_impl!(Rect<T>, MultiPoint<T>);
 
+// 常数时间的区间重叠检查，不需要转换为多边形。边或角点相接也视为相交，与 DE-9IM 的 intersects 一致。
 impl<T> Intersects<Rect<T>> for Rect<T>
 where
     T: CoordNum,
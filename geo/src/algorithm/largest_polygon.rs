@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+
+use crate::{Area, CoordFloat, MultiPolygon, Polygon};
+
+/// 按面积（无符号）选择或排序 [`MultiPolygon`] 的成员多边形。
+///
+/// 例如，一个由大陆和许多岛屿组成的国家，其大陆就是面积最大的多边形。
+/// 面积相同的多边形保持原有的相对顺序。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, LargestPolygon};
+///
+/// let mut multi_polygon = wkt!(MULTIPOLYGON(
+///     ((0. 0.,1. 0.,1. 1.,0. 0.)),
+///     ((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)),
+///     ((10. 10.,12. 10.,12. 12.,10. 12.,10. 10.))
+/// ));
+///
+/// assert_eq!(
+///     multi_polygon.largest_polygon(),
+///     Some(&wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))))
+/// );
+/// assert_eq!(
+///     multi_polygon.nth_largest_polygon(1),
+///     Some(&wkt!(POLYGON((10. 10.,12. 10.,12. 12.,10. 12.,10. 10.))))
+/// );
+/// assert_eq!(multi_polygon.nth_largest_polygon(3), None);
+///
+/// multi_polygon.sort_by_area();
+/// assert_eq!(
+///     multi_polygon.0[2],
+///     wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)))
+/// );
+/// ```
+pub trait LargestPolygon<T: CoordFloat> {
+    /// 返回面积最大的多边形。如果有多个，返回第一个。如果没有成员，返回 `None`。
+    fn largest_polygon(&self) -> Option<&Polygon<T>>;
+
+    /// 返回面积第 `n` 大的多边形（从 0 开始计数，`n == 0` 即面积最大的多边形）。
+    /// 如果成员数量不超过 `n`，返回 `None`。
+    fn nth_largest_polygon(&self, n: usize) -> Option<&Polygon<T>>;
+
+    /// 将成员多边形按面积从大到小排序（稳定排序）。
+    fn sort_by_area(&mut self);
+}
+
+impl<T: CoordFloat> LargestPolygon<T> for MultiPolygon<T> {
+    fn largest_polygon(&self) -> Option<&Polygon<T>> {
+        self.iter()
+            .map(|polygon| (polygon.unsigned_area(), polygon))
+            .reduce(|largest, candidate| {
+                if cmp_descending(candidate.0, largest.0).is_lt() {
+                    candidate
+                } else {
+                    largest
+                }
+            })
+            .map(|(_, polygon)| polygon)
+    }
+
+    fn nth_largest_polygon(&self, n: usize) -> Option<&Polygon<T>> {
+        let areas: Vec<T> = self.iter().map(Area::unsigned_area).collect();
+        let mut order: Vec<usize> = (0..areas.len()).collect();
+        order.sort_by(|&a, &b| cmp_descending(areas[a], areas[b]));
+        order.get(n).map(|&index| &self.0[index])
+    }
+
+    fn sort_by_area(&mut self) {
+        let mut polygons: Vec<(T, Polygon<T>)> = std::mem::take(&mut self.0)
+            .into_iter()
+            .map(|polygon| (polygon.unsigned_area(), polygon))
+            .collect();
+        polygons.sort_by(|(a, _), (b, _)| cmp_descending(*a, *b));
+        self.0 = polygons.into_iter().map(|(_, polygon)| polygon).collect();
+    }
+}
+
+fn cmp_descending<T: CoordFloat>(a: T, b: T) -> Ordering {
+    b.partial_cmp(&a).unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn largest_polygon_ignores_orientation_and_counts_holes() {
+        let multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            // 顺时针的外环，面积为 16 - 9 = 7
+            ((0. 0.,0. 4.,4. 4.,4. 0.,0. 0.),(0.5 0.5,3.5 0.5,3.5 3.5,0.5 3.5,0.5 0.5)),
+            ((10. 0.,13. 0.,13. 3.,10. 3.,10. 0.))
+        ));
+        assert_eq!(multi_polygon.largest_polygon(), Some(&multi_polygon.0[1]));
+        assert_eq!(
+            multi_polygon.nth_largest_polygon(1),
+            Some(&multi_polygon.0[0])
+        );
+    }
+
+    #[test]
+    fn largest_polygon_ties_keep_order() {
+        let mut multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)),
+            ((5. 0.,6. 0.,6. 1.,5. 1.,5. 0.)),
+            ((9. 0.,11. 0.,11. 2.,9. 2.,9. 0.))
+        ));
+        let original = multi_polygon.clone();
+        assert_eq!(multi_polygon.nth_largest_polygon(1), Some(&original.0[0]));
+        assert_eq!(multi_polygon.nth_largest_polygon(2), Some(&original.0[1]));
+
+        multi_polygon.sort_by_area();
+        assert_eq!(
+            multi_polygon.0,
+            vec![
+                original.0[2].clone(),
+                original.0[0].clone(),
+                original.0[1].clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn largest_polygon_empty() {
+        let mut empty = MultiPolygon::<f64>::new(vec![]);
+        assert_eq!(empty.largest_polygon(), None);
+        assert_eq!(empty.nth_largest_polygon(0), None);
+        empty.sort_by_area();
+        assert!(empty.0.is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+
+use crate::{Coord, CoordNum};
+
+/// 按字典顺序比较两个坐标：先按 x 坐标比较，如相等则比较 y 坐标。
+///
+/// 这是一个全序：`nan` 分量被视为大于任何其他值，并且与另一个 `nan` 相等，
+/// 因此即使坐标中含有 `nan`，也可以安全地用于排序。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{coord, lex_cmp};
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(lex_cmp(&coord! { x: 0., y: 5. }, &coord! { x: 1., y: 0. }), Ordering::Less);
+/// assert_eq!(lex_cmp(&coord! { x: 1., y: 5. }, &coord! { x: 1., y: 0. }), Ordering::Greater);
+///
+/// let mut coords = vec![
+///     coord! { x: f64::NAN, y: 0. },
+///     coord! { x: 1., y: 1. },
+///     coord! { x: 1., y: 0. },
+/// ];
+/// coords.sort_by(lex_cmp);
+/// assert_eq!(coords[0], coord! { x: 1., y: 0. });
+/// assert_eq!(coords[1], coord! { x: 1., y: 1. });
+/// assert!(coords[2].x.is_nan());
+/// ```
+#[inline]
+pub fn lex_cmp<T: CoordNum>(p: &Coord<T>, q: &Coord<T>) -> Ordering {
+    total_cmp(&p.x, &q.x).then_with(|| total_cmp(&p.y, &q.y))
+}
+
+/// 返回迭代器中按 [`lex_cmp`] 字典顺序最小的坐标。如果迭代器为空，返回 `None`。
+///
+/// 当有多个相等的最小坐标时，返回第一个。
+///
+/// 如需按分量分别求最小值，请使用 [`BoundingRect`](crate::BoundingRect)。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{coord, min_coord, wkt};
+///
+/// let ring = wkt!(LINESTRING(2. 0.,0. 2.,0. 1.,2. 0.));
+/// assert_eq!(min_coord(ring.coords().copied()), Some(coord! { x: 0., y: 1. }));
+/// ```
+pub fn min_coord<T: CoordNum>(coords: impl IntoIterator<Item = Coord<T>>) -> Option<Coord<T>> {
+    coords.into_iter().min_by(lex_cmp)
+}
+
+/// 返回迭代器中按 [`lex_cmp`] 字典顺序最大的坐标。如果迭代器为空，返回 `None`。
+///
+/// 当有多个相等的最大坐标时，返回最后一个。
+///
+/// 如需按分量分别求最大值，请使用 [`BoundingRect`](crate::BoundingRect)。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{coord, max_coord, wkt};
+///
+/// let ring = wkt!(LINESTRING(2. 0.,0. 2.,2. 1.,2. 0.));
+/// assert_eq!(max_coord(ring.coords().copied()), Some(coord! { x: 2., y: 1. }));
+/// ```
+pub fn max_coord<T: CoordNum>(coords: impl IntoIterator<Item = Coord<T>>) -> Option<Coord<T>> {
+    coords.into_iter().max_by(lex_cmp)
+}
+
+/// 对只实现了 `PartialOrd` 的值的全序比较，不可比较的值（`nan`）排在最后。
+#[inline]
+fn total_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| {
+        let is_nan = |v: &T| v.partial_cmp(v).is_none();
+        is_nan(a).cmp(&is_nan(b))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord;
+
+    #[test]
+    fn min_max_coord() {
+        let coords = vec![
+            coord! { x: 1., y: 2. },
+            coord! { x: 0., y: 3. },
+            coord! { x: 0., y: 1. },
+            coord! { x: 1., y: 4. },
+        ];
+        assert_eq!(min_coord(coords.clone()), Some(coord! { x: 0., y: 1. }));
+        assert_eq!(max_coord(coords), Some(coord! { x: 1., y: 4. }));
+
+        let empty: Vec<Coord<f64>> = vec![];
+        assert_eq!(min_coord(empty.clone()), None);
+        assert_eq!(max_coord(empty), None);
+    }
+
+    #[test]
+    fn lex_cmp_nan() {
+        let nan = coord! { x: f64::NAN, y: 0. };
+        let finite = coord! { x: f64::INFINITY, y: 0. };
+        assert_eq!(lex_cmp(&nan, &finite), Ordering::Greater);
+        assert_eq!(lex_cmp(&finite, &nan), Ordering::Less);
+        assert_eq!(lex_cmp(&nan, &nan), Ordering::Equal);
+
+        let nan_y = coord! { x: 0., y: f64::NAN };
+        assert_eq!(lex_cmp(&nan_y, &coord! { x: 0., y: 1. }), Ordering::Greater);
+
+        // 含 nan 时不会 panic
+        let coords = vec![nan, coord! { x: 3., y: 3. }, nan_y];
+        let min = min_coord(coords).unwrap();
+        assert_eq!(min.x, 0.);
+        assert!(min.y.is_nan());
+    }
+
+    #[test]
+    fn integer_coords() {
+        let coords = vec![coord! { x: 2, y: 1 }, coord! { x: 2, y: -1 }];
+        assert_eq!(min_coord(coords.clone()), Some(coord! { x: 2, y: -1 }));
+        assert_eq!(max_coord(coords), Some(coord! { x: 2, y: 1 }));
+    }
+}
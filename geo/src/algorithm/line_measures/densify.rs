@@ -31,7 +31,8 @@ use num_traits::FromPrimitive;
 /// assert_relative_eq!(densified, expected_output);
 ///```
 ///
-/// 对于经度/纬度几何图形，考虑使用其他[度量空间]，如 [`Haversine`](crate::Haversine) 或 [`Geodesic`](crate::Geodesic)。
+/// 对于经度/纬度几何图形，考虑使用其他[度量空间]，如 [`Haversine`](crate::Haversine)、[`Geodesic`](crate::Geodesic)
+/// 或 [`Rhumb`](crate::Rhumb)（沿恒向线加密）。
 ///
 /// ```
 /// # use approx::assert_relative_eq;
@@ -56,7 +57,103 @@ use num_traits::FromPrimitive;
 /// [度量空间]: crate::line_measures::metric_spaces
 pub trait Densify<F: CoordFloat> {
     type Output;
+
     fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+    {
+        self.densify_with::<MetricSpace, _>(|_line| max_segment_length)
+    }
+
+    /// 与 [`densify`](Self::densify) 相同，但每条线段的最大长度由 `max_segment_length` 闭包根据该线段决定，
+    /// 从而可以进行自适应的加密，例如在赤道附近更密、在两极附近更疏。
+    ///
+    /// 闭包对每条原始线段调用一次，返回值的单位取决于[度量空间]，且必须大于0。
+    /// 多边形的环在加密后仍然保持闭合。
+    ///
+    /// # 示例
+    /// ```
+    /// use geo::{wkt, Densify};
+    /// use geo::line_measures::Euclidean;
+    ///
+    /// let line_string = wkt!(LINESTRING(0.0 0.0,4.0 0.0,4.0 4.0));
+    ///
+    /// // 水平线段每 1 个单位一个点，其余线段每 2 个单位一个点
+    /// let densified = line_string.densify_with::<Euclidean, _>(|line| {
+    ///     if line.start.y == line.end.y { 1.0 } else { 2.0 }
+    /// });
+    /// assert_eq!(
+    ///     densified,
+    ///     wkt!(LINESTRING(0.0 0.0,1.0 0.0,2.0 0.0,3.0 0.0,4.0 0.0,4.0 2.0,4.0 4.0))
+    /// );
+    /// ```
+    /// [度量空间]: crate::line_measures::metric_spaces
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F;
+}
+
+/// 与 [`Densify`] 一样插入附加点，但同时返回每个输出点在原始线上按弧长计算的归一化位置（`[0, 1]`）。
+/// 有了这个分数，就可以在各个顶点的属性（例如时间戳、高程）之间自行线性插值。
+///
+/// ## 单位
+/// - `max_segment_length` 的单位取决于实现的[度量空间]。它必须大于0。
+/// - 弧长使用同一[度量空间]计算。
+///
+/// # 示例
+/// ```
+/// use geo::{wkt, point, DensifyWithFractions};
+/// use geo::line_measures::Euclidean;
+///
+/// let line_string = wkt!(LINESTRING(0.0 0.0,0.0 6.0,2.0 6.0));
+///
+/// let densified = line_string.densify_with_fractions::<Euclidean>(3.0);
+/// assert_eq!(
+///     densified,
+///     vec![
+///         (point!(x: 0.0, y: 0.0), 0.0),
+///         (point!(x: 0.0, y: 3.0), 0.375),
+///         (point!(x: 0.0, y: 6.0), 0.75),
+///         (point!(x: 2.0, y: 6.0), 1.0),
+///     ]
+/// );
+/// ```
+/// [度量空间]: crate::line_measures::metric_spaces
+pub trait DensifyWithFractions<F: CoordFloat> {
+    fn densify_with_fractions<MetricSpace>(&self, max_segment_length: F) -> Vec<(Point<F>, F)>
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>;
+}
+
+/// 与 [`Densify`] 一样插入附加点，但只加密 `segment_indices` 中列出的线段，其余线段保持不变。
+///
+/// 第 `i` 条线段连接第 `i` 和第 `i + 1` 个坐标。超出范围的索引会被忽略，重复的索引只生效一次。
+/// 原始坐标全部保留，因此输出仍然是有效的线串。
+///
+/// ## 单位
+/// - `max_segment_length` 的单位取决于实现的[度量空间]。它必须大于0。
+///
+/// # 示例
+/// ```
+/// use geo::{wkt, DensifySegments};
+/// use geo::line_measures::Euclidean;
+///
+/// let line_string = wkt!(LINESTRING(0.0 0.0,0.0 4.0,4.0 4.0));
+///
+/// let densified = line_string.densify_segments::<Euclidean>(2.0, &[1]);
+/// assert_eq!(densified, wkt!(LINESTRING(0.0 0.0,0.0 4.0,2.0 4.0,4.0 4.0)));
+/// ```
+/// [度量空间]: crate::line_measures::metric_spaces
+pub trait DensifySegments<F: CoordFloat> {
+    fn densify_segments<MetricSpace>(
+        &self,
+        max_segment_length: F,
+        segment_indices: &[usize],
+    ) -> LineString<F>
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>;
 }
@@ -69,9 +166,29 @@ pub(crate) fn densify_between<F, MetricSpace>(
 ) where
     F: CoordFloat + FromPrimitive,
     MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+{
+    densify_segment::<F, MetricSpace>(
+        line_start,
+        line_end,
+        MetricSpace::distance(line_start, line_end),
+        max_segment_length,
+        |point, _ratio| container.push(point),
+    );
+}
+
+/// 对长度为 `segment_length` 的线段插入中间点（不含端点），并将每个点及其在该线段上的比例传给 `push`。
+fn densify_segment<F, MetricSpace>(
+    line_start: Point<F>,
+    line_end: Point<F>,
+    segment_length: F,
+    max_segment_length: F,
+    mut push: impl FnMut(Point<F>, F),
+) where
+    F: CoordFloat + FromPrimitive,
+    MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
 {
     assert!(max_segment_length > F::zero());
-    let num_segments = (MetricSpace::distance(line_start, line_end) / max_segment_length)
+    let num_segments = (segment_length / max_segment_length)
         .ceil()
         .to_u64()
         .expect("段数不合理");
@@ -86,23 +203,27 @@ pub(crate) fn densify_between<F, MetricSpace>(
         // 如果我们实现 point_at_distance_between，我们可以计算一次然后在这里使用。
         // 就此而言，我认为这个函数可能是为所有度量空间统一通用points_along_line的*唯一*基础。
         let interpolated_point = MetricSpace::point_at_ratio_between(line_start, line_end, ratio);
-        container.push(interpolated_point);
+        push(interpolated_point, ratio);
     }
 }
 
 impl<F: CoordFloat + FromPrimitive> Densify<F> for Line<F> {
     type Output = LineString<F>;
 
-    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F,
     {
         let mut points = vec![self.start_point()];
         densify_between::<F, MetricSpace>(
             self.start_point(),
             self.end_point(),
             &mut points,
-            max_segment_length,
+            max_segment_length(self),
         );
         points.push(self.end_point());
         LineString::from(points)
@@ -112,9 +233,13 @@ impl<F: CoordFloat + FromPrimitive> Densify<F> for Line<F> {
 impl<F: CoordFloat + FromPrimitive> Densify<F> for LineString<F> {
     type Output = Self;
 
-    fn densify<MetricSpace>(&self, max_segment_length: F) -> LineString<F>
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F,
     {
         if self.coords_count() == 0 {
             return LineString::new(vec![]);
@@ -127,7 +252,7 @@ impl<F: CoordFloat + FromPrimitive> Densify<F> for LineString<F> {
                 line.start_point(),
                 line.end_point(),
                 &mut points,
-                max_segment_length,
+                max_segment_length(&line),
             )
         });
 
@@ -142,13 +267,17 @@ impl<F: CoordFloat + FromPrimitive> Densify<F> for LineString<F> {
 impl<F: CoordFloat + FromPrimitive> Densify<F> for MultiLineString<F> {
     type Output = Self;
 
-    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F,
     {
         MultiLineString::new(
             self.iter()
-                .map(|line_string| line_string.densify::<MetricSpace>(max_segment_length))
+                .map(|line_string| line_string.densify_with::<MetricSpace, _>(&max_segment_length))
                 .collect(),
         )
     }
@@ -157,15 +286,20 @@ impl<F: CoordFloat + FromPrimitive> Densify<F> for MultiLineString<F> {
 impl<F: CoordFloat + FromPrimitive> Densify<F> for Polygon<F> {
     type Output = Self;
 
-    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F,
     {
         Polygon::new(
-            self.exterior().densify::<MetricSpace>(max_segment_length),
+            self.exterior()
+                .densify_with::<MetricSpace, _>(&max_segment_length),
             self.interiors()
                 .iter()
-                .map(|interior| interior.densify::<MetricSpace>(max_segment_length))
+                .map(|interior| interior.densify_with::<MetricSpace, _>(&max_segment_length))
                 .collect(),
         )
     }
@@ -174,13 +308,17 @@ impl<F: CoordFloat + FromPrimitive> Densify<F> for Polygon<F> {
 impl<F: CoordFloat + FromPrimitive> Densify<F> for MultiPolygon<F> {
     type Output = Self;
 
-    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F,
     {
         MultiPolygon::new(
             self.iter()
-                .map(|polygon| polygon.densify::<MetricSpace>(max_segment_length))
+                .map(|polygon| polygon.densify_with::<MetricSpace, _>(&max_segment_length))
                 .collect(),
         )
     }
@@ -189,29 +327,133 @@ impl<F: CoordFloat + FromPrimitive> Densify<F> for MultiPolygon<F> {
 impl<F: CoordFloat + FromPrimitive> Densify<F> for Rect<F> {
     type Output = Polygon<F>;
 
-    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F,
     {
-        self.to_polygon().densify::<MetricSpace>(max_segment_length)
+        self.to_polygon()
+            .densify_with::<MetricSpace, _>(max_segment_length)
     }
 }
 
 impl<F: CoordFloat + FromPrimitive> Densify<F> for Triangle<F> {
     type Output = Polygon<F>;
 
-    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    fn densify_with<MetricSpace, MaxSegmentLength>(
+        &self,
+        max_segment_length: MaxSegmentLength,
+    ) -> Self::Output
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+        MaxSegmentLength: Fn(&Line<F>) -> F,
+    {
+        self.to_polygon()
+            .densify_with::<MetricSpace, _>(max_segment_length)
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive> DensifyWithFractions<F> for Line<F> {
+    fn densify_with_fractions<MetricSpace>(&self, max_segment_length: F) -> Vec<(Point<F>, F)>
     where
         MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
     {
-        self.to_polygon().densify::<MetricSpace>(max_segment_length)
+        LineString::from(*self).densify_with_fractions::<MetricSpace>(max_segment_length)
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive> DensifyWithFractions<F> for LineString<F> {
+    fn densify_with_fractions<MetricSpace>(&self, max_segment_length: F) -> Vec<(Point<F>, F)>
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+    {
+        let Some(final_coord) = self.0.last() else {
+            return vec![];
+        };
+
+        let segment_lengths: Vec<F> = self
+            .lines()
+            .map(|line| MetricSpace::distance(line.start_point(), line.end_point()))
+            .collect();
+        let total_length = segment_lengths
+            .iter()
+            .fold(F::zero(), |total, &length| total + length);
+        // 长度为0的线上所有点的分数都为0
+        let fraction_at = |distance: F| {
+            if total_length == F::zero() {
+                F::zero()
+            } else {
+                distance / total_length
+            }
+        };
+
+        let mut points = vec![];
+        let mut length_before = F::zero();
+        for (line, segment_length) in self.lines().zip(segment_lengths) {
+            points.push((line.start_point(), fraction_at(length_before)));
+            densify_segment::<F, MetricSpace>(
+                line.start_point(),
+                line.end_point(),
+                segment_length,
+                max_segment_length,
+                |point, ratio| {
+                    points.push((point, fraction_at(length_before + segment_length * ratio)))
+                },
+            );
+            length_before = length_before + segment_length;
+        }
+
+        // 最后一个坐标的分数恰好为1，不受累计误差影响
+        points.push(((*final_coord).into(), fraction_at(total_length)));
+        points
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive> DensifySegments<F> for LineString<F> {
+    fn densify_segments<MetricSpace>(
+        &self,
+        max_segment_length: F,
+        segment_indices: &[usize],
+    ) -> LineString<F>
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F>,
+    {
+        let Some(final_coord) = self.0.last() else {
+            return LineString::new(vec![]);
+        };
+
+        let mut selected = vec![false; self.0.len() - 1];
+        for &index in segment_indices {
+            if let Some(is_selected) = selected.get_mut(index) {
+                *is_selected = true;
+            }
+        }
+
+        let mut points = vec![];
+        for (line, is_selected) in self.lines().zip(selected) {
+            points.push(line.start_point());
+            if is_selected {
+                densify_between::<F, MetricSpace>(
+                    line.start_point(),
+                    line.end_point(),
+                    &mut points,
+                    max_segment_length,
+                );
+            }
+        }
+        points.push((*final_coord).into());
+
+        LineString::from(points)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{coord, polygon, wkt, Euclidean, Geodesic, Haversine, Rhumb};
+    use crate::{coord, polygon, wkt, Euclidean, Geodesic, Haversine, Length, Rhumb};
 
     #[test]
     fn densify_line() {
@@ -261,6 +503,54 @@ mod tests {
         assert!(densified_polygon.exterior().coords_count() > polygon.exterior().coords_count());
     }
 
+    #[test]
+    fn densify_with_adaptive_length() {
+        // 最大线段长度随纬度增大：在赤道附近更密，在两极附近更疏
+        let max_segment_length = |line: &Line<f64>| {
+            let latitude = (line.start.y + line.end.y).abs() / 2.0;
+            200_000.0 * (1.0 + latitude / 30.0)
+        };
+        let polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 60.0),
+            (x: 0.0, y: 60.0),
+        ];
+
+        let densified = polygon.densify_with::<Haversine, _>(max_segment_length);
+        assert!(densified.exterior().is_closed());
+
+        // 每条原始线段插入的点数由该线段的阈值决定
+        let mut index = 0;
+        for line in polygon.exterior().lines() {
+            assert_eq!(densified.exterior().0[index], line.start);
+            let length = Haversine::distance(line.start_point(), line.end_point());
+            index += (length / max_segment_length(&line)).ceil() as usize;
+        }
+        assert_eq!(densified.exterior().0.len(), index + 1);
+
+        // 赤道附近的线段比靠近极地的线段插入更多的点
+        let near_equator = Line::new(coord!(x: 0.0, y: 0.0), coord!(x: 10.0, y: 0.0));
+        let near_pole = Line::new(coord!(x: 0.0, y: 60.0), coord!(x: 10.0, y: 60.0));
+        assert!(
+            near_equator
+                .densify_with::<Haversine, _>(max_segment_length)
+                .0
+                .len()
+                > near_pole
+                    .densify_with::<Haversine, _>(max_segment_length)
+                    .0
+                    .len()
+        );
+
+        // 常数闭包与 `densify` 相同
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        assert_eq!(
+            multi_polygon.densify_with::<Haversine, _>(|_| 300_000.0),
+            multi_polygon.densify::<Haversine>(300_000.0)
+        );
+    }
+
     // 从旧的已弃用trait移植，仅适用于欧几里得度量
     mod euclidean {
         use super::*;
@@ -326,6 +616,44 @@ mod tests {
     mod lon_lat_tests {
         use super::*;
 
+        #[test]
+        fn test_rhumb_polygon_densify() {
+            let max_segment_length = 20_000.0;
+            let polygon = wkt!(POLYGON(
+                (4.925 45.804,4.732 45.941,4.935 46.513,5.821 46.103,5.627 45.611,4.925 45.804),
+                (5.0 45.9,5.4 46.2,5.5 45.8,5.0 45.9)
+            ));
+            let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon.clone()]);
+
+            let densified = polygon.densify::<Rhumb>(max_segment_length);
+            assert_eq!(
+                densified.interiors().len(),
+                polygon.interiors().len(),
+                "每个环都应独立加密"
+            );
+            let rings = std::iter::once((polygon.exterior(), densified.exterior()))
+                .chain(polygon.interiors().iter().zip(densified.interiors()));
+            for (original, densified) in rings {
+                assert!(densified.is_closed());
+                assert!(densified.coords_count() > original.coords_count());
+                for line in densified.lines() {
+                    let length = Rhumb::distance(line.start_point(), line.end_point());
+                    assert!(length <= max_segment_length + 1e-6);
+                }
+                // 加密出的点都位于对应的恒向线上
+                assert_relative_eq!(
+                    densified.length::<Rhumb>(),
+                    original.length::<Rhumb>(),
+                    epsilon = 1e-6
+                );
+            }
+
+            assert_eq!(
+                multi_polygon.densify::<Rhumb>(max_segment_length),
+                MultiPolygon::new(vec![densified.clone(), densified])
+            );
+        }
+
         #[test]
         fn test_polygon_densify() {
             let polygon = wkt!(POLYGON((
@@ -409,6 +737,223 @@ mod tests {
         }
     }
 
+    // 加密只应插入新点，原始顶点必须原样出现在输出中，否则会破坏与相邻要素的拓扑关系
+    mod preserves_vertices {
+        use super::*;
+
+        /// 断言 `original` 的每个顶点都按顺序出现在 `densified` 中，且偏差不超过 `epsilon`
+        fn assert_vertices_preserved(
+            original: &LineString<f64>,
+            densified: &LineString<f64>,
+            epsilon: f64,
+        ) {
+            let mut densified_coords = densified.coords();
+            for original_coord in original.coords() {
+                let found = densified_coords.any(|densified_coord| {
+                    (densified_coord.x - original_coord.x).abs() <= epsilon
+                        && (densified_coord.y - original_coord.y).abs() <= epsilon
+                });
+                assert!(
+                    found,
+                    "原始顶点 {original_coord:?} 在加密结果中丢失或被移动"
+                );
+            }
+            assert_eq!(original.0.first(), densified.0.first());
+            assert_eq!(original.0.last(), densified.0.last());
+        }
+
+        fn line_string() -> LineString<f64> {
+            wkt!(LINESTRING(
+                -58.3816 -34.6037,
+                -77.0428 -12.0464,
+                -47.9292 -15.7801,
+                -47.9292 -15.7801,
+                10.1 20.3
+            ))
+        }
+
+        #[test]
+        fn euclidean() {
+            let original = line_string();
+            let densified = original.densify::<Euclidean>(0.7);
+            assert!(densified.coords_count() > original.coords_count());
+            // 欧几里得加密必须逐位保留原始顶点
+            assert_vertices_preserved(&original, &densified, 0.0);
+        }
+
+        #[test]
+        fn haversine() {
+            let original = line_string();
+            let densified = original.densify::<Haversine>(70_000.0);
+            assert!(densified.coords_count() > original.coords_count());
+            assert_vertices_preserved(&original, &densified, 1e-12);
+        }
+
+        #[test]
+        fn geodesic() {
+            let original = line_string();
+            let densified = original.densify::<Geodesic>(70_000.0);
+            assert!(densified.coords_count() > original.coords_count());
+            assert_vertices_preserved(&original, &densified, 1e-12);
+        }
+
+        #[test]
+        fn rhumb() {
+            let original = line_string();
+            let densified = original.densify::<Rhumb>(70_000.0);
+            assert!(densified.coords_count() > original.coords_count());
+            assert_vertices_preserved(&original, &densified, 1e-12);
+        }
+
+        #[test]
+        fn polygon_rings() {
+            let original = wkt!(POLYGON(
+                (-5.0 0.0,0.0 5.0,5.0 0.0,-5.0 0.0),
+                (-3.0 0.0,0.0 3.0,3.0 0.0,-3.0 0.0)
+            ));
+            let densified = original.densify::<Euclidean>(0.3);
+            assert_vertices_preserved(original.exterior(), densified.exterior(), 0.0);
+            for (original, densified) in original.interiors().iter().zip(densified.interiors()) {
+                assert_vertices_preserved(original, densified, 0.0);
+            }
+        }
+
+        #[test]
+        fn points_along_line_does_not_duplicate_end() {
+            // 累加比例时的浮点误差可能在终点附近额外生成一个几乎重合的点
+            let start = Point::new(0.0, 0.0);
+            let end = Point::new(0.0, 3.0);
+            let max_distance = Haversine::distance(start, end) / 3.0 + 1.0;
+            let points: Vec<_> =
+                Haversine::points_along_line(start, end, max_distance, true).collect();
+            assert_eq!(points.len(), 4);
+            assert_eq!(points[0], start);
+            assert_eq!(points[3], end);
+
+            let points: Vec<_> = Geodesic::points_along_line(start, end, 1.0e5, true).collect();
+            assert_eq!(points.first(), Some(&start));
+            assert_eq!(points.last(), Some(&end));
+            assert!(Geodesic::distance(points[points.len() - 2], end) > 1.0e4);
+        }
+
+        #[test]
+        fn points_along_line_non_positive_max_distance() {
+            let start = Point::new(0.0, 0.0);
+            let end = Point::new(1.0, 1.0);
+            for max_distance in [f64::NAN, 0.0, -1.0] {
+                let expected = vec![start, end];
+                assert_eq!(
+                    Haversine::points_along_line(start, end, max_distance, true)
+                        .collect::<Vec<_>>(),
+                    expected
+                );
+                assert_eq!(
+                    Geodesic::points_along_line(start, end, max_distance, true).collect::<Vec<_>>(),
+                    expected
+                );
+                assert_eq!(
+                    Rhumb::points_along_line(start, end, max_distance, true).collect::<Vec<_>>(),
+                    expected
+                );
+                assert_eq!(
+                    Rhumb::points_along_line(start, end, max_distance, false).count(),
+                    0
+                );
+            }
+        }
+    }
+
+    mod with_fractions {
+        use super::*;
+        use crate::Length;
+
+        #[test]
+        fn matches_densify() {
+            let line_string = wkt!(LINESTRING(
+                -58.3816 - 34.6037,
+                -77.0428 - 12.0464,
+                -47.9292 - 15.7801
+            ));
+            let densified = line_string.densify::<Haversine>(500_000.0);
+            let with_fractions = line_string.densify_with_fractions::<Haversine>(500_000.0);
+
+            let points: Vec<Point> = with_fractions.iter().map(|(p, _)| *p).collect();
+            assert_eq!(LineString::from(points), densified);
+
+            // 分数从0单调递增到1，并与沿线的弧长一致
+            let total_length = line_string.length::<Haversine>();
+            let mut travelled = 0.0;
+            assert_eq!(with_fractions[0].1, 0.0);
+            for pair in with_fractions.windows(2) {
+                let ((previous, _), (point, fraction)) = (pair[0], pair[1]);
+                travelled += Haversine::distance(previous, point);
+                assert!(fraction > pair[0].1);
+                assert_relative_eq!(fraction, travelled / total_length, epsilon = 1e-9);
+            }
+            assert_eq!(with_fractions.last().unwrap().1, 1.0);
+        }
+
+        #[test]
+        fn line() {
+            let line = Line::new(coord!(x: 0.0, y: 0.0), coord!(x: 4.0, y: 0.0));
+            let fractions: Vec<f64> = line
+                .densify_with_fractions::<Euclidean>(1.0)
+                .into_iter()
+                .map(|(_, fraction)| fraction)
+                .collect();
+            assert_eq!(fractions, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        }
+
+        #[test]
+        fn degenerate() {
+            let empty: LineString = wkt!(LINESTRING EMPTY);
+            assert!(empty.densify_with_fractions::<Euclidean>(1.0).is_empty());
+
+            let single = wkt!(LINESTRING(1.0 1.0));
+            assert_eq!(
+                single.densify_with_fractions::<Euclidean>(1.0),
+                vec![(Point::new(1.0, 1.0), 0.0)]
+            );
+
+            let collapsed = wkt!(LINESTRING(1.0 1.0,1.0 1.0));
+            assert_eq!(
+                collapsed.densify_with_fractions::<Euclidean>(1.0),
+                vec![(Point::new(1.0, 1.0), 0.0), (Point::new(1.0, 1.0), 0.0)]
+            );
+        }
+    }
+
+    mod segments {
+        use super::*;
+
+        #[test]
+        fn only_selected_segments() {
+            let line_string = wkt!(LINESTRING(0.0 0.0,0.0 3.0,3.0 3.0,3.0 0.0));
+
+            assert_eq!(
+                line_string.densify_segments::<Euclidean>(1.0, &[2, 0, 2, 7]),
+                wkt!(LINESTRING(0.0 0.0,0.0 1.0,0.0 2.0,0.0 3.0,3.0 3.0,3.0 2.0,3.0 1.0,3.0 0.0))
+            );
+            assert_eq!(
+                line_string.densify_segments::<Euclidean>(1.0, &[]),
+                line_string
+            );
+            assert_eq!(
+                line_string.densify_segments::<Euclidean>(1.0, &[0, 1, 2]),
+                line_string.densify::<Euclidean>(1.0)
+            );
+        }
+
+        #[test]
+        fn degenerate() {
+            let empty: LineString = wkt!(LINESTRING EMPTY);
+            assert_eq!(empty.densify_segments::<Euclidean>(1.0, &[0]), empty);
+
+            let single = wkt!(LINESTRING(1.0 1.0));
+            assert_eq!(single.densify_segments::<Euclidean>(1.0, &[0]), single);
+        }
+    }
+
     mod degenerate {
         use super::*;
 
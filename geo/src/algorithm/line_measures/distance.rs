@@ -8,4 +8,31 @@ pub trait Distance<F, Origin, Destination> {
     /// - `origin`, `destination`: 几何体，其中x/y的单位取决于特征实现。
     /// - 返回值: 依赖于特征实现。
     fn distance(origin: Origin, destination: Destination) -> F;
+
+    /// 判断两个几何体之间的距离是否不超过 `max_distance`，结果与
+    /// `distance(origin, destination) <= max_distance` 一致。
+    ///
+    /// 默认实现计算完整的距离后再比较。[`Euclidean`](crate::Euclidean) 中涉及线串、
+    /// 多边形和多几何体的组合会在找到足够近的部分后立即返回，不再检查其余部分。
+    ///
+    /// # 示例
+    /// ```
+    /// use geo::{wkt, Distance, Euclidean, Haversine, Point};
+    ///
+    /// let polygon = wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)));
+    /// let line_string = wkt!(LINESTRING(12. 0.,12. 10.));
+    /// assert!(Euclidean::within_distance(&polygon, &line_string, 2.));
+    /// assert!(!Euclidean::within_distance(&polygon, &line_string, 1.9));
+    ///
+    /// let london = Point::new(-0.1278, 51.5074);
+    /// let paris = Point::new(2.3522, 48.8566);
+    /// assert!(Haversine::within_distance(london, paris, 350_000.));
+    /// assert!(!Haversine::within_distance(london, paris, 300_000.));
+    /// ```
+    fn within_distance(origin: Origin, destination: Destination, max_distance: F) -> bool
+    where
+        F: PartialOrd,
+    {
+        Self::distance(origin, destination) <= max_distance
+    }
 }
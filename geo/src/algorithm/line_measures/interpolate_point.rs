@@ -16,6 +16,29 @@ pub trait InterpolatePoint<F: CoordFloat> {
     /// 详见[具体实现](#implementors)。
     fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F>;
 
+    /// 返回`start`和`end`之间线的中点，即 `point_at_ratio_between(start, end, 0.5)`。
+    ///
+    /// 对于[`Haversine`](crate::Haversine)是大圆弧的中点，对于[`Geodesic`](crate::Geodesic)是测地线的中点。
+    ///
+    /// # 示例
+    /// ```
+    /// use geo::{Distance, Euclidean, Haversine, InterpolatePoint, Point};
+    ///
+    /// let midpoint = Euclidean::midpoint(Point::new(0.0, 0.0), Point::new(4.0, 2.0));
+    /// assert_eq!(midpoint, Point::new(2.0, 1.0));
+    ///
+    /// let london: Point = Point::new(-0.1278, 51.5074);
+    /// let paris = Point::new(2.3522, 48.8566);
+    /// let midpoint = Haversine::midpoint(london, paris);
+    /// assert_eq!(
+    ///     Haversine::distance(london, midpoint).round(),
+    ///     Haversine::distance(midpoint, paris).round()
+    /// );
+    /// ```
+    fn midpoint(start: Point<F>, end: Point<F>) -> Point<F> {
+        Self::point_at_ratio_between(start, end, F::from(0.5).unwrap())
+    }
+
     /// 插值出在`start`和`end`之间的`Point`。
     ///
     /// 详见[具体实现](#implementors)。
@@ -54,6 +77,38 @@ mod tests {
         assert_eq!(Rhumb::point_at_ratio_between(start, end, ratio), end);
     }
 
+    #[test]
+    fn midpoint() {
+        use crate::Distance;
+
+        let start = Point::new(-58.3816, -34.6037);
+        let end = Point::new(-77.0428, -12.0464);
+
+        let midpoint = Haversine::midpoint(start, end);
+        assert_eq!(midpoint, Haversine::point_at_ratio_between(start, end, 0.5));
+        assert_relative_eq!(
+            Haversine::distance(start, midpoint),
+            Haversine::distance(midpoint, end),
+            epsilon = 1e-6
+        );
+
+        let midpoint = Geodesic::midpoint(start, end);
+        assert_relative_eq!(
+            Geodesic::distance(start, midpoint),
+            Geodesic::distance(midpoint, end),
+            epsilon = 1e-6
+        );
+
+        assert_eq!(
+            Rhumb::midpoint(start, end),
+            Rhumb::point_at_ratio_between(start, end, 0.5)
+        );
+        assert_eq!(
+            Euclidean::midpoint(start, end),
+            Point::new((-58.3816 + -77.0428) / 2.0, (-34.6037 + -12.0464) / 2.0)
+        );
+    }
+
     mod degenerate {
         use super::*;
 
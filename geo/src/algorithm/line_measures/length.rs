@@ -1,8 +1,11 @@
 use super::Distance;
-use crate::{CoordFloat, Line, LineString, MultiLineString, Point};
+use crate::{CoordFloat, Line, LineString, MultiLineString, Point, Rect, Triangle};
 
 /// 计算给定[度量空间](crate::algorithm::line_measures::metric_spaces)中的`Line`、`LineString`或`MultiLineString`的长度。
 ///
+/// 对于`Rect`和`Triangle`，返回其周长，即各条边在该度量空间中的长度之和。
+/// 例如在[`Haversine`](crate::Haversine)中，每条边被视为大圆弧段。
+///
 /// # 示例
 /// ```
 /// use geo::algorithm::line_measures::{Length, Euclidean, Haversine};
@@ -23,6 +26,28 @@ use crate::{CoordFloat, Line, LineString, MultiLineString, Point};
 /// ```
 pub trait Length<F: CoordFloat> {
     fn length<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self) -> F;
+
+    /// 判断长度是否严格大于 `threshold`。
+    ///
+    /// 对于`LineString`和`MultiLineString`，逐段累加长度，一旦超过阈值就立即返回，
+    /// 不再计算剩余线段。当只需要与阈值比较时（例如按长度筛选路线），
+    /// 这可以避免计算完整的（对[`Geodesic`](crate::Geodesic)而言代价较高的）长度。
+    ///
+    /// # 示例
+    /// ```
+    /// use geo::algorithm::line_measures::{Length, Euclidean};
+    ///
+    /// let line_string = geo::wkt!(LINESTRING(
+    ///     0.0 0.0,
+    ///     3.0 4.0,
+    ///     3.0 5.0
+    /// ));
+    /// assert!(line_string.length_exceeds::<Euclidean>(5.5));
+    /// assert!(!line_string.length_exceeds::<Euclidean>(6.0));
+    /// ```
+    fn length_exceeds<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self, threshold: F) -> bool {
+        self.length::<MetricSpace>() > threshold
+    }
 }
 
 impl<F: CoordFloat> Length<F> for Line<F> {
@@ -39,6 +64,10 @@ impl<F: CoordFloat> Length<F> for LineString<F> {
         }
         length
     }
+
+    fn length_exceeds<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self, threshold: F) -> bool {
+        lengths_exceed::<F, MetricSpace, _>(self.lines(), threshold)
+    }
 }
 
 impl<F: CoordFloat> Length<F> for MultiLineString<F> {
@@ -49,6 +78,55 @@ impl<F: CoordFloat> Length<F> for MultiLineString<F> {
         }
         length
     }
+
+    fn length_exceeds<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self, threshold: F) -> bool {
+        lengths_exceed::<F, MetricSpace, _>(self.iter().flat_map(LineString::lines), threshold)
+    }
+}
+
+impl<F: CoordFloat> Length<F> for Rect<F> {
+    fn length<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self) -> F {
+        let mut length = F::zero();
+        for line in self.to_lines() {
+            length = length + line.length::<MetricSpace>();
+        }
+        length
+    }
+
+    fn length_exceeds<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self, threshold: F) -> bool {
+        lengths_exceed::<F, MetricSpace, _>(self.to_lines(), threshold)
+    }
+}
+
+impl<F: CoordFloat> Length<F> for Triangle<F> {
+    fn length<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self) -> F {
+        let mut length = F::zero();
+        for line in self.to_lines() {
+            length = length + line.length::<MetricSpace>();
+        }
+        length
+    }
+
+    fn length_exceeds<MetricSpace: Distance<F, Point<F>, Point<F>>>(&self, threshold: F) -> bool {
+        lengths_exceed::<F, MetricSpace, _>(self.to_lines(), threshold)
+    }
+}
+
+/// 累加各线段的长度，一旦超过 `threshold` 就停止。
+fn lengths_exceed<F, MetricSpace, I>(lines: I, threshold: F) -> bool
+where
+    F: CoordFloat,
+    MetricSpace: Distance<F, Point<F>, Point<F>>,
+    I: IntoIterator<Item = Line<F>>,
+{
+    let mut length = F::zero();
+    for line in lines {
+        length = length + line.length::<MetricSpace>();
+        if length > threshold {
+            return true;
+        }
+    }
+    false
 }
 
 #[cfg(test)]
@@ -69,7 +147,7 @@ mod tests {
             line.length::<Geodesic>().round()
         );
         assert_eq!(
-            341_088., // 米
+            343_572., // 米
             line.length::<Rhumb>().round()
         );
         assert_eq!(
@@ -103,7 +181,7 @@ mod tests {
             line_string.length::<Geodesic>().round()
         );
         assert_eq!(
-            6_332_790., // 米
+            6_308_683., // 米
             line_string.length::<Rhumb>().round()
         );
         assert_eq!(
@@ -127,4 +205,66 @@ mod tests {
             projected_line_string.length::<Euclidean>().round()
         );
     }
+
+    #[test]
+    fn rect_and_triangle_perimeters() {
+        let rect = Rect::new(coord!(x: 1.5f64, y: -2.), coord!(x: 4., y: 5.));
+        assert_eq!(
+            rect.length::<Euclidean>(),
+            2. * (rect.width() + rect.height())
+        );
+        assert!(rect.length_exceeds::<Euclidean>(18.9));
+        assert!(!rect.length_exceeds::<Euclidean>(19.));
+
+        let triangle = Triangle::from([(0f64, 0.), (3., 0.), (3., 4.)]);
+        assert_eq!(triangle.length::<Euclidean>(), 12.);
+        assert!(!triangle.length_exceeds::<Euclidean>(12.));
+
+        // 在球面上，各条边都被视为大圆弧段
+        let rect_lon_lat = Rect::new(coord!(x: 0f64, y: 0.), coord!(x: 10., y: 10.));
+        let expected: f64 = rect_lon_lat
+            .to_lines()
+            .iter()
+            .map(|line| line.length::<Haversine>())
+            .sum();
+        assert_eq!(rect_lon_lat.length::<Haversine>(), expected);
+        assert_eq!(
+            rect_lon_lat.length::<Haversine>(),
+            rect_lon_lat.to_polygon().exterior().length::<Haversine>()
+        );
+        assert_eq!(
+            triangle.length::<Geodesic>(),
+            LineString::from(triangle.to_array().to_vec()).length::<Geodesic>()
+                + Line::new(triangle.2, triangle.0).length::<Geodesic>()
+        );
+    }
+
+    #[test]
+    fn length_exceeds() {
+        let line_string = LineString::new(vec![
+            coord!(x: -58.3816f64, y: -34.6037), // 布宜诺斯艾利斯，阿根廷
+            coord!(x: -77.0428, y: -12.0464),    // 利马，秘鲁
+            coord!(x: -47.9292, y: -15.7801),    // 巴西利亚，巴西
+        ]);
+        // 第一段约为 3_100 千米
+        assert!(line_string.length_exceeds::<Geodesic>(3_000_000.));
+        assert!(line_string.length_exceeds::<Geodesic>(6_302_000.));
+        assert!(!line_string.length_exceeds::<Geodesic>(6_303_000.));
+        assert!(line_string.length_exceeds::<Haversine>(6_304_000.));
+        assert!(!line_string.length_exceeds::<Haversine>(6_305_000.));
+
+        // 长度恰好等于阈值时不算超过
+        let multi_line_string = MultiLineString::new(vec![
+            LineString::from(vec![(0., 0.), (3., 4.)]),
+            LineString::new(vec![]),
+            LineString::from(vec![(10., 0.), (10., 1.)]),
+        ]);
+        assert!(multi_line_string.length_exceeds::<Euclidean>(5.5));
+        assert!(!multi_line_string.length_exceeds::<Euclidean>(6.));
+        assert!(!LineString::<f64>::new(vec![]).length_exceeds::<Euclidean>(0.));
+
+        let line = Line::new(coord!(x: 0., y: 0.), coord!(x: 3., y: 4.));
+        assert!(line.length_exceeds::<Euclidean>(4.9));
+        assert!(!line.length_exceeds::<Euclidean>(5.));
+    }
 }
@@ -1,5 +1,5 @@
 use super::{Distance, Euclidean};
-use crate::algorithm::Intersects;
+use crate::algorithm::{BoundingRect, Intersects};
 use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
 use crate::geometry::*;
 use crate::{CoordFloat, GeoFloat, GeoNum};
@@ -17,6 +17,10 @@ macro_rules! symmetric_distance_impl {
             fn distance(a: $a, b: $b) -> F {
                 Self::distance(b, a)
             }
+
+            fn within_distance(a: $a, b: $b, max_distance: F) -> bool {
+                Self::within_distance(b, a, max_distance)
+            }
         }
     };
 }
@@ -81,6 +85,20 @@ impl<F: CoordFloat> Distance<F, &Point<F>, &Point<F>> for Euclidean {
     }
 }
 
+impl<F: CoordFloat> Distance<F, (F, F), (F, F)> for Euclidean {
+    /// 与 `Distance<F, Point<F>, Point<F>>` 相同，但接受 `(x, y)` 元组，便于快速计算。
+    ///
+    /// # 示例
+    /// ```
+    /// use geo::{Distance, Euclidean};
+    ///
+    /// assert_eq!(Euclidean::distance((0.0, 0.0), (3.0, 4.0)), 5.0);
+    /// ```
+    fn distance(origin: (F, F), destination: (F, F)) -> F {
+        Self::distance(Coord::from(origin), Coord::from(destination))
+    }
+}
+
 impl<F: CoordFloat> Distance<F, &Point<F>, &Line<F>> for Euclidean {
     fn distance(origin: &Point<F>, destination: &Line<F>) -> F {
         geo_types::private_utils::point_line_euclidean_distance(*origin, *destination)
@@ -180,25 +198,46 @@ impl<F: GeoFloat> Distance<F, &LineString<F>, &LineString<F>> for Euclidean {
             nearest_neighbour_distance(line_string_a, line_string_b)
         }
     }
+
+    fn within_distance(
+        line_string_a: &LineString<F>,
+        line_string_b: &LineString<F>,
+        max_distance: F,
+    ) -> bool {
+        if !bounding_rects_within_distance(line_string_a, line_string_b, max_distance) {
+            return false;
+        }
+        if line_string_a.intersects(line_string_b) {
+            F::zero() <= max_distance
+        } else {
+            nearest_neighbour_within_distance(line_string_a, line_string_b, max_distance)
+        }
+    }
 }
 
 impl<F: GeoFloat> Distance<F, &LineString<F>, &Polygon<F>> for Euclidean {
     fn distance(line_string: &LineString<F>, polygon: &Polygon<F>) -> F {
         if line_string.intersects(polygon) {
-            F::zero()
-        } else if !polygon.interiors().is_empty()
-            // 注意：在空的 line_string 上会爆炸
-            && ring_contains_coord(polygon.exterior(), line_string.0[0])
-        {
-            // 检查每个环的距离，返回最小值
-            let mut mindist: F = Float::max_value();
-            for ring in polygon.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(line_string, ring))
-            }
-            mindist
-        } else {
-            nearest_neighbour_distance(line_string, polygon.exterior())
+            return F::zero();
         }
+        // 检查每个环的距离，返回最小值
+        rings_to_compare(line_string, polygon)
+            .iter()
+            .fold(Float::max_value(), |acc: F, ring| {
+                acc.min(nearest_neighbour_distance(line_string, ring))
+            })
+    }
+
+    fn within_distance(line_string: &LineString<F>, polygon: &Polygon<F>, max_distance: F) -> bool {
+        if !bounding_rects_within_distance(line_string, polygon, max_distance) {
+            return false;
+        }
+        if line_string.intersects(polygon) {
+            return F::zero() <= max_distance;
+        }
+        rings_to_compare(line_string, polygon)
+            .iter()
+            .any(|ring| nearest_neighbour_within_distance(line_string, ring, max_distance))
     }
 }
 
@@ -215,28 +254,24 @@ impl<F: GeoFloat> Distance<F, &Polygon<F>, &Polygon<F>> for Euclidean {
         if polygon_a.intersects(polygon_b) {
             return F::zero();
         }
-        // 注意：polygon_b.exterior() 为空时会爆炸
-        // 包含检查
-        if !polygon_a.interiors().is_empty()
-            && ring_contains_coord(polygon_a.exterior(), polygon_b.exterior().0[0])
-        {
-            // 检查每个环的距离，返回最小值
-            let mut mindist: F = Float::max_value();
-            for ring in polygon_a.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(polygon_b.exterior(), ring))
-            }
-            return mindist;
-        } else if !polygon_b.interiors().is_empty()
-            // 注意：polygon_a.exterior() 为空时会爆炸
-            && ring_contains_coord(polygon_b.exterior(), polygon_a.exterior().0[0])
-        {
-            let mut mindist: F = Float::max_value();
-            for ring in polygon_b.interiors() {
-                mindist = mindist.min(nearest_neighbour_distance(polygon_a.exterior(), ring))
-            }
-            return mindist;
+        // 检查每个环的距离，返回最小值
+        let (exterior, rings) = polygon_rings_to_compare(polygon_a, polygon_b);
+        rings.iter().fold(Float::max_value(), |acc: F, ring| {
+            acc.min(nearest_neighbour_distance(exterior, ring))
+        })
+    }
+
+    fn within_distance(polygon_a: &Polygon<F>, polygon_b: &Polygon<F>, max_distance: F) -> bool {
+        if !bounding_rects_within_distance(polygon_a, polygon_b, max_distance) {
+            return false;
         }
-        nearest_neighbour_distance(polygon_a.exterior(), polygon_b.exterior())
+        if polygon_a.intersects(polygon_b) {
+            return F::zero() <= max_distance;
+        }
+        let (exterior, rings) = polygon_rings_to_compare(polygon_a, polygon_b);
+        rings
+            .iter()
+            .any(|ring| nearest_neighbour_within_distance(exterior, ring, max_distance))
     }
 }
 
@@ -252,6 +287,10 @@ macro_rules! impl_euclidean_distance_for_polygonlike_geometry {
             fn distance(origin: $polygonlike, destination: $polygonlike) -> F {
                 Self::distance(&origin.to_polygon(), destination)
             }
+
+            fn within_distance(origin: $polygonlike, destination: $polygonlike, max_distance: F) -> bool {
+                Self::within_distance(&origin.to_polygon(), destination, max_distance)
+            }
         }
         $(
             impl<F: GeoFloat> Distance<F, $polygonlike, $geometry_b> for Euclidean
@@ -259,6 +298,10 @@ macro_rules! impl_euclidean_distance_for_polygonlike_geometry {
                 fn distance(polygonlike: $polygonlike, geometry_b: $geometry_b) -> F {
                       Self::distance(&polygonlike.to_polygon(), geometry_b)
                 }
+
+                fn within_distance(polygonlike: $polygonlike, geometry_b: $geometry_b, max_distance: F) -> bool {
+                      Self::within_distance(&polygonlike.to_polygon(), geometry_b, max_distance)
+                }
             }
             symmetric_distance_impl!(GeoFloat, $geometry_b, $polygonlike);
         )*
@@ -283,6 +326,12 @@ macro_rules! impl_euclidean_distance_for_iter_geometry {
                         accum.min(Self::distance(member, destination))
                     })
                 }
+
+            fn within_distance(origin: $iter_geometry, destination: $iter_geometry, max_distance: F) -> bool {
+                origin
+                    .iter()
+                    .any(|member| Self::within_distance(member, destination, max_distance))
+            }
         }
         $(
             impl<F: GeoFloat> Distance<F, $iter_geometry, $to_geometry> for Euclidean {
@@ -293,6 +342,12 @@ macro_rules! impl_euclidean_distance_for_iter_geometry {
                             accum.min(Self::distance(member, to_geometry))
                         })
                 }
+
+                fn within_distance(iter_geometry: $iter_geometry, to_geometry: $to_geometry, max_distance: F) -> bool {
+                    iter_geometry
+                        .iter()
+                        .any(|member| Self::within_distance(member, to_geometry, max_distance))
+                }
             }
             symmetric_distance_impl!(GeoFloat, $to_geometry, $iter_geometry);
         )*
@@ -327,6 +382,21 @@ macro_rules! impl_euclidean_distance_for_geometry_and_variant {
                         Geometry::Triangle(triangle) => Self::distance(origin, triangle),
                     }
                 }
+
+                fn within_distance(origin: $target, destination: &Geometry<F>, max_distance: F) -> bool {
+                    match destination {
+                        Geometry::Point(point) => Self::within_distance(origin, point, max_distance),
+                        Geometry::Line(line) => Self::within_distance(origin, line, max_distance),
+                        Geometry::LineString(line_string) => Self::within_distance(origin, line_string, max_distance),
+                        Geometry::Polygon(polygon) => Self::within_distance(origin, polygon, max_distance),
+                        Geometry::MultiPoint(multi_point) => Self::within_distance(origin, multi_point, max_distance),
+                        Geometry::MultiLineString(multi_line_string) => Self::within_distance(origin, multi_line_string, max_distance),
+                        Geometry::MultiPolygon(multi_polygon) => Self::within_distance(origin, multi_polygon, max_distance),
+                        Geometry::GeometryCollection(geometry_collection) => Self::within_distance(origin, geometry_collection, max_distance),
+                        Geometry::Rect(rect) => Self::within_distance(origin, rect, max_distance),
+                        Geometry::Triangle(triangle) => Self::within_distance(origin, triangle, max_distance),
+                    }
+                }
             }
             symmetric_distance_impl!(GeoFloat, &Geometry<F>, $target);
         )*
@@ -354,6 +424,33 @@ impl<F: GeoFloat> Distance<F, &Geometry<F>, &Geometry<F>> for Euclidean {
             Geometry::Triangle(triangle) => Self::distance(triangle, destination),
         }
     }
+
+    fn within_distance(origin: &Geometry<F>, destination: &Geometry<F>, max_distance: F) -> bool {
+        match origin {
+            Geometry::Point(point) => Self::within_distance(point, destination, max_distance),
+            Geometry::Line(line) => Self::within_distance(line, destination, max_distance),
+            Geometry::LineString(line_string) => {
+                Self::within_distance(line_string, destination, max_distance)
+            }
+            Geometry::Polygon(polygon) => Self::within_distance(polygon, destination, max_distance),
+            Geometry::MultiPoint(multi_point) => {
+                Self::within_distance(multi_point, destination, max_distance)
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                Self::within_distance(multi_line_string, destination, max_distance)
+            }
+            Geometry::MultiPolygon(multi_polygon) => {
+                Self::within_distance(multi_polygon, destination, max_distance)
+            }
+            Geometry::GeometryCollection(geometry_collection) => {
+                Self::within_distance(geometry_collection, destination, max_distance)
+            }
+            Geometry::Rect(rect) => Self::within_distance(rect, destination, max_distance),
+            Geometry::Triangle(triangle) => {
+                Self::within_distance(triangle, destination, max_distance)
+            }
+        }
+    }
 }
 
 // ┌───────────────────────────┐
@@ -378,6 +475,92 @@ fn nearest_neighbour_distance<F: GeoFloat>(geom1: &LineString<F>, geom2: &LineSt
         }))
 }
 
+/// 与 [`nearest_neighbour_distance`] 相同的计算，但一旦找到不超过 `max_distance` 的点-线段对就立即返回
+fn nearest_neighbour_within_distance<F: GeoFloat>(
+    geom1: &LineString<F>,
+    geom2: &LineString<F>,
+    max_distance: F,
+) -> bool {
+    if !bounding_rects_within_distance(geom1, geom2, max_distance) {
+        return false;
+    }
+    let within = |tree: &RTree<CachedEnvelope<Line<F>>>, point: Point<F>| {
+        let nearest = tree.nearest_neighbor(&point).unwrap();
+        Euclidean::distance(nearest as &Line<F>, &point) <= max_distance
+    };
+    let tree_a = RTree::bulk_load(geom1.lines().map(CachedEnvelope::new).collect());
+    if geom2.points().any(|point| within(&tree_a, point)) {
+        return true;
+    }
+    // 只有在第一轮查询没有找到时才构建第二棵树
+    let tree_b = RTree::bulk_load(geom2.lines().map(CachedEnvelope::new).collect());
+    geom1.points().any(|point| within(&tree_b, point))
+}
+
+/// 边界矩形之间的距离是几何体之间距离的下界，可以在构建 R 树或检查相交之前快速排除相距较远的几何体。
+/// 任一几何体为空时返回 `false`。
+fn bounding_rects_within_distance<F, A, B>(a: &A, b: &B, max_distance: F) -> bool
+where
+    F: GeoFloat,
+    A: BoundingRect<F, Output = Option<Rect<F>>>,
+    B: BoundingRect<F, Output = Option<Rect<F>>>,
+{
+    let (Some(a), Some(b)) = (a.bounding_rect(), b.bounding_rect()) else {
+        return false;
+    };
+    let dx = (b.min().x - a.max().x)
+        .max(a.min().x - b.max().x)
+        .max(F::zero());
+    let dy = (b.min().y - a.max().y)
+        .max(a.min().y - b.max().y)
+        .max(F::zero());
+    dx.hypot(dy) <= max_distance
+}
+
+/// 选择与不相交的 `line_string` 之间可能最近的 `polygon` 的环：
+/// `line_string` 位于带洞多边形的外环内部时，它一定在某个洞中，只需比较内环；否则只需比较外环。
+fn rings_to_compare<'a, F: GeoFloat>(
+    line_string: &LineString<F>,
+    polygon: &'a Polygon<F>,
+) -> &'a [LineString<F>] {
+    enclosing_interiors(line_string, polygon).unwrap_or(std::slice::from_ref(polygon.exterior()))
+}
+
+/// 与 [`rings_to_compare`] 相同的选择，用于两个不相交的多边形：
+/// 返回一个多边形的外环，以及需要与之比较的另一个多边形的环。
+fn polygon_rings_to_compare<'a, F: GeoFloat>(
+    polygon_a: &'a Polygon<F>,
+    polygon_b: &'a Polygon<F>,
+) -> (&'a LineString<F>, &'a [LineString<F>]) {
+    // 注意：polygon_b.exterior() 为空时会爆炸
+    if let Some(interiors) = enclosing_interiors(polygon_b.exterior(), polygon_a) {
+        return (polygon_b.exterior(), interiors);
+    }
+    // 注意：polygon_a.exterior() 为空时会爆炸
+    if let Some(interiors) = enclosing_interiors(polygon_a.exterior(), polygon_b) {
+        return (polygon_a.exterior(), interiors);
+    }
+    (
+        polygon_a.exterior(),
+        std::slice::from_ref(polygon_b.exterior()),
+    )
+}
+
+/// 包含检查：`line_string` 位于带洞 `polygon` 的外环内部时返回其内环。
+fn enclosing_interiors<'a, F: GeoFloat>(
+    line_string: &LineString<F>,
+    polygon: &'a Polygon<F>,
+) -> Option<&'a [LineString<F>]> {
+    if !polygon.interiors().is_empty()
+        // 注意：在空的 line_string 上会爆炸
+        && ring_contains_coord(polygon.exterior(), line_string.0[0])
+    {
+        Some(polygon.interiors())
+    } else {
+        None
+    }
+}
+
 fn ring_contains_coord<T: GeoNum>(ring: &LineString<T>, c: Coord<T>) -> bool {
     match coord_pos_relative_to_ring(c, ring) {
         CoordPos::Inside => true,
@@ -1084,4 +1267,49 @@ mod test {
         let test_gc = GeometryCollection(vec![Geometry::Rect(test_rect)]);
         assert_relative_eq!(Euclidean::distance(&test_gc, &gc), 60.959002616512684);
     }
+
+    #[test]
+    fn within_distance_matches_distance() {
+        // 带洞多边形，以及位于洞内、外部和与之相交的几何体
+        let polygon: Polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)],
+            interiors: [[(x: 3., y: 3.), (x: 7., y: 3.), (x: 7., y: 7.), (x: 3., y: 7.), (x: 3., y: 3.)]],
+        ];
+        let in_hole = Geometry::LineString(LineString::from(vec![(4., 5.), (6., 5.)]));
+        let outside = Geometry::Polygon(polygon![
+            (x: 13., y: 0.), (x: 15., y: 0.), (x: 15., y: 2.), (x: 13., y: 0.)
+        ]);
+        let crossing = Geometry::LineString(LineString::from(vec![(-1., 5.), (1., 5.)]));
+        let points = Geometry::MultiPoint(MultiPoint::from(vec![(20., 20.), (11., 5.)]));
+        // 边界矩形在对角方向上相距 2√2，用于检查边界矩形的快速排除
+        let diagonal = Geometry::LineString(LineString::from(vec![(12., 12.), (14., 12.)]));
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            outside.clone(),
+            points.clone(),
+        ]));
+
+        let polygon = Geometry::Polygon(polygon);
+        for other in [
+            &in_hole,
+            &outside,
+            &crossing,
+            &points,
+            &collection,
+            &diagonal,
+        ] {
+            let distance = Euclidean::distance(&polygon, other);
+            for max_distance in [0., 0.5, 1., 1.5, 2., 3., 100.] {
+                let expected = distance <= max_distance;
+                assert_eq!(
+                    Euclidean::within_distance(&polygon, other, max_distance),
+                    expected
+                );
+                assert_eq!(
+                    Euclidean::within_distance(other, &polygon, max_distance),
+                    expected
+                );
+            }
+        }
+        assert!(!Euclidean::within_distance(&polygon, &crossing, f64::NAN));
+    }
 }
@@ -1,6 +1,6 @@
 mod distance;
 
-use super::super::{Distance, InterpolatePoint};
+use super::super::{Bearing, Distance, InterpolatePoint};
 use crate::line_measures::densify::densify_between;
 use crate::{CoordFloat, Point};
 use num_traits::FromPrimitive;
@@ -18,6 +18,45 @@ use num_traits::FromPrimitive;
 /// [度量空间]: super
 pub struct Euclidean;
 
+/// 计算[欧几里得平面]上的方位角（网格方位角）。
+///
+/// [欧几里得平面]: https://en.wikipedia.org/wiki/Euclidean_plane
+impl<F: CoordFloat> Bearing<F> for Euclidean {
+    /// 返回从`origin`到`destination`的方位角，以度为单位，从+Y轴（北方）开始顺时针测量。
+    ///
+    /// 如果两点重合，返回0°。
+    ///
+    /// # 单位
+    ///
+    /// - `origin`，`destination`：使用非角度单位（如米）的投影坐标点，例如web墨卡托。
+    ///   对于经/纬度点，使用[`Haversine`]、[`Geodesic`]或[`Rhumb`] [度量空间]。
+    /// - 返回：度，范围为`[0, 360)`（北：0°，东：90°，南：180°，西：270°）
+    ///
+    /// # 例子
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::{Bearing, Euclidean};
+    /// use geo::Point;
+    ///
+    /// let origin = Point::new(0.0, 0.0);
+    /// let destination = Point::new(1.0, 1.0);
+    /// let bearing = Euclidean::bearing(origin, destination);
+    /// assert_relative_eq!(bearing, 45.0);
+    /// ```
+    ///
+    /// [`Haversine`]: crate::line_measures::Haversine
+    /// [`Geodesic`]: crate::line_measures::Geodesic
+    /// [`Rhumb`]: crate::line_measures::Rhumb
+    /// [度量空间]: crate::line_measures::metric_spaces
+    fn bearing(origin: Point<F>, destination: Point<F>) -> F {
+        let three_sixty = F::from(360.0f64).unwrap();
+
+        let diff = destination - origin;
+        (diff.x().atan2(diff.y()).to_degrees() + three_sixty) % three_sixty
+    }
+}
+
 /// 在[欧几里得平面]上沿线插值点。
 ///
 /// [欧几里得平面]: https://en.wikipedia.org/wiki/Euclidean_plane
@@ -99,6 +138,35 @@ mod tests {
 
     type MetricSpace = Euclidean;
 
+    mod bearing {
+        use super::*;
+
+        #[test]
+        fn cardinal_directions() {
+            let origin = Point::new(3.0, -2.0);
+            let bearing = |dx: f64, dy: f64| {
+                MetricSpace::bearing(origin, Point::new(origin.x() + dx, origin.y() + dy))
+            };
+            assert_relative_eq!(bearing(0.0, 1.0), 0.0);
+            assert_relative_eq!(bearing(1.0, 0.0), 90.0);
+            assert_relative_eq!(bearing(0.0, -1.0), 180.0);
+            assert_relative_eq!(bearing(-1.0, 0.0), 270.0);
+            assert_relative_eq!(bearing(-1.0, 1.0), 315.0);
+            assert_relative_eq!(bearing(1.0, -1.0), 135.0);
+        }
+
+        #[test]
+        fn range_and_coincident_points() {
+            let origin = Point::new(0.0, 0.0);
+            // 略微偏西于正北方向时，结果接近360°而不是负数
+            let bearing = MetricSpace::bearing(origin, Point::new(-1e-9, 1.0));
+            assert!((0.0..360.0).contains(&bearing));
+            assert_relative_eq!(bearing, 360.0, epsilon = 1e-6);
+
+            assert_eq!(MetricSpace::bearing(origin, origin), 0.0);
+        }
+    }
+
     mod distance {
         use super::*;
 
@@ -1,5 +1,5 @@
 use super::super::{Bearing, Destination, Distance, InterpolatePoint};
-use crate::Point;
+use crate::{LineString, Point};
 use geographiclib_rs::{DirectGeodesic, InverseGeodesic};
 
 /// 地球的椭球模型，使用[Karney (2013)]提供的方法。
@@ -129,6 +129,90 @@ impl Distance<f64, Point<f64>, Point<f64>> for Geodesic {
     }
 }
 
+impl Distance<f64, &Point<f64>, &LineString<f64>> for Geodesic {
+    /// 确定地球椭球模型上一个点到一条线串的最小[大地测线]距离。
+    ///
+    /// 对于线串的每条线段，沿线段的大地线迭代寻找离该点最近的位置，而不仅仅检查线段端点，
+    /// 因此点在线段中部附近时也能得到正确的距离。
+    ///
+    /// 空的线串返回 `f64::INFINITY`。
+    ///
+    /// # 单位
+    /// - `origin`: 以lon/lat度坐标表示的点
+    /// - `destination`: 以lon/lat度坐标表示的线串
+    /// - 返回值: 米
+    ///
+    /// # 示例
+    /// ```rust
+    /// # use approx::assert_relative_eq;
+    /// use geo::{wkt, Distance, Geodesic};
+    ///
+    /// let road = wkt!(LINESTRING(0.0 0.0, 0.0 10.0));
+    /// let gps_point = wkt!(POINT(0.001 5.0));
+    ///
+    /// // 到最近端点的距离超过 500 公里，但到道路的距离约为 111 米
+    /// assert_relative_eq!(Geodesic::distance(&gps_point, &road), 110.9, epsilon = 0.1);
+    ///
+    /// let on_road = wkt!(POINT(0.0 2.5));
+    /// assert_relative_eq!(Geodesic::distance(&on_road, &road), 0.0, epsilon = 1e-6);
+    /// ```
+    ///
+    /// [大地测线]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
+    fn distance(origin: &Point<f64>, destination: &LineString<f64>) -> f64 {
+        if let [only] = destination.0.as_slice() {
+            return Geodesic::distance(*origin, Point(*only));
+        }
+        destination
+            .lines()
+            .map(|line| geodesic_segment_distance(*origin, line.start_point(), line.end_point()))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl Distance<f64, &LineString<f64>, &Point<f64>> for Geodesic {
+    /// 确定线串到点的最小大地测线距离，详见 `Distance<f64, &Point<f64>, &LineString<f64>>`。
+    fn distance(origin: &LineString<f64>, destination: &Point<f64>) -> f64 {
+        Geodesic::distance(destination, origin)
+    }
+}
+
+/// 迭代寻找最近点的最大次数。通常几次迭代就能收敛到毫米级。
+const SEGMENT_DISTANCE_MAX_ITERATIONS: usize = 50;
+
+/// 点到从 `start` 到 `end` 的大地线段的最小距离（米）。
+///
+/// 从线段起点开始，沿大地线移动沿轨距离的估计值 `d·cos(Δ方位角)`，直到位置变化小于 1 毫米，
+/// 并将位置限制在线段之内。
+fn geodesic_segment_distance(point: Point<f64>, start: Point<f64>, end: Point<f64>) -> f64 {
+    let geodesic = geographiclib_rs::Geodesic::wgs84();
+    let (length, azimuth, _, _): (f64, f64, f64, f64) =
+        geodesic.inverse(start.y(), start.x(), end.y(), end.x());
+    let endpoints_distance = Geodesic::distance(point, start).min(Geodesic::distance(point, end));
+    if length == 0.0 {
+        return endpoints_distance;
+    }
+
+    let mut along = 0.0;
+    let mut distance = f64::INFINITY;
+    for _ in 0..SEGMENT_DISTANCE_MAX_ITERATIONS {
+        let (lat, lon, line_azimuth): (f64, f64, f64) =
+            geodesic.direct(start.y(), start.x(), azimuth, along);
+        let (to_point, to_point_azimuth, _, _): (f64, f64, f64, f64) =
+            geodesic.inverse(lat, lon, point.y(), point.x());
+        distance = to_point;
+        if distance == 0.0 {
+            break;
+        }
+        let step = distance * (to_point_azimuth - line_azimuth).to_radians().cos();
+        let next = (along + step).clamp(0.0, length);
+        if (next - along).abs() < 1e-3 {
+            break;
+        }
+        along = next;
+    }
+    distance.min(endpoints_distance)
+}
+
 /// 在[a geodesic line]上插值点。
 ///
 /// [大地测线]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
@@ -243,7 +327,8 @@ impl InterpolatePoint<f64> for Geodesic {
         let g = geographiclib_rs::Geodesic::wgs84();
         let (total_distance, azi1, _azi2, _a12) = g.inverse(start.y(), start.x(), end.y(), end.x());
 
-        if total_distance <= max_distance {
+        // `max_distance` 不是正数（包括 NaN）时无法加密，与距离足够短时一样只返回端点
+        if max_distance.is_nan() || max_distance <= 0.0 || total_distance <= max_distance {
             return if include_ends {
                 vec![start, end].into_iter()
             } else {
@@ -251,17 +336,17 @@ impl InterpolatePoint<f64> for Geodesic {
             };
         }
 
-        let number_of_points = (total_distance / max_distance).ceil();
-        let interval = 1.0 / number_of_points;
+        let number_of_points = (total_distance / max_distance).ceil() as usize;
+        let interval = 1.0 / number_of_points as f64;
 
-        let mut current_step = interval;
         let mut points = if include_ends { vec![start] } else { vec![] };
 
-        while current_step < 1.0 {
+        // 使用整数步数而不是累加比例，避免浮点误差在终点附近多生成一个几乎重合的点
+        for step in 1..number_of_points {
+            let current_step = interval * step as f64;
             let (lat2, lon2) = g.direct(start.y(), start.x(), azi1, total_distance * current_step);
             let point = Point::new(lon2, lat2);
             points.push(point);
-            current_step += interval;
         }
 
         if include_ends {
@@ -368,6 +453,57 @@ mod tests {
                     distance.round()
                 );
             }
+
+            #[test]
+            fn point_to_line_string() {
+                use crate::wkt;
+
+                let line_string = wkt!(LINESTRING(10.0 20.0, 30.0 25.0, 30.0 40.0));
+                let point = wkt!(POINT(18.0 26.0));
+
+                // 与沿线段密集采样得到的最小距离比较
+                let brute_force = line_string
+                    .lines()
+                    .flat_map(|line| {
+                        MetricSpace::points_along_line(
+                            line.start_point(),
+                            line.end_point(),
+                            100.0,
+                            true,
+                        )
+                    })
+                    .map(|sample| MetricSpace::distance(point, sample))
+                    .fold(f64::INFINITY, f64::min);
+                let distance = MetricSpace::distance(&point, &line_string);
+                assert!(distance <= brute_force);
+                assert_relative_eq!(distance, brute_force, epsilon = 0.1);
+                assert_eq!(distance, MetricSpace::distance(&line_string, &point));
+
+                // 线上的点
+                let on_line = MetricSpace::point_at_ratio_between(
+                    Point::new(10.0, 20.0),
+                    Point::new(30.0, 25.0),
+                    0.3,
+                );
+                assert_relative_eq!(
+                    MetricSpace::distance(&on_line, &line_string),
+                    0.0,
+                    epsilon = 1e-6
+                );
+            }
+
+            #[test]
+            fn point_to_degenerate_line_string() {
+                let point = Point::new(1.0, 1.0);
+                assert_eq!(
+                    MetricSpace::distance(&point, &LineString::new(vec![])),
+                    f64::INFINITY
+                );
+                assert_eq!(
+                    MetricSpace::distance(&point, &LineString::from(vec![(1.0, 2.0)])),
+                    MetricSpace::distance(point, Point::new(1.0, 2.0))
+                );
+            }
         }
 
         mod interpolate_point {
@@ -147,6 +147,25 @@ impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Haversin
     }
 }
 
+impl<F: CoordFloat + FromPrimitive> Distance<F, (F, F), (F, F)> for Haversine {
+    /// 与 `Distance<F, Point<F>, Point<F>>` 相同，但接受 `(经度, 纬度)` 元组，便于快速计算。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{Distance, Haversine, Point};
+    ///
+    /// let distance = Haversine::distance((-74.006, 40.7128), (-0.1278, 51.5074));
+    /// assert_eq!(
+    ///     distance,
+    ///     Haversine::distance(Point::new(-74.006, 40.7128), Point::new(-0.1278, 51.5074))
+    /// );
+    /// ```
+    fn distance(origin: (F, F), destination: (F, F)) -> F {
+        Self::distance(Point::from(origin), Point::from(destination))
+    }
+}
+
 /// 沿 [大圆] 插值点。
 ///
 /// [大圆]: https://en.wikipedia.org/wiki/Great_circle
@@ -230,24 +249,28 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Haversine {
 
         let total_distance = d * F::from(MEAN_EARTH_RADIUS).unwrap();
 
-        if total_distance <= max_distance {
+        // `max_distance` 不是正数（包括 NaN）时无法加密，与距离足够短时一样只返回端点
+        let number_of_points = if max_distance > F::zero() && total_distance > max_distance {
+            (total_distance / max_distance).ceil().to_usize()
+        } else {
+            None
+        };
+        let Some(number_of_points) = number_of_points else {
             return if include_ends {
                 vec![start, end].into_iter()
             } else {
                 vec![].into_iter()
             };
-        }
-
-        let number_of_points = (total_distance / max_distance).ceil();
-        let interval = F::one() / number_of_points;
+        };
+        let interval = F::one() / F::from(number_of_points).unwrap();
 
-        let mut current_step = interval;
         let mut points = if include_ends { vec![start] } else { vec![] };
 
-        while current_step < F::one() {
+        // 使用整数步数而不是累加比例，避免浮点误差在终点附近多生成一个几乎重合的点
+        for step in 1..number_of_points {
+            let current_step = interval * F::from(step).unwrap();
             let point = calculation.point_at_ratio(current_step);
             points.push(point);
-            current_step = current_step + interval;
         }
 
         if include_ends {
@@ -22,7 +22,7 @@ pub use length::Length;
 
 // 包含加密线段模块
 mod densify;
-pub use densify::Densify;
+pub use densify::{Densify, DensifySegments, DensifyWithFractions};
 
 // 包含度量空间相关模块
 pub mod metric_spaces;
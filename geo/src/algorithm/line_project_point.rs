@@ -0,0 +1,188 @@
+use crate::line_measures::{Distance, Euclidean};
+use crate::{CoordFloat, Line, LineLocatePoint, LineString, Point};
+
+/// 将一个点投影到线上的结果，参见 [`LineProjectPoint`]。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineProjection<T: CoordFloat> {
+    /// 线上距离查询点最近的点。
+    pub point: Point<T>,
+    /// `point` 所在位置占线总长度的分数，范围为 `[0, 1]`。
+    pub fraction: T,
+    /// 查询点到 `point` 的欧几里得距离。
+    pub distance: T,
+    /// `point` 所在线段的索引，即 [`LineString::lines`] 中的位置。对于 [`Line`] 总是 `0`。
+    pub segment_index: usize,
+}
+
+/// 一次性计算线上距离给定点最近的点、它在线上的分数位置、到该点的距离以及所在线段的索引。
+///
+/// 等价于依次调用 [`ClosestPoint`](crate::ClosestPoint)、[`LineLocatePoint`] 和
+/// [`Distance`]，但只遍历一次线段。
+///
+/// 当多个线段与点等距时，返回第一个最近的线段。
+///
+/// 如果线为空，或者点的坐标或线的任何坐标不是有限的，则返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, point, LineProjectPoint};
+///
+/// let line_string = wkt!(LINESTRING(-1.0 0.0,0.0 0.0,0.0 1.0));
+///
+/// let projection = line_string.project_point(&point!(x: 1.0, y: 0.5)).unwrap();
+/// assert_eq!(projection.point, point!(x: 0.0, y: 0.5));
+/// assert_eq!(projection.fraction, 0.75);
+/// assert_eq!(projection.distance, 1.0);
+/// assert_eq!(projection.segment_index, 1);
+/// ```
+pub trait LineProjectPoint<T: CoordFloat> {
+    fn project_point(&self, p: &Point<T>) -> Option<LineProjection<T>>;
+}
+
+impl<T: CoordFloat> LineProjectPoint<T> for Line<T> {
+    fn project_point(&self, p: &Point<T>) -> Option<LineProjection<T>> {
+        let fraction = self.line_locate_point(p)?;
+        let point: Point<T> = (self.start + self.delta() * fraction).into();
+        let distance = Euclidean::distance(point, *p);
+        if !distance.is_finite() {
+            return None;
+        }
+        Some(LineProjection {
+            point,
+            fraction,
+            distance,
+            segment_index: 0,
+        })
+    }
+}
+
+impl<T: CoordFloat> LineProjectPoint<T> for LineString<T> {
+    fn project_point(&self, p: &Point<T>) -> Option<LineProjection<T>> {
+        if self.0.len() == 1 {
+            // 只有一个坐标的线串没有线段，投影退化为该坐标本身
+            return Line::new(self.0[0], self.0[0]).project_point(p);
+        }
+
+        let mut total_length = T::zero();
+        // (投影, 线段起点处的累计长度, 线段长度)
+        let mut closest: Option<(LineProjection<T>, T, T)> = None;
+        for (segment_index, segment) in self.lines().enumerate() {
+            let projection = segment.project_point(p)?;
+            let segment_length = Euclidean::distance(segment.start_point(), segment.end_point());
+            let is_closer = closest
+                .as_ref()
+                .map_or(true, |(best, _, _)| projection.distance < best.distance);
+            if is_closer {
+                closest = Some((
+                    LineProjection {
+                        segment_index,
+                        ..projection
+                    },
+                    total_length,
+                    segment_length,
+                ));
+            }
+            total_length = total_length + segment_length;
+        }
+
+        let (mut projection, length_before, segment_length) = closest?;
+        projection.fraction = if total_length == T::zero() {
+            T::zero()
+        } else {
+            (length_before + projection.fraction * segment_length) / total_length
+        };
+        Some(projection)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, wkt, Closest, ClosestPoint};
+    use num_traits::Float;
+
+    #[test]
+    fn project_point_on_line() {
+        let line = Line::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 10.0, y: 0.0 });
+
+        let projection = line.project_point(&point!(x: 3.0, y: 4.0)).unwrap();
+        assert_eq!(projection.point, point!(x: 3.0, y: 0.0));
+        assert_eq!(projection.fraction, 0.3);
+        assert_eq!(projection.distance, 4.0);
+        assert_eq!(projection.segment_index, 0);
+
+        // 超出端点时投影到端点
+        let projection = line.project_point(&point!(x: 13.0, y: 4.0)).unwrap();
+        assert_eq!(projection.point, point!(x: 10.0, y: 0.0));
+        assert_eq!(projection.fraction, 1.0);
+        assert_eq!(projection.distance, 5.0);
+    }
+
+    #[test]
+    fn project_point_matches_separate_calls() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let query = point!(x: -91.0, y: 30.5);
+
+        let projection = line_string.project_point(&query).unwrap();
+        assert_eq!(
+            Some(projection.fraction),
+            line_string.line_locate_point(&query)
+        );
+        match line_string.closest_point(&query) {
+            Closest::SinglePoint(p) | Closest::Intersection(p) => {
+                assert_relative_eq!(projection.point, p)
+            }
+            Closest::Indeterminate => panic!("应该能找到最近点"),
+        }
+        assert_relative_eq!(
+            projection.distance,
+            Euclidean::distance(&line_string, &query),
+            epsilon = 1e-12
+        );
+        let segment = line_string.lines().nth(projection.segment_index).unwrap();
+        assert_relative_eq!(
+            Euclidean::distance(&segment, &projection.point),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn project_point_equidistant_segments() {
+        // 点与两段线段等距 - 返回第一个最近的线段
+        let line_string = wkt!(LINESTRING(0.0 0.0,1.0 0.0,1.0 1.0,0.0 1.0));
+        let projection = line_string.project_point(&point!(x: 0.0, y: 0.5)).unwrap();
+        assert_eq!(projection.point, point!(x: 0.0, y: 0.0));
+        assert_eq!(projection.fraction, 0.0);
+        assert_eq!(projection.segment_index, 0);
+    }
+
+    #[test]
+    fn project_point_degenerate() {
+        let empty: LineString = wkt!(LINESTRING EMPTY);
+        assert_eq!(empty.project_point(&point!(x: 0.0, y: 0.0)), None);
+
+        let single = wkt!(LINESTRING(1.0 1.0));
+        let projection = single.project_point(&point!(x: 4.0, y: 5.0)).unwrap();
+        assert_eq!(projection.point, point!(x: 1.0, y: 1.0));
+        assert_eq!(projection.fraction, 0.0);
+        assert_eq!(projection.distance, 5.0);
+
+        let collapsed = wkt!(LINESTRING(1.0 1.0,1.0 1.0,1.0 1.0));
+        let projection = collapsed.project_point(&point!(x: 4.0, y: 5.0)).unwrap();
+        assert_eq!(projection.point, point!(x: 1.0, y: 1.0));
+        assert_eq!(projection.fraction, 0.0);
+        assert_eq!(projection.segment_index, 0);
+
+        let line_string = wkt!(LINESTRING(0.0 0.0,1.0 0.0));
+        assert_eq!(
+            line_string.project_point(&point!(x: Float::nan(), y: 0.0)),
+            None
+        );
+        assert_eq!(
+            line_string.project_point(&point!(x: Float::infinity(), y: 0.0)),
+            None
+        );
+    }
+}
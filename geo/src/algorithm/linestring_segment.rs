@@ -115,6 +115,79 @@ implement_segmentize!(
     Haversine
 );
 
+/// 在给定的累积欧几里得距离处切分线串，切分点通过 [`LineInterpolatePoint`] 插值得到。
+///
+/// `distances` 必须按升序排列，否则（或包含 `NaN` 时）返回 `None`。不大于 0 或不小于线串长度的距离，
+/// 以及重复的距离都会被忽略，因此结果中不会出现长度为零的片段；最后一个片段是切分点之后剩余的部分。
+/// 线串为空时返回 `None`。
+///
+/// # 例子
+///
+/// ```
+/// use geo::{wkt, LineStringSplitAtDistances};
+///
+/// let route = wkt!(LINESTRING(0. 0.,150. 0.,150. 100.));
+///
+/// // 每 100 个单位切分一次，超出长度的距离被忽略
+/// let pieces = route.split_at_distances(&[100., 200., 300.]).unwrap();
+/// assert_eq!(
+///     pieces,
+///     vec![
+///         wkt!(LINESTRING(0. 0.,100. 0.)),
+///         wkt!(LINESTRING(100. 0.,150. 0.,150. 50.)),
+///         wkt!(LINESTRING(150. 50.,150. 100.)),
+///     ]
+/// );
+///
+/// assert!(route.split_at_distances(&[200., 100.]).is_none());
+/// ```
+pub trait LineStringSplitAtDistances {
+    fn split_at_distances(&self, distances: &[f64]) -> Option<Vec<LineString>>;
+}
+
+impl LineStringSplitAtDistances for LineString {
+    fn split_at_distances(&self, distances: &[f64]) -> Option<Vec<LineString>> {
+        if distances.iter().any(|distance| distance.is_nan())
+            || distances.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return None;
+        }
+        let first = *self.0.first()?;
+
+        let mut cuts = distances.iter().copied().peekable();
+        let mut pieces = Vec::new();
+        let mut current = vec![first];
+        let mut cumulative = 0.0;
+        let segment_count = self.0.len().saturating_sub(1);
+        for (index, segment) in self.lines().enumerate() {
+            let length = segment.length::<Euclidean>();
+            // 跳过已经走过的距离（包括不大于 0 的距离和重复的距离）
+            while cuts.next_if(|&cut| cut <= cumulative).is_some() {}
+            while let Some(cut) = cuts.next_if(|&cut| cut < cumulative + length) {
+                let point: Coord = segment
+                    .line_interpolate_point((cut - cumulative) / length)?
+                    .into();
+                current.push(point);
+                pieces.push(LineString::new(std::mem::replace(
+                    &mut current,
+                    vec![point],
+                )));
+            }
+            cumulative += length;
+            current.push(segment.end);
+            // 恰好落在中间顶点上的切分点
+            if index + 1 < segment_count && cuts.next_if(|&cut| cut == cumulative).is_some() {
+                pieces.push(LineString::new(std::mem::replace(
+                    &mut current,
+                    vec![segment.end],
+                )));
+            }
+        }
+        pieces.push(LineString::new(current));
+        Some(pieces)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -354,4 +427,40 @@ mod test {
             epsilon = 1e-11
         );
     }
+
+    #[test]
+    fn split_at_distances() {
+        let linestring: LineString = vec![[0.0, 0.0], [3.0, 4.0], [3.0, 10.0]].into();
+
+        // 恰好落在顶点上的切分点，以及重复、非正的距离
+        let pieces = linestring
+            .split_at_distances(&[-1.0, 0.0, 5.0, 5.0, 8.0])
+            .unwrap();
+        assert_eq!(
+            pieces,
+            vec![
+                LineString::from(vec![[0.0, 0.0], [3.0, 4.0]]),
+                LineString::from(vec![[3.0, 4.0], [3.0, 7.0]]),
+                LineString::from(vec![[3.0, 7.0], [3.0, 10.0]]),
+            ]
+        );
+        assert_relative_eq!(
+            pieces.iter().map(|p| p.length::<Euclidean>()).sum::<f64>(),
+            linestring.length::<Euclidean>()
+        );
+
+        // 没有有效的切分点时返回整条线串
+        assert_eq!(
+            linestring.split_at_distances(&[]).unwrap(),
+            vec![linestring.clone()]
+        );
+        assert_eq!(
+            linestring.split_at_distances(&[11.0, 20.0]).unwrap(),
+            vec![linestring.clone()]
+        );
+
+        assert!(linestring.split_at_distances(&[2.0, f64::NAN]).is_none());
+        assert!(linestring.split_at_distances(&[3.0, 2.0]).is_none());
+        assert!(LineString::new(vec![]).split_at_distances(&[1.0]).is_none());
+    }
 }
@@ -73,6 +73,9 @@ pub trait MapCoords<T, NT> {
 
     /// 在几何中的所有坐标上映射一个可能失败的函数，返回一个 Result
     ///
+    /// 函数第一次返回 `Err` 时立即返回该错误，不再处理其余的坐标。对于 [`Geometry`] 和
+    /// [`GeometryCollection`]，这同样适用于所有嵌套的几何体。
+    ///
     /// # 示例
     ///
     /// ```
@@ -607,8 +610,10 @@ impl<T: CoordNum> MapCoordsInPlace<T> for GeometryCollection<T> {
         &mut self,
         func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
     ) -> Result<(), E> {
+        // 统一为 `&dyn Fn`，否则嵌套的几何集合每递归一层就会多一层引用类型，导致单态化无限展开
+        let func: &dyn Fn(Coord<T>) -> Result<Coord<T>, E> = &func;
         for p in &mut self.0 {
-            p.try_map_coords_in_place(&func)?;
+            p.try_map_coords_in_place(func)?;
         }
         Ok(())
     }
@@ -928,6 +933,73 @@ mod test {
         );
     }
 
+    #[test]
+    fn geometrycollection_inplace() {
+        let nested = GeometryCollection::new_from(vec![Geometry::Line(Line::new(
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 2. },
+        ))]);
+        let mut gc = GeometryCollection::new_from(vec![
+            Geometry::Point(Point::new(10., 10.)),
+            Geometry::GeometryCollection(nested),
+        ]);
+
+        gc.map_coords_in_place(|Coord { x, y }| (x + 10., y + 100.).into());
+        assert_eq!(
+            gc,
+            GeometryCollection::new_from(vec![
+                Geometry::Point(Point::new(20., 110.)),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::Line(
+                    Line::new(coord! { x: 11., y: 101. }, coord! { x: 12., y: 102. })
+                )])),
+            ])
+        );
+
+        let result = gc.try_map_coords_in_place(|Coord { x, y }| {
+            if x > 11. {
+                Err("too large")
+            } else {
+                Ok(coord! { x: -x, y: -y })
+            }
+        });
+        assert_eq!(result, Err("too large"));
+    }
+
+    #[test]
+    fn geometrycollection_try_map_coords() {
+        let gc = GeometryCollection::new_from(vec![
+            Geometry::Point(Point::new(10., 80.)),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![Geometry::Line(
+                Line::new(coord! { x: 1., y: 89. }, coord! { x: 2., y: 91. }),
+            )])),
+            Geometry::Point(Point::new(20., 95.)),
+        ]);
+
+        // 数值类型的变化贯穿嵌套的几何集合
+        let converted: GeometryCollection<f32> = gc
+            .try_map_coords(|Coord { x, y }| -> Result<_, ()> {
+                Ok(coord! { x: x as f32, y: y as f32 })
+            })
+            .unwrap();
+        assert_eq!(
+            converted,
+            gc.map_coords(|Coord { x, y }| coord! { x: x as f32, y: y as f32 })
+        );
+
+        // 遇到第一个错误时立即返回，不再调用后面的坐标
+        let calls = std::cell::Cell::new(0);
+        let result = gc.try_map_coords(|Coord { x, y }| {
+            calls.set(calls.get() + 1);
+            if y > 90. {
+                Err(format!("latitude out of range: {y}"))
+            } else {
+                Ok(coord! { x: x as f32, y: y as f32 })
+            }
+        });
+        assert_eq!(result, Err("latitude out of range: 91".to_string()));
+        assert_eq!(calls.get(), 3);
+    }
+
     #[test]
     fn convert_type() {
         let p1: Point<f64> = Point::new(1., 2.);
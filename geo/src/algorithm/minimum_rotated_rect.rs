@@ -2,8 +2,12 @@ use num_traits::Float;
 
 use crate::{
     algorithm::{centroid::Centroid, rotate::Rotate, BoundingRect, CoordsIter},
-    Area, ConvexHull, CoordFloat, GeoFloat, GeoNum, LinesIter, Polygon,
+    Area, ConvexHull, CoordFloat, GeoFloat, GeoNum, LinesIter, Point, Polygon, Rect,
 };
+
+/// [`MinimumRotatedRect::minimum_rotated_rect_with_metadata`] 的返回值：`(矩形, 角度, 宽度, 高度)`。
+pub type RotatedRectWithMetadata<T> = (Polygon<T>, T, T, T);
+
 /// 返回几何体的最小边界矩形(MBR)
 /// 参考资料: <https://en.wikipedia.org/wiki/Minimum_bounding_box>
 /// 最小旋转矩形是可以包含给定所有点且面积最小的矩形
@@ -30,6 +34,35 @@ use crate::{
 pub trait MinimumRotatedRect<T> {
     type Scalar: GeoNum;
     fn minimum_rotated_rect(&self) -> Option<Polygon<Self::Scalar>>;
+
+    /// 返回最小旋转矩形，以及它的旋转角度、宽度和高度，例如用于确定标注的方向。
+    ///
+    /// 返回值依次为 `(矩形, 角度, 宽度, 高度)`：
+    /// - 矩形与 [`minimum_rotated_rect`](Self::minimum_rotated_rect) 的结果相同；
+    /// - 宽度是较长的一边，高度是较短的一边；
+    /// - 角度是长边相对于 x 轴逆时针旋转的角度，单位为度，范围为 `[0, 180)`。
+    ///
+    /// 如果几何体为空或所有点共线（矩形面积为零），则返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{polygon, MinimumRotatedRect};
+    ///
+    /// // 一个旋转了 30 度的 4x2 矩形
+    /// let poly = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 3.4641016151377544, y: 2.),
+    ///     (x: 2.4641016151377544, y: 3.732050807568877),
+    ///     (x: -1., y: 1.7320508075688772),
+    /// ];
+    /// let (_rect, angle, width, height) = poly.minimum_rotated_rect_with_metadata().unwrap();
+    /// assert_relative_eq!(angle, 30., epsilon = 1e-9);
+    /// assert_relative_eq!(width, 4., epsilon = 1e-9);
+    /// assert_relative_eq!(height, 2., epsilon = 1e-9);
+    /// ```
+    fn minimum_rotated_rect_with_metadata(&self) -> Option<RotatedRectWithMetadata<Self::Scalar>>;
 }
 
 impl<T, G> MinimumRotatedRect<T> for G
@@ -40,44 +73,106 @@ where
     type Scalar = T;
 
     fn minimum_rotated_rect(&self) -> Option<Polygon<Self::Scalar>> {
-        // 计算凸包
-        let convex_poly = ConvexHull::convex_hull(self);
-        // 初始化最小面积为最大浮点值
-        let mut min_area: T = Float::max_value();
-        // 初始化最小角度为零
-        let mut min_angle: T = T::zero();
-        // 初始矩形多边形为None
-        let mut rect_poly: Option<Polygon<T>> = None;
-        // 获取旋转的参考点（质心）
-        let rotate_point = convex_poly.centroid();
-        for line in convex_poly.exterior().lines_iter() {
-            // 获取线段的两个点
-            let (ci, cii) = line.points();
-            // 计算旋转角度
-            let angle = (cii.y() - ci.y()).atan2(cii.x() - ci.x()).to_degrees();
-            // 逆时针旋转多边形
-            let rotated_poly = Rotate::rotate_around_point(&convex_poly, -angle, rotate_point?);
-            // 获取旋转后的边界矩形并转化为多边形
-            let tmp_poly = rotated_poly.bounding_rect()?.to_polygon();
-            // 计算面积
-            let area = tmp_poly.unsigned_area();
-            // 如果面积小于记录的最小面积，则更新最小面积和所使用的角度
-            if area < min_area {
-                min_area = area;
-                min_angle = angle;
-                rect_poly = Some(tmp_poly);
-            }
-        }
+        let calipers = rotating_calipers(self)?;
         // 使用最小角度对最终的矩形多边形进行旋转校正
-        Some(rect_poly?.rotate_around_point(min_angle, rotate_point?))
+        Some(calipers.polygon())
+    }
+
+    fn minimum_rotated_rect_with_metadata(&self) -> Option<RotatedRectWithMetadata<T>> {
+        let calipers = rotating_calipers(self)?;
+        // 旋转后的矩形可能因舍入而具有极小的非零高度，因此检查凸包的面积
+        if calipers.hull_area == T::zero() {
+            return None;
+        }
+        let polygon = calipers.polygon();
+        let Calipers { rect, angle, .. } = calipers;
+        // 矩形的宽度沿 `angle` 方向，高度沿垂直方向；使宽度始终为长边
+        let (angle, width, height) = if rect.width() >= rect.height() {
+            (angle, rect.width(), rect.height())
+        } else {
+            (angle + T::from(90).unwrap(), rect.height(), rect.width())
+        };
+        let half_turn = T::from(180).unwrap();
+        let mut angle = angle % half_turn;
+        if angle < T::zero() {
+            angle = angle + half_turn;
+        }
+        // 舍入可能使负的极小角度变为 180
+        if angle >= half_turn {
+            angle = T::zero();
+        }
+        Some((polygon, angle, width, height))
     }
 }
 
+/// 旋转卡壳法的结果。
+struct Calipers<T: CoordFloat> {
+    /// 在旋转后的坐标系中轴对齐的最小矩形
+    rect: Rect<T>,
+    /// 将矩形旋转回原位置所需的角度（度）
+    angle: T,
+    /// 旋转的中心点
+    rotate_point: Point<T>,
+    /// 凸包的面积
+    hull_area: T,
+}
+
+impl<T: GeoFloat> Calipers<T> {
+    fn polygon(&self) -> Polygon<T> {
+        self.rect
+            .to_polygon()
+            .rotate_around_point(self.angle, self.rotate_point)
+    }
+}
+
+/// 使用旋转卡壳法找到面积最小的矩形。
+fn rotating_calipers<T, G>(geometry: &G) -> Option<Calipers<T>>
+where
+    T: CoordFloat + GeoFloat + GeoNum,
+    G: CoordsIter<Scalar = T>,
+{
+    // 计算凸包
+    let convex_poly = ConvexHull::convex_hull(geometry);
+    // 初始化最小面积为最大浮点值
+    let mut min_area: T = Float::max_value();
+    // 初始化最小角度为零
+    let mut min_angle: T = T::zero();
+    // 初始矩形为None
+    let mut min_rect: Option<Rect<T>> = None;
+    // 获取旋转的参考点（质心）
+    let rotate_point = convex_poly.centroid()?;
+    for line in convex_poly.exterior().lines_iter() {
+        // 获取线段的两个点
+        let (ci, cii) = line.points();
+        // 计算旋转角度
+        let angle = (cii.y() - ci.y()).atan2(cii.x() - ci.x()).to_degrees();
+        // 逆时针旋转多边形
+        let rotated_poly = Rotate::rotate_around_point(&convex_poly, -angle, rotate_point);
+        // 获取旋转后的边界矩形
+        let tmp_rect = rotated_poly.bounding_rect()?;
+        // 计算面积
+        let area = tmp_rect.to_polygon().unsigned_area();
+        // 如果面积小于记录的最小面积，则更新最小面积和所使用的角度
+        if area < min_area {
+            min_area = area;
+            min_angle = angle;
+            min_rect = Some(tmp_rect);
+        }
+    }
+    Some(Calipers {
+        rect: min_rect?,
+        angle: min_angle,
+        rotate_point,
+        hull_area: convex_poly.unsigned_area(),
+    })
+}
+
 #[cfg(test)]
 mod test {
+    use approx::assert_relative_eq;
     use geo_types::{line_string, polygon, LineString, Polygon};
 
-    use crate::MinimumRotatedRect;
+    use crate::{Area, Distance, Euclidean, MinimumRotatedRect};
 
     #[test]
     fn returns_polygon_mbr() {
@@ -111,4 +206,50 @@ mod test {
             ])
         );
     }
+    #[test]
+    fn metadata_matches_rect() {
+        let poly: Polygon<f64> = polygon![(x: 3.3, y: 30.4), (x: 1.7, y: 24.6), (x: 13.4, y: 25.1), (x: 14.4, y: 31.0),(x:3.3,y:30.4)];
+        let (rect, angle, width, height) = poly.minimum_rotated_rect_with_metadata().unwrap();
+        assert_eq!(rect, poly.minimum_rotated_rect().unwrap());
+        assert!(width >= height);
+        assert!((0. ..180.).contains(&angle));
+        assert_relative_eq!(width * height, rect.unsigned_area(), epsilon = 1e-9);
+
+        // 长边的方向与角度一致
+        let coords: Vec<_> = rect.exterior().coords().copied().collect();
+        let (long_start, long_end) = if Euclidean::distance(coords[0], coords[1])
+            > Euclidean::distance(coords[1], coords[2])
+        {
+            (coords[0], coords[1])
+        } else {
+            (coords[1], coords[2])
+        };
+        assert_relative_eq!(
+            Euclidean::distance(long_start, long_end),
+            width,
+            epsilon = 1e-9
+        );
+        let direction = long_end - long_start;
+        let expected = direction.y.atan2(direction.x).to_degrees().rem_euclid(180.);
+        assert_relative_eq!(angle, expected, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn metadata_for_tall_axis_aligned_rect() {
+        let poly: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 5.), (x: 0., y: 5.)];
+        let (_, angle, width, height) = poly.minimum_rotated_rect_with_metadata().unwrap();
+        assert_relative_eq!(angle, 90.);
+        assert_relative_eq!(width, 5.);
+        assert_relative_eq!(height, 1.);
+    }
+
+    #[test]
+    fn metadata_for_degenerate_input() {
+        let collinear: LineString<f64> =
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        assert!(collinear.minimum_rotated_rect_with_metadata().is_none());
+        let empty: LineString<f64> = line_string![];
+        assert!(empty.minimum_rotated_rect_with_metadata().is_none());
+    }
 }
@@ -6,13 +6,17 @@ pub use kernels::{Kernel, Orientation};
 pub mod area;
 pub use area::Area;
 
+/// 由一组点生成感兴趣区域多边形：凸包、边界矩形或凹包。
+pub mod area_of_interest;
+pub use area_of_interest::{AoiMethod, ToAoi};
+
 /// 布尔运算，如两个几何图形的并集、异或或差值。
 pub mod bool_ops;
-pub use bool_ops::{unary_union, BooleanOps, OpType};
+pub use bool_ops::{unary_union, unary_union_owned, BooleanOps, Dissolve, FillRule, OpType};
 
 /// 计算几何图形的边界矩形。
 pub mod bounding_rect;
-pub use bounding_rect::BoundingRect;
+pub use bounding_rect::{BoundingRect, BoundingRectAccumulator, BoundingRectBuilder};
 
 /// 计算几何图形的最小旋转矩形。
 pub mod minimum_rotated_rect;
@@ -20,7 +24,7 @@ pub use minimum_rotated_rect::MinimumRotatedRect;
 
 /// 计算几何对象的中心点。
 pub mod centroid;
-pub use centroid::Centroid;
+pub use centroid::{Centroid, CentroidAllDimensions};
 
 /// 使用Chaikins算法平滑`LineString`、`Polygon`、`MultiLineString`和`MultiPolygon`。
 pub mod chaikin_smoothing;
@@ -34,9 +38,13 @@ pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 pub mod closest_point;
 pub use closest_point::ClosestPoint;
 
+/// 几何体的结构复杂度度量：顶点、环和组成部分的数量。
+pub mod complexity;
+pub use complexity::Complexity;
+
 /// 计算几何图形的凹壳。
 pub mod concave_hull;
-pub use concave_hull::ConcaveHull;
+pub use concave_hull::{ConcaveHull, HullBuilder};
 
 /// 判断几何图形`A`是否完全包围几何图形`B`。
 pub mod contains;
@@ -71,6 +79,14 @@ pub mod densify_haversine;
 #[allow(deprecated)]
 pub use densify_haversine::DensifyHaversine;
 
+/// 对整数坐标的几何体进行密集化，插入的点四舍五入到整数。
+pub mod densify_integer;
+pub use densify_integer::DensifyInteger;
+
+/// 在线串中识别近似圆弧的连续点。
+pub mod detect_arcs;
+pub use detect_arcs::{ArcSegment, DetectArcs};
+
 /// 几何图形及其边界的维度，基于 OGC-SFA。
 pub mod dimensions;
 pub use dimensions::HasDimensions;
@@ -87,7 +103,11 @@ pub use euclidean_length::EuclideanLength;
 
 /// 计算几何体的极值坐标和索引。
 pub mod extremes;
-pub use extremes::Extremes;
+pub use extremes::{Extremes, ExtremesIndexed};
+
+/// 通过闭包就地修改几何图形的坐标。
+pub mod for_each_coord_mut;
+pub use for_each_coord_mut::ForEachCoordMut;
 
 /// 计算两个`线串`之间的Fréchet距离。
 pub mod frechet_distance;
@@ -170,6 +190,14 @@ pub use is_convex::IsConvex;
 pub mod k_nearest_concave_hull;
 pub use k_nearest_concave_hull::KNearestConcaveHull;
 
+/// 按面积选择或排序`多多边形`的成员多边形。
+pub mod largest_polygon;
+pub use largest_polygon::LargestPolygon;
+
+/// 按字典顺序比较坐标，并求迭代器中字典顺序最小或最大的坐标。
+pub mod lex_order;
+pub use lex_order::{lex_cmp, max_coord, min_coord};
+
 /// 沿着`线`或`线串`插入一个点。
 pub mod line_interpolate_point;
 pub use line_interpolate_point::LineInterpolatePoint;
@@ -182,6 +210,10 @@ pub use line_intersection::LineIntersection;
 pub mod line_locate_point;
 pub use line_locate_point::LineLocatePoint;
 
+/// 将一个点投影到`线`或`线串`上，同时得到最近点、分数位置和距离。
+pub mod line_project_point;
+pub use line_project_point::{LineProjectPoint, LineProjection};
+
 /// 在几何体中迭代线。
 pub mod lines_iter;
 pub use lines_iter::LinesIter;
@@ -189,20 +221,49 @@ pub use lines_iter::LinesIter;
 /// 线度量相关模块和对外接口，包括欧氏空间、测地空间及Haversine、Rhumb测地函数的接口。
 pub mod line_measures;
 pub use line_measures::metric_spaces::{Euclidean, Geodesic, Haversine, Rhumb};
-pub use line_measures::{Bearing, Densify, Destination, Distance, InterpolatePoint, Length};
+pub use line_measures::{
+    Bearing, Densify, DensifySegments, DensifyWithFractions, Destination, Distance,
+    InterpolatePoint, Length,
+};
 
 /// 将`线串`拆分为n段
 pub mod linestring_segment;
-pub use linestring_segment::{LineStringSegmentize, LineStringSegmentizeHaversine};
+pub use linestring_segment::{
+    LineStringSegmentize, LineStringSegmentizeHaversine, LineStringSplitAtDistances,
+};
 
 /// 对`几何体`的所有`坐标`应用一个函数。
 pub mod map_coords;
 pub use map_coords::{MapCoords, MapCoordsInPlace};
 
+/// 在 R 树中按精确距离查找离某点最近的几何体。
+pub mod nearest_geometry;
+pub use nearest_geometry::NearestGeometry;
+
+/// 将`多边形`转换为规范形式，以便进行结构相等比较和哈希。
+pub mod normalize;
+pub use normalize::Normalize;
+
+/// 在所有交点处打断一组`线串`，生成平面化的边集合。
+pub mod noding;
+pub use noding::node;
+
+/// 计算`线`或`线串`一侧的平行偏移线。
+pub mod offset_curve;
+pub use offset_curve::OffsetCurve;
+
 /// 定向化`多边形`的外部和内部环。
 pub mod orient;
 pub use orient::Orient;
 
+/// 由一组已打断的`线串`构建多边形。
+pub mod polygonize;
+pub use polygonize::polygonize;
+
+/// 预先建立索引，以便重复查询点到同一几何体的距离和最近点。
+pub mod prepared_distance;
+pub use prepared_distance::PreparedDistance;
+
 /// 使用当前稳定版本的 [PROJ](http://proj.org) 进行坐标投影和转换。
 #[cfg(feature = "use-proj")]
 pub mod proj;
@@ -213,11 +274,15 @@ pub use relate::Relate;
 
 /// 移除（连续的）重复点
 pub mod remove_repeated_points;
-pub use remove_repeated_points::RemoveRepeatedPoints;
+pub use remove_repeated_points::{RemoveRepeatedPoints, RemoveRepeatedPointsWithin};
+
+/// 移除几何体原路折返形成的尖刺
+pub mod remove_spikes;
+pub use remove_spikes::RemoveSpikes;
 
 /// 根据给定的角度旋转`几何体`。
 pub mod rotate;
-pub use rotate::Rotate;
+pub use rotate::{Rotate, RotateGeographic};
 
 /// 按比例放大或缩小`几何体`
 pub mod scale;
@@ -233,12 +298,19 @@ pub use affine_ops::{AffineOps, AffineTransform};
 
 /// 使用 Ramer-Douglas-Peucker 算法简化`几何体`。
 pub mod simplify;
-pub use simplify::{Simplify, SimplifyIdx};
+pub use simplify::{
+    Simplify, SimplifyIdx, SimplifyPreserveClosed, SimplifyPreservingArea, SimplifyRing,
+    SimplifyValid,
+};
 
 /// 使用 Visvalingam-Whyatt 算法对`几何体`进行简化。包括拓扑保持的变体。
 pub mod simplify_vw;
 pub use simplify_vw::{SimplifyVw, SimplifyVwIdx, SimplifyVwPreserve};
 
+/// 按最小距离对`多点`进行抽稀。
+pub mod thin_points;
+pub use thin_points::ThinPoints;
+
 /// 将邻边三角形缝合在一起。与通过 BooleanOps 结合三角形的替代方法。
 #[allow(dead_code)]
 pub(crate) mod stitch;
@@ -248,7 +320,7 @@ pub use stitch::StitchTriangles;
 #[cfg(feature = "use-proj")]
 pub mod transform;
 #[cfg(feature = "use-proj")]
-pub use transform::Transform;
+pub use transform::{transform_many, Transform};
 
 /// 沿给定偏移量平移`几何体`。
 pub mod translate;
@@ -270,7 +342,7 @@ pub use triangulate_spade::TriangulateSpade;
 
 /// 二维坐标的向量操作
 mod vector_ops;
-pub use vector_ops::Vector2DOps;
+pub use vector_ops::{angle_at_vertex, Vector2DOps};
 
 /// 计算两个`点`之间的 Vincenty 距离。
 pub mod vincenty_distance;
@@ -282,7 +354,15 @@ pub use vincenty_length::VincentyLength;
 
 /// 计算及处理`线串`的环绕顺序。
 pub mod winding_order;
-pub use winding_order::Winding;
+pub use winding_order::{RingWindingOrders, ToPolygonWithWinding, Winding};
+
+/// 将几何体写为 WKT 字符串，可控制坐标精度，无需依赖 `wkt` crate。
+pub mod wkt_string;
+pub use wkt_string::ToWktString;
+
+/// 将`多线串`各组成部分中彼此接近的端点焊接到同一坐标。
+pub mod weld_endpoints;
+pub use weld_endpoints::WeldEndpoints;
 
 /// 判断`几何体`A是否被完全包含在`几何体`B之内。
 pub mod within;
@@ -294,7 +374,7 @@ pub mod sweep;
 /// 使用 [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor) 检测一组点中的离群值
 pub mod outlier_detection;
 
-pub use outlier_detection::OutlierDetection;
+pub use outlier_detection::{OutlierDetection, OutlierRecord};
 
 /// 单调多边形细分
 pub mod monotone;
@@ -307,4 +387,4 @@ pub use rhumb::{RhumbBearing, RhumbDestination, RhumbDistance, RhumbIntermediate
 
 /// 验证模块和对外接口
 pub mod validation;
-pub use validation::Validation;
+pub use validation::{MakeValidOrientation, Validation};
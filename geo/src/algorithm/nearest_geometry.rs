@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use rstar::primitives::GeomWithData;
+use rstar::{ParentNode, RTree, RTreeNode, RTreeNum, RTreeObject, AABB};
+
+use crate::{Distance, Euclidean, GeoFloat, Point};
+
+/// 在由 `geo` 几何体组成的 [`RTree`] 中，按照精确的欧几里得距离查找离查询点最近的几何体。
+///
+/// `rstar` 的 [`RTree::nearest_neighbor`] 需要对象实现 [`PointDistance`](rstar::PointDistance)，
+/// 而 [`Polygon`](crate::Polygon)、[`MultiPolygon`](crate::MultiPolygon) 等几何体只实现了
+/// [`RTreeObject`]。如果改为比较包络框的距离，当某个几何体的包络框很大（例如一条斜线）时，
+/// 就会得到错误的结果。
+///
+/// 此方法以包络框的距离作为下界，按由近到远的顺序遍历树的节点，
+/// 只对可能更近的几何体计算 [`Euclidean`] 距离，因此结果与对所有几何体逐一计算距离相同。
+/// 返回最近的对象（包括其附带的数据）以及它到查询点的距离；树为空时返回 `None`。
+/// 距离相同时返回其中任意一个。
+///
+/// 如果还需要几何体上最近的点，可以对返回的几何体使用 [`ClosestPoint`](crate::ClosestPoint)。
+///
+/// # 例子
+///
+/// ```
+/// use geo::{point, wkt, NearestGeometry};
+/// use rstar::{primitives::GeomWithData, RTree};
+///
+/// let tree = RTree::bulk_load(vec![
+///     GeomWithData::new(wkt!(LINESTRING(0. 0.,10. 10.)), "main street"),
+///     GeomWithData::new(wkt!(LINESTRING(9. 0.,9. 2.)), "side street"),
+/// ]);
+///
+/// // 查询点位于主街的包络框内，但离小巷更近
+/// let (nearest, distance) = tree.nearest_geometry(&point!(x: 8., y: 1.)).unwrap();
+/// assert_eq!(nearest.data, "side street");
+/// assert_eq!(distance, 1.);
+/// ```
+pub trait NearestGeometry<T: GeoFloat> {
+    /// 树中存储的对象类型。
+    type Object;
+
+    fn nearest_geometry(&self, query: &Point<T>) -> Option<(&Self::Object, T)>;
+}
+
+impl<T, G, D> NearestGeometry<T> for RTree<GeomWithData<G, D>>
+where
+    T: GeoFloat + RTreeNum,
+    G: RTreeObject<Envelope = AABB<Point<T>>>,
+    for<'a> Euclidean: Distance<T, &'a Point<T>, &'a G>,
+{
+    type Object = GeomWithData<G, D>;
+
+    fn nearest_geometry(&self, query: &Point<T>) -> Option<(&Self::Object, T)> {
+        if self.size() == 0 {
+            return None;
+        }
+        let mut queue = BinaryHeap::new();
+        queue.push(QueueEntry {
+            distance_2: self.root().envelope().distance_2(query),
+            candidate: Candidate::Node(self.root()),
+        });
+        while let Some(QueueEntry { candidate, .. }) = queue.pop() {
+            let node = match candidate {
+                // 队列中剩余的所有节点的包络框距离都不小于此距离
+                Candidate::Object(object, distance) => return Some((object, distance)),
+                Candidate::Node(node) => node,
+            };
+            for child in node.children() {
+                let entry = match child {
+                    RTreeNode::Leaf(object) => {
+                        let distance = Euclidean::distance(query, object.geom());
+                        QueueEntry {
+                            distance_2: distance * distance,
+                            candidate: Candidate::Object(object, distance),
+                        }
+                    }
+                    RTreeNode::Parent(parent) => QueueEntry {
+                        distance_2: parent.envelope().distance_2(query),
+                        candidate: Candidate::Node(parent),
+                    },
+                };
+                queue.push(entry);
+            }
+        }
+        None
+    }
+}
+
+enum Candidate<'a, T, O: RTreeObject> {
+    Node(&'a ParentNode<O>),
+    Object(&'a O, T),
+}
+
+struct QueueEntry<'a, T, O: RTreeObject> {
+    distance_2: T,
+    candidate: Candidate<'a, T, O>,
+}
+
+// 这些impls为我们提供了一个最小堆
+impl<T: GeoFloat, O: RTreeObject> Ord for QueueEntry<'_, T, O> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance_2
+            .partial_cmp(&self.distance_2)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<T: GeoFloat, O: RTreeObject> PartialOrd for QueueEntry<'_, T, O> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat, O: RTreeObject> Eq for QueueEntry<'_, T, O> {}
+
+impl<T: GeoFloat, O: RTreeObject> PartialEq for QueueEntry<'_, T, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance_2 == other.distance_2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Polygon, Translate};
+
+    #[test]
+    fn matches_brute_force() {
+        // 足够多的几何体，使树有多层节点
+        let triangle = wkt!(POLYGON((0. 0.,2. 0.,0. 2.,0. 0.)));
+        let polygons: Vec<Polygon> = (0..20)
+            .flat_map(|i| (0..20).map(move |j| (i as f64 * 3., j as f64 * 3.)))
+            .map(|(x, y)| triangle.translate(x, y))
+            .collect();
+        let tree = RTree::bulk_load(
+            polygons
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(index, polygon)| GeomWithData::new(polygon, index))
+                .collect(),
+        );
+
+        for query in [
+            point!(x: 1.9, y: 1.9),
+            point!(x: 0.5, y: 0.5),
+            point!(x: -5., y: 30.),
+            point!(x: 31.1, y: 17.4),
+        ] {
+            let (nearest, distance) = tree.nearest_geometry(&query).unwrap();
+            let expected = polygons
+                .iter()
+                .map(|polygon| Euclidean::distance(&query, polygon))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(distance, expected);
+            assert_eq!(
+                Euclidean::distance(&query, &polygons[nearest.data]),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn large_envelope_is_not_nearest() {
+        // 一个沿对角线的细长多边形
+        let diagonal = wkt!(POLYGON((0. 0.,100. 100.,99. 100.,0. 0.)));
+        let square = wkt!(POLYGON((60. 10.,62. 10.,62. 12.,60. 12.,60. 10.)));
+        let tree = RTree::bulk_load(vec![
+            GeomWithData::new(diagonal, 0),
+            GeomWithData::new(square, 1),
+        ]);
+
+        let query = point!(x: 60., y: 20.);
+        // 斜线的包络框包含查询点
+        assert_eq!(
+            tree.iter()
+                .find(|object| object.data == 0)
+                .unwrap()
+                .envelope()
+                .distance_2(&query),
+            0.
+        );
+        let (nearest, distance) = tree.nearest_geometry(&query).unwrap();
+        assert_eq!(nearest.data, 1);
+        assert_eq!(distance, 8.);
+    }
+
+    #[test]
+    fn empty_tree() {
+        let tree: RTree<GeomWithData<Polygon, ()>> = RTree::new();
+        assert!(tree.nearest_geometry(&point!(x: 0., y: 0.)).is_none());
+    }
+}
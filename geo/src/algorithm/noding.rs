@@ -0,0 +1,265 @@
+use std::cmp::Ordering;
+
+use crate::line_intersection::LineIntersection;
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, Line, LineString, MultiLineString};
+
+/// 在所有交点处打断输入的线，生成一组只在端点处相接的边（平面化的线排列）。
+///
+/// 输出中的每条边都是原始某条线的一部分，并保留原始的中间顶点。
+/// 两条边要么不相交，要么只在共同的端点处相接，因此结果可以直接作为拓扑图的边。
+/// 线与自身的交点同样会被打断。
+///
+/// 重叠（共线）的部分在输出中只出现一次。
+/// 零长度的线段会被忽略，少于两个不同坐标的线不会产生任何边。
+///
+/// 交点使用 [`Intersections`] 的 Bentley–Ottmann 扫描计算，时间复杂度为 O((n + k) log n)。
+/// 交点坐标以浮点数计算，多于两条线在同一点相交时，不同线对计算出的交点可能有微小差异。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{node, wkt};
+///
+/// let lines = [
+///     wkt!(LINESTRING(0. 0.,2. 2.)),
+///     wkt!(LINESTRING(0. 2.,2. 0.)),
+/// ];
+///
+/// assert_eq!(
+///     node(&lines),
+///     wkt!(MULTILINESTRING(
+///         (0. 0.,1. 1.),
+///         (1. 1.,2. 2.),
+///         (0. 2.,1. 1.),
+///         (1. 1.,2. 0.)
+///     ))
+/// );
+/// ```
+pub fn node<T: GeoFloat>(lines: &[LineString<T>]) -> MultiLineString<T> {
+    let segments: Vec<NodingSegment<T>> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(line_idx, line_string)| {
+            line_string
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.start != line.end)
+                .map(move |(segment_idx, line)| NodingSegment {
+                    line,
+                    line_idx,
+                    segment_idx,
+                })
+        })
+        .collect();
+
+    // 每条线上需要打断的顶点，以及每条线段内部的打断点
+    let mut vertex_nodes: Vec<Vec<bool>> = lines
+        .iter()
+        .map(|line_string| vec![false; line_string.0.len()])
+        .collect();
+    let mut segment_nodes: Vec<Vec<Vec<Coord<T>>>> = lines
+        .iter()
+        .map(|line_string| vec![vec![]; line_string.0.len().saturating_sub(1)])
+        .collect();
+
+    let mut add_node = |segment: &NodingSegment<T>, coord: Coord<T>| {
+        if coord == segment.line.start {
+            vertex_nodes[segment.line_idx][segment.segment_idx] = true;
+        } else if coord == segment.line.end {
+            vertex_nodes[segment.line_idx][segment.segment_idx + 1] = true;
+        } else {
+            segment_nodes[segment.line_idx][segment.segment_idx].push(coord);
+        }
+    };
+
+    for (a, b, intersection) in Intersections::from_iter(segments.iter()) {
+        match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => {
+                // 同一条线上相邻的线段总是在共同的顶点处相接，这不构成节点
+                if a.is_adjacent_to(b) && a.shares_vertex(b, intersection) {
+                    continue;
+                }
+                add_node(a, intersection);
+                add_node(b, intersection);
+            }
+            LineIntersection::Collinear { intersection } => {
+                for coord in [intersection.start, intersection.end] {
+                    add_node(a, coord);
+                    add_node(b, coord);
+                }
+            }
+        }
+    }
+
+    let mut edges = vec![];
+    for (line_idx, line_string) in lines.iter().enumerate() {
+        let mut edge: Vec<Coord<T>> = vec![];
+        let mut push = |edge: &mut Vec<Coord<T>>, coord: Coord<T>, is_node: bool| {
+            if edge.last() != Some(&coord) {
+                edge.push(coord);
+            }
+            if is_node && edge.len() > 1 {
+                edges.push(LineString::new(std::mem::replace(edge, vec![coord])));
+            }
+        };
+
+        for (segment_idx, line) in line_string.lines().enumerate() {
+            push(&mut edge, line.start, vertex_nodes[line_idx][segment_idx]);
+            let nodes = &mut segment_nodes[line_idx][segment_idx];
+            nodes.sort_by(|p, q| {
+                distance_2(line.start, *p)
+                    .partial_cmp(&distance_2(line.start, *q))
+                    .unwrap_or(Ordering::Equal)
+            });
+            for node in nodes.iter() {
+                push(&mut edge, *node, true);
+            }
+        }
+        if let Some(last) = line_string.0.last() {
+            push(&mut edge, *last, true);
+        }
+    }
+
+    MultiLineString::new(dedup_edges(edges))
+}
+
+/// 移除重复的边（包括方向相反的重复边），保持其余边的顺序。
+fn dedup_edges<T: GeoFloat>(edges: Vec<LineString<T>>) -> Vec<LineString<T>> {
+    let canonical: Vec<Vec<Coord<T>>> = edges
+        .iter()
+        .map(|edge| {
+            let reversed: Vec<_> = edge.0.iter().rev().copied().collect();
+            if coords_cmp(&reversed, &edge.0).is_lt() {
+                reversed
+            } else {
+                edge.0.clone()
+            }
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&i, &j| coords_cmp(&canonical[i], &canonical[j]).then(i.cmp(&j)));
+    let mut keep = vec![true; edges.len()];
+    for pair in order.windows(2) {
+        if canonical[pair[0]] == canonical[pair[1]] {
+            keep[pair[1]] = false;
+        }
+    }
+
+    edges
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(edge, keep)| keep.then_some(edge))
+        .collect()
+}
+
+fn coords_cmp<T: GeoFloat>(a: &[Coord<T>], b: &[Coord<T>]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(p, q)| lex_cmp(p, q))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn distance_2<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    let delta = b - a;
+    delta.x * delta.x + delta.y * delta.y
+}
+
+/// 记录来源位置的线段，用于扫描。
+#[derive(Debug, Clone, Copy)]
+struct NodingSegment<T: GeoFloat> {
+    line: Line<T>,
+    line_idx: usize,
+    segment_idx: usize,
+}
+
+impl<T: GeoFloat> NodingSegment<T> {
+    fn is_adjacent_to(&self, other: &Self) -> bool {
+        self.line_idx == other.line_idx && self.segment_idx.abs_diff(other.segment_idx) == 1
+    }
+
+    fn shares_vertex(&self, other: &Self, coord: Coord<T>) -> bool {
+        let (first, second) = if self.segment_idx < other.segment_idx {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        first.line.end == coord && second.line.start == coord
+    }
+}
+
+impl<T: GeoFloat> Cross for NodingSegment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<T> {
+        self.line.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn node_t_junction_and_vertex() {
+        let lines = [
+            wkt!(LINESTRING(0. 0.,4. 0.,4. 4.,6. 4.)),
+            // 端点落在第一条线的内部
+            wkt!(LINESTRING(2. 0.,2. 3.)),
+            // 穿过第一条线的顶点
+            wkt!(LINESTRING(3. 1.,5. -1.)),
+        ];
+        assert_eq!(
+            node(&lines),
+            wkt!(MULTILINESTRING(
+                (0. 0.,2. 0.),
+                (2. 0.,4. 0.),
+                // 没有交点的顶点被保留在边的内部
+                (4. 0.,4. 4.,6. 4.),
+                (2. 0.,2. 3.),
+                (3. 1.,4. 0.),
+                (4. 0.,5. -1.)
+            ))
+        );
+    }
+
+    #[test]
+    fn node_overlap_is_deduplicated() {
+        let lines = [wkt!(LINESTRING(0. 0.,4. 0.)), wkt!(LINESTRING(6. 0.,2. 0.))];
+        assert_eq!(
+            node(&lines),
+            wkt!(MULTILINESTRING(
+                (0. 0.,2. 0.),
+                (2. 0.,4. 0.),
+                (6. 0.,4. 0.)
+            ))
+        );
+    }
+
+    #[test]
+    fn node_self_intersection() {
+        let lines = [wkt!(LINESTRING(0. 0.,2. 2.,2. 0.,0. 2.))];
+        assert_eq!(
+            node(&lines),
+            wkt!(MULTILINESTRING(
+                (0. 0.,1. 1.),
+                (1. 1.,2. 2.,2. 0.,1. 1.),
+                (1. 1.,0. 2.)
+            ))
+        );
+    }
+
+    #[test]
+    fn node_closed_ring_and_degenerate() {
+        let ring = wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.));
+        let noded = node(std::slice::from_ref(&ring));
+        assert_eq!(noded, MultiLineString::new(vec![ring]));
+
+        let lines = [wkt!(LINESTRING(1. 1.,1. 1.)), wkt!(LINESTRING EMPTY)];
+        assert!(node(&lines).0.is_empty());
+    }
+}
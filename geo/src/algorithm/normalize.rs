@@ -0,0 +1,114 @@
+use std::cmp::Ordering;
+
+use crate::orient::{Direction, Orient};
+use crate::utils::{least_index, lex_cmp};
+use crate::{GeoNum, LineString, Polygon};
+
+/// 将几何体转换为规范形式，使表示同一几何体的不同坐标序列在结构上相等。
+///
+/// 对于 [`Polygon`]，规范形式为：
+///
+/// - 外环为逆时针方向，内环为顺时针方向（参见 [`Orient`]）；
+/// - 每个环都从其字典顺序最小的顶点（参见 [`lex_cmp`](crate::lex_cmp)）开始；
+/// - 内环按其坐标的字典顺序排序。
+///
+/// 因此，两个只在环的起点、方向或内环顺序上不同的多边形，规范化后是 `==` 相等的，
+/// 对于整数坐标，其哈希值也相同。
+///
+/// 规范化不会移除重复或共线的顶点。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, Normalize};
+///
+/// let a = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)));
+/// // 同一个正方形，但为顺时针方向并且从另一个顶点开始
+/// let b = wkt!(POLYGON((2. 2.,2. 0.,0. 0.,0. 2.,2. 2.)));
+///
+/// assert_ne!(a, b);
+/// assert_eq!(a.normalize(), b.normalize());
+/// assert_eq!(a.normalize(), a);
+/// ```
+pub trait Normalize {
+    fn normalize(&self) -> Self;
+}
+
+impl<T> Normalize for Polygon<T>
+where
+    T: GeoNum,
+{
+    fn normalize(&self) -> Self {
+        let (exterior, interiors) = self.orient(Direction::Default).into_inner();
+        let mut interiors: Vec<_> = interiors.into_iter().map(rotate_to_least).collect();
+        interiors.sort_by(ring_cmp);
+        Polygon::new(rotate_to_least(exterior), interiors)
+    }
+}
+
+/// 旋转闭合的环，使其从字典顺序最小的顶点开始。
+fn rotate_to_least<T: GeoNum>(mut ring: LineString<T>) -> LineString<T> {
+    if ring.0.len() < 2 {
+        return ring;
+    }
+    // 环是闭合的：去掉重复的终点，旋转后再重新闭合
+    ring.0.pop();
+    let start = least_index(&ring.0);
+    ring.0.rotate_left(start);
+    ring.close();
+    ring
+}
+
+/// 按坐标逐个以字典顺序比较两个环。
+fn ring_cmp<T: GeoNum>(a: &LineString<T>, b: &LineString<T>) -> Ordering {
+    a.0.iter()
+        .zip(&b.0)
+        .map(|(p, q)| lex_cmp(p, q))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.0.len().cmp(&b.0.len()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    fn normalize_polygon_with_holes() {
+        let polygon: Polygon = wkt!(POLYGON(
+            (10. 10.,0. 10.,0. 0.,10. 0.,10. 10.),
+            (6. 6.,8. 6.,8. 8.,6. 8.,6. 6.),
+            (2. 3.,2. 1.,4. 1.,4. 3.,2. 3.)
+        ));
+        let expected: Polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (2. 1.,2. 3.,4. 3.,4. 1.,2. 1.),
+            (6. 6.,6. 8.,8. 8.,8. 6.,6. 6.)
+        ));
+        assert_eq!(polygon.normalize(), expected);
+        assert_eq!(expected.normalize(), expected);
+    }
+
+    #[test]
+    fn normalize_is_hashable() {
+        let a: Polygon<i32> = wkt!(POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,1 2,2 2,2 1,1 1)));
+        let b: Polygon<i32> = wkt!(POLYGON((4 4,4 0,0 0,0 4,4 4),(2 2,2 1,1 1,1 2,2 2)));
+
+        let hash = |p: &Polygon<i32>| {
+            let mut hasher = DefaultHasher::new();
+            p.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_ne!(a, b);
+        assert_eq!(a.normalize(), b.normalize());
+        assert_eq!(hash(&a.normalize()), hash(&b.normalize()));
+    }
+
+    #[test]
+    fn normalize_empty() {
+        let empty: Polygon = wkt!(POLYGON EMPTY);
+        assert_eq!(empty.normalize(), empty);
+    }
+}
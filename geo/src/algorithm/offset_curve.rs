@@ -0,0 +1,129 @@
+use crate::{Coord, CoordFloat, Line, LineString, Vector2DOps};
+
+/// 斜接点到顶点的距离与偏移距离之比的上限，超过时改用斜切连接。
+const MITER_LIMIT: f64 = 5.0;
+
+/// 计算单侧偏移线：与原始线平行、位于其一侧固定距离处的线。
+///
+/// 正的 `distance` 偏移到前进方向的左侧，负的偏移到右侧。
+/// 顶点处使用斜接（miter）连接：相邻两条偏移线段延长至相交。
+/// 对于非常尖锐的转角，斜接点会远离顶点，此时（斜接长度超过 `5 * |distance|`，
+/// 包括原路折返的情况）改用斜切（bevel）连接，即依次连接两条偏移线段的端点。
+///
+/// 这不是完整的缓冲区：结果**不会**移除自相交。当偏移距离大于转角内侧的线段长度时，
+/// 偏移线会在凹侧形成小环。如果需要干净的结果，请自行后处理，例如用 [`node`](crate::node)
+/// 打断后丢弃多余的部分。
+///
+/// 相邻的重复坐标会被忽略。如果线没有两个不同的坐标，返回空的 [`LineString`]。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, OffsetCurve};
+///
+/// let line_string = wkt!(LINESTRING(0. 0.,4. 0.,4. 4.));
+///
+/// // 左侧，即转角的内侧
+/// assert_eq!(
+///     line_string.offset_line(1.),
+///     wkt!(LINESTRING(0. 1.,3. 1.,3. 4.))
+/// );
+/// // 右侧，即转角的外侧
+/// assert_eq!(
+///     line_string.offset_line(-1.),
+///     wkt!(LINESTRING(0. -1.,5. -1.,5. 4.))
+/// );
+/// ```
+pub trait OffsetCurve<T: CoordFloat> {
+    fn offset_line(&self, distance: T) -> LineString<T>;
+}
+
+impl<T: CoordFloat> OffsetCurve<T> for Line<T> {
+    fn offset_line(&self, distance: T) -> LineString<T> {
+        LineString::from(*self).offset_line(distance)
+    }
+}
+
+impl<T: CoordFloat> OffsetCurve<T> for LineString<T> {
+    fn offset_line(&self, distance: T) -> LineString<T> {
+        let mut coords = self.0.clone();
+        coords.dedup();
+
+        // 每条线段左侧的单位法向量
+        let normals: Vec<Coord<T>> = coords
+            .windows(2)
+            .filter_map(|pair| (pair[1] - pair[0]).left().try_normalize())
+            .collect();
+        let (Some(first), Some(last)) = (normals.first(), normals.last()) else {
+            return LineString::new(vec![]);
+        };
+
+        let two = T::one() + T::one();
+        let min_miter_denominator = two / T::from(MITER_LIMIT * MITER_LIMIT).unwrap();
+
+        let mut offset = vec![coords[0] + *first * distance];
+        for (vertex, pair) in coords[1..].iter().zip(normals.windows(2)) {
+            let (incoming, outgoing) = (pair[0], pair[1]);
+            // 斜接向量为 (n0 + n1) / (1 + n0·n1)，其长度的平方为 2 / (1 + n0·n1)
+            let denominator = T::one() + incoming.dot_product(outgoing);
+            if denominator >= min_miter_denominator {
+                offset.push(*vertex + (incoming + outgoing) * (distance / denominator));
+            } else {
+                offset.push(*vertex + incoming * distance);
+                offset.push(*vertex + outgoing * distance);
+            }
+        }
+        offset.push(coords[coords.len() - 1] + *last * distance);
+
+        LineString::new(offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn offset_line_sides() {
+        let line = Line::new((0., 0.), (0., 2.));
+        assert_eq!(line.offset_line(1.), wkt!(LINESTRING(-1. 0.,-1. 2.)));
+        assert_eq!(line.offset_line(-1.), wkt!(LINESTRING(1. 0.,1. 2.)));
+        assert_eq!(line.offset_line(0.), LineString::from(line));
+    }
+
+    #[test]
+    fn offset_line_collinear_and_repeated_vertices() {
+        let line_string = wkt!(LINESTRING(0. 0.,1. 0.,1. 0.,3. 0.));
+        assert_eq!(
+            line_string.offset_line(2.),
+            wkt!(LINESTRING(0. 2.,1. 2.,3. 2.))
+        );
+    }
+
+    #[test]
+    fn offset_line_sharp_turn_is_beveled() {
+        // 原路折返
+        let reversal = wkt!(LINESTRING(0. 0.,2. 0.,0. 0.));
+        assert_eq!(
+            reversal.offset_line(-1.),
+            wkt!(LINESTRING(0. -1.,2. -1.,2. 1.,0. 1.))
+        );
+
+        // 斜接长度超过上限的尖角
+        let spike = wkt!(LINESTRING(0. 0.,10. 0.,0. 1.));
+        let offset = spike.offset_line(-1.);
+        assert_eq!(offset.0.len(), 4);
+        assert_eq!(offset.0[1], Coord { x: 10., y: -1. });
+
+        // 直角的斜接长度为 √2，不超过上限
+        let corner = wkt!(LINESTRING(0. 0.,2. 0.,2. 2.));
+        assert_eq!(corner.offset_line(-1.).0.len(), 3);
+    }
+
+    #[test]
+    fn offset_line_degenerate() {
+        assert!(wkt!(LINESTRING(1. 1.,1. 1.)).offset_line(1.).0.is_empty());
+        assert!(LineString::<f64>::new(vec![]).offset_line(1.).0.is_empty());
+    }
+}
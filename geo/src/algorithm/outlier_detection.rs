@@ -103,6 +103,34 @@ where
     ///```
     fn outliers(&self, k_neighbours: usize) -> Vec<T>;
 
+    /// 与 [`outliers`](Self::outliers) 相同，但对每个输入点额外返回计算过程中的中间值，
+    /// 包括到第 k 个邻居的距离的平方和局部可达性密度，便于调整 `k_neighbours` 并解释被标记的点。
+    ///
+    /// 返回的 [`OutlierRecord`] 与输入点的顺序对应，其中的 `lof` 与 [`outliers`](Self::outliers) 的结果相同。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::OutlierDetection;
+    /// use geo::point;
+    ///
+    /// let v = vec![
+    ///     point!(x: 0.0, y: 0.0),
+    ///     point!(x: 0.0, y: 1.0),
+    ///     point!(x: 3.0, y: 0.0),
+    ///     point!(x: 1.0, y: 1.0),
+    /// ];
+    ///
+    /// let records = v.outliers_detailed(2);
+    /// assert_relative_eq!(records[2].lof, 3.0);
+    /// // 离群点离它最近的邻居很远，局部可达性密度较低
+    /// // 点本身是第一个邻居，第二个邻居是 (1, 1)
+    /// assert_relative_eq!(records[2].k_distance_2, 5.0);
+    /// assert!(records[2].lrd < records[3].lrd);
+    /// ```
+    fn outliers_detailed(&self, k_neighbours: usize) -> Vec<OutlierRecord<T>>;
+
     /// 创建一个准备好的离群检测器，允许多次运行以保留使用中的空间索引。
     /// 一个[`PreparedDetector`]可以高效地重新计算不同 `k_neigbhours` 值的离群点。
     fn prepared_detector(&self) -> PreparedDetector<T>;
@@ -155,6 +183,19 @@ where
     fn ensemble_max(&self, bounds: RangeInclusive<usize>) -> Vec<T>;
 }
 
+/// [`OutlierDetection::outliers_detailed`] 为单个点返回的 LOF 计算细节。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutlierRecord<T: GeoFloat> {
+    /// 局部离群因子，与 [`OutlierDetection::outliers`] 返回的值相同。
+    pub lof: T,
+    /// 到第 k 个最近邻居的欧几里得距离的平方。点本身被计为它的第一个邻居。
+    ///
+    /// 与 `lrd` 和 LOF 的计算使用相同的基准。
+    pub k_distance_2: T,
+    /// 局部可达性密度，基于距离的平方计算。
+    pub lrd: T,
+}
+
 /// 此结构体允许在点集中使用不同的 `k_neighbours` 大小进行多次检测操作，
 /// 而无需重建底层的空间索引。它的 [`PreparedDetector::outliers`] 方法
 /// 与 [`OutlierDetection::outliers`] 方法具有相同的签名，但保留底层的空间索引和点集，
@@ -185,6 +226,14 @@ where
 
     /// 参见 [`OutlierDetection::outliers`] 以了解用法
     pub fn outliers(&self, kneighbours: usize) -> Vec<T> {
+        lof(self.points, &self.tree, kneighbours)
+            .into_iter()
+            .map(|record| record.lof)
+            .collect()
+    }
+
+    /// 参见 [`OutlierDetection::outliers_detailed`] 以了解用法
+    pub fn outliers_detailed(&self, kneighbours: usize) -> Vec<OutlierRecord<T>> {
         lof(self.points, &self.tree, kneighbours)
     }
 }
@@ -193,14 +242,21 @@ fn lof<T>(
     points: &[Point<T>],
     tree: &RTree<GeomWithData<Point<T>, usize>>,
     kneighbours: usize,
-) -> Vec<T>
+) -> Vec<OutlierRecord<T>>
 where
     T: GeoFloat + Sum,
 {
     debug_assert!(kneighbours > 0);
     if points.len() <= kneighbours || kneighbours < 1 {
-        // 在这种情况下没有必要尝试运行算法
-        return points.iter().map(|_| T::one()).collect();
+        // 在这种情况下没有必要尝试运行算法，也没有有意义的中间值
+        return points
+            .iter()
+            .map(|_| OutlierRecord {
+                lof: T::one(),
+                k_distance_2: T::nan(),
+                lrd: T::nan(),
+            })
+            .collect();
     }
     let knn_dists = points
         .iter()
@@ -236,7 +292,8 @@ where
     // 除以 p 的 kNN 集中项数的平方。
     knn_dists
         .iter()
-        .map(|neighbours| {
+        .zip(&local_reachability_densities)
+        .map(|(neighbours, lrd)| {
             // 对于每个点的邻居集，计算第k个距离
             let kth_dist = neighbours
                 .iter()
@@ -253,7 +310,11 @@ where
                 .iter()
                 .map(|(_, distance)| distance.max(*kth_dist))
                 .sum();
-            (lrd_scores * sum_rd) / T::from(neighbours.len().pow(2)).unwrap()
+            OutlierRecord {
+                lof: (lrd_scores * sum_rd) / T::from(neighbours.len().pow(2)).unwrap(),
+                k_distance_2: *kth_dist,
+                lrd: *lrd,
+            }
         })
         .collect()
 }
@@ -267,6 +328,11 @@ where
         pd.outliers(k_neighbours)
     }
 
+    fn outliers_detailed(&self, k_neighbours: usize) -> Vec<OutlierRecord<T>> {
+        let pd = self.prepared_detector();
+        pd.outliers_detailed(k_neighbours)
+    }
+
     fn prepared_detector(&self) -> PreparedDetector<T> {
         PreparedDetector::new(&self.0)
     }
@@ -301,6 +367,11 @@ where
         pd.outliers(k_neighbours)
     }
 
+    fn outliers_detailed(&self, k_neighbours: usize) -> Vec<OutlierRecord<T>> {
+        let pd = self.prepared_detector();
+        pd.outliers_detailed(k_neighbours)
+    }
+
     fn prepared_detector(&self) -> PreparedDetector<T> {
         PreparedDetector::new(self)
     }
@@ -346,6 +417,33 @@ mod tests {
         assert_eq!(lofs[2], 3.3333333333333335);
     }
     #[test]
+    fn test_lof_detailed() {
+        let v = [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(3.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+
+        let records = v.outliers_detailed(3);
+        let lofs = v.outliers(3);
+        assert_eq!(records.len(), v.len());
+        for (record, lof) in records.iter().zip(&lofs) {
+            assert_eq!(record.lof, *lof);
+        }
+        assert_eq!(records[2].lof, 3.3333333333333335);
+        // 离群点的邻居（包括自身）是 (3, 0)、(1, 1) 和 (0, 0)
+        assert_eq!(records[2].k_distance_2, 9.0);
+        assert_eq!(records[2].lrd, 3.0 / 27.0);
+        assert_eq!(records[3].k_distance_2, 2.0);
+
+        // 邻居数不少于点数时没有中间值
+        let records: Vec<OutlierRecord<f64>> = v.outliers_detailed(4);
+        assert!(records.iter().all(|record| record.lof == 1.0
+            && record.k_distance_2.is_nan()
+            && record.lrd.is_nan()));
+    }
+    #[test]
     fn test_lof2() {
         // 第四个点是离群点
         let v = [
@@ -0,0 +1,393 @@
+use std::cmp::Ordering;
+
+use crate::area::twice_signed_ring_area;
+use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, LineString, MultiPolygon, Polygon};
+
+/// 由一组已打断（noded）的线构建多边形，类似于 JTS 的 `Polygonizer`。
+///
+/// 输入的每条线被视为一条边，只在其两个端点处与其他边相接（参见 [`node`](crate::node)）。
+/// 由这些边围成的每个最小面都成为一个多边形；位于某个面内部、不与其相接的边组成该多边形的内环。
+///
+/// 返回 `(多边形, 未成环的边)`：
+///
+/// - 多边形的外环为逆时针方向，内环为顺时针方向；
+/// - 未成环的边包括悬挂边（至少一个端点不与其他边相接）和切割边（两侧属于同一个面，
+///   例如连接两个环的“桥”），按输入顺序返回。
+///
+/// 少于两个不同坐标的线会被忽略。如果输入没有正确打断（例如边在内部交叉），结果是未定义的。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{polygonize, wkt};
+///
+/// let lines = [
+///     // 两个相邻的正方形共享中间的边
+///     wkt!(LINESTRING(0. 0.,1. 0.)),
+///     wkt!(LINESTRING(1. 0.,1. 1.)),
+///     wkt!(LINESTRING(1. 1.,0. 1.,0. 0.)),
+///     wkt!(LINESTRING(1. 0.,2. 0.,2. 1.,1. 1.)),
+///     // 悬挂边
+///     wkt!(LINESTRING(2. 1.,3. 2.)),
+/// ];
+///
+/// let (polygons, dangles) = polygonize(&lines);
+/// assert_eq!(
+///     polygons,
+///     wkt!(MULTIPOLYGON(
+///         ((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)),
+///         ((1. 1.,1. 0.,2. 0.,2. 1.,1. 1.))
+///     ))
+/// );
+/// assert_eq!(dangles, vec![wkt!(LINESTRING(2. 1.,3. 2.))]);
+/// ```
+pub fn polygonize<T: GeoFloat>(lines: &[LineString<T>]) -> (MultiPolygon<T>, Vec<LineString<T>>) {
+    let mut graph = PlanarGraph::new(lines);
+    graph.remove_dangles();
+    loop {
+        let cycles = graph.cycles();
+        let cut_edges: Vec<usize> = graph
+            .edge_indices()
+            .filter(|&edge| cycles[2 * edge] == cycles[2 * edge + 1])
+            .collect();
+        if cut_edges.is_empty() {
+            break;
+        }
+        for edge in cut_edges {
+            graph.remove_edge(edge);
+        }
+        graph.remove_dangles();
+    }
+
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for (ring, component) in graph.rings() {
+        let twice_area = twice_signed_ring_area(&ring);
+        if twice_area > T::zero() {
+            shells.push((ring, component, twice_area, vec![]));
+        } else if twice_area < T::zero() {
+            holes.push((ring, component));
+        }
+    }
+
+    // 每个连通分量的外边界是其所在面的内环
+    for (hole, component) in holes {
+        let containing_shell = shells
+            .iter_mut()
+            .filter(|(shell, shell_component, ..)| {
+                *shell_component != component
+                    && coord_pos_relative_to_ring(hole.0[0], shell) == CoordPos::Inside
+            })
+            .min_by(|(.., a, _), (.., b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        if let Some((.., interiors)) = containing_shell {
+            interiors.push(hole);
+        }
+    }
+
+    let polygons = shells
+        .into_iter()
+        .map(|(exterior, _, _, interiors)| Polygon::new(exterior, interiors))
+        .collect();
+    let unused = lines
+        .iter()
+        .zip(&graph.edges)
+        .filter(|(_, edge)| matches!(edge, Some(edge) if !edge.alive))
+        .map(|(line_string, _)| line_string.clone())
+        .collect();
+    (MultiPolygon::new(polygons), unused)
+}
+
+/// 由输入的线组成的平面图，每条线是一条边，其端点是节点。
+///
+/// 边 `i` 对应两条半边：`2 * i` 沿线的方向，`2 * i + 1` 沿相反方向。
+struct PlanarGraph<'a, T: GeoFloat> {
+    lines: &'a [LineString<T>],
+    /// 与输入一一对应，少于两个不同坐标的线为 `None`
+    edges: Vec<Option<Edge>>,
+    nodes: Vec<Coord<T>>,
+    /// 每个节点上仍然存在的边的端点数量（自环计两次）
+    degrees: Vec<usize>,
+}
+
+struct Edge {
+    start: usize,
+    end: usize,
+    alive: bool,
+}
+
+impl<'a, T: GeoFloat> PlanarGraph<'a, T> {
+    fn new(lines: &'a [LineString<T>]) -> Self {
+        let is_valid = |line_string: &LineString<T>| {
+            line_string
+                .0
+                .first()
+                .is_some_and(|first| line_string.0.iter().any(|coord| coord != first))
+        };
+
+        let mut nodes: Vec<Coord<T>> = lines
+            .iter()
+            .filter(|line_string| is_valid(line_string))
+            .flat_map(|line_string| [line_string.0[0], line_string.0[line_string.0.len() - 1]])
+            .collect();
+        nodes.sort_by(lex_cmp);
+        nodes.dedup();
+
+        let node_index = |coord: &Coord<T>| {
+            nodes
+                .binary_search_by(|node| lex_cmp(node, coord))
+                .expect("端点一定是节点")
+        };
+        let mut degrees = vec![0; nodes.len()];
+        let edges = lines
+            .iter()
+            .map(|line_string| {
+                if !is_valid(line_string) {
+                    return None;
+                }
+                let start = node_index(&line_string.0[0]);
+                let end = node_index(&line_string.0[line_string.0.len() - 1]);
+                degrees[start] += 1;
+                degrees[end] += 1;
+                Some(Edge {
+                    start,
+                    end,
+                    alive: true,
+                })
+            })
+            .collect();
+
+        Self {
+            lines,
+            edges,
+            nodes,
+            degrees,
+        }
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| matches!(edge, Some(edge) if edge.alive))
+            .map(|(index, _)| index)
+    }
+
+    fn edge(&self, index: usize) -> &Edge {
+        self.edges[index].as_ref().expect("边必须有效")
+    }
+
+    fn remove_edge(&mut self, index: usize) {
+        let edge = self.edges[index].as_mut().expect("边必须有效");
+        edge.alive = false;
+        self.degrees[edge.start] -= 1;
+        self.degrees[edge.end] -= 1;
+    }
+
+    /// 反复移除至少有一个端点的度为 1 的边。
+    fn remove_dangles(&mut self) {
+        loop {
+            let dangles: Vec<usize> = self
+                .edge_indices()
+                .filter(|&index| {
+                    let edge = self.edge(index);
+                    self.degrees[edge.start] == 1 || self.degrees[edge.end] == 1
+                })
+                .collect();
+            if dangles.is_empty() {
+                return;
+            }
+            for index in dangles {
+                self.remove_edge(index);
+            }
+        }
+    }
+
+    /// 半边的起点节点。
+    fn origin(&self, half_edge: usize) -> usize {
+        let edge = self.edge(half_edge / 2);
+        if half_edge % 2 == 0 {
+            edge.start
+        } else {
+            edge.end
+        }
+    }
+
+    /// 半边的坐标，按半边的方向排列。
+    fn coords(&self, half_edge: usize) -> Box<dyn Iterator<Item = Coord<T>> + '_> {
+        let coords = self.lines[half_edge / 2].0.iter().copied();
+        if half_edge % 2 == 0 {
+            Box::new(coords)
+        } else {
+            Box::new(coords.rev())
+        }
+    }
+
+    /// 半边离开起点时的方向角。
+    fn angle(&self, half_edge: usize) -> T {
+        let mut coords = self.coords(half_edge);
+        let origin = coords.next().expect("边必须有效");
+        let next = coords
+            .find(|coord| *coord != origin)
+            .expect("边至少有两个不同的坐标");
+        let delta = next - origin;
+        delta.y.atan2(delta.x)
+    }
+
+    /// 对于每条半边，返回沿其左侧的面前进时的下一条半边。
+    fn next_half_edges(&self) -> Vec<Option<usize>> {
+        let mut outgoing: Vec<Vec<(T, usize)>> = vec![vec![]; self.nodes.len()];
+        for edge in self.edge_indices() {
+            for half_edge in [2 * edge, 2 * edge + 1] {
+                outgoing[self.origin(half_edge)].push((self.angle(half_edge), half_edge));
+            }
+        }
+
+        let mut next = vec![None; 2 * self.edges.len()];
+        for half_edges in outgoing.iter_mut() {
+            half_edges.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            for (i, &(_, half_edge)) in half_edges.iter().enumerate() {
+                // 到达此节点的半边是 `half_edge ^ 1`，下一条半边是其反向半边顺时针方向的相邻半边
+                let previous = half_edges[(i + half_edges.len() - 1) % half_edges.len()].1;
+                next[half_edge ^ 1] = Some(previous);
+            }
+        }
+        next
+    }
+
+    /// 为每条半边标记其所在环的编号。
+    fn cycles(&self) -> Vec<Option<usize>> {
+        let next = self.next_half_edges();
+        let mut cycles = vec![None; next.len()];
+        let mut cycle_count = 0;
+        for start in 0..next.len() {
+            if next[start].is_none() || cycles[start].is_some() {
+                continue;
+            }
+            let mut half_edge = start;
+            while cycles[half_edge].is_none() {
+                cycles[half_edge] = Some(cycle_count);
+                half_edge = next[half_edge].expect("存在的半边一定有后继");
+            }
+            cycle_count += 1;
+        }
+        cycles
+    }
+
+    /// 返回所有环及其所在连通分量的编号。
+    fn rings(&self) -> Vec<(LineString<T>, usize)> {
+        let next = self.next_half_edges();
+        let components = self.components();
+        let mut visited = vec![false; next.len()];
+        let mut rings = vec![];
+        for start in 0..next.len() {
+            if next[start].is_none() || visited[start] {
+                continue;
+            }
+            let mut coords: Vec<Coord<T>> = vec![];
+            let mut half_edge = start;
+            while !visited[half_edge] {
+                visited[half_edge] = true;
+                let skip = usize::from(!coords.is_empty());
+                coords.extend(self.coords(half_edge).skip(skip));
+                half_edge = next[half_edge].expect("存在的半边一定有后继");
+            }
+            rings.push((LineString::new(coords), components[self.origin(start)]));
+        }
+        rings
+    }
+
+    /// 为每个节点标记其所在连通分量的编号。
+    fn components(&self) -> Vec<usize> {
+        let mut parents: Vec<usize> = (0..self.nodes.len()).collect();
+        fn find(parents: &mut [usize], mut node: usize) -> usize {
+            while parents[node] != node {
+                parents[node] = parents[parents[node]];
+                node = parents[node];
+            }
+            node
+        }
+        for index in self.edge_indices() {
+            let edge = self.edge(index);
+            let (a, b) = (find(&mut parents, edge.start), find(&mut parents, edge.end));
+            parents[a] = b;
+        }
+        (0..self.nodes.len())
+            .map(|node| find(&mut parents, node))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn polygonize_nested_components() {
+        let lines = [
+            wkt!(LINESTRING(0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)),
+            // 内部独立的环成为外层多边形的洞，同时自身也是一个多边形
+            wkt!(LINESTRING(2. 2.,2. 4.,4. 4.,4. 2.,2. 2.)),
+            // 洞中的悬挂边
+            wkt!(LINESTRING(3. 3.,3.5 3.5)),
+        ];
+        let (polygons, unused) = polygonize(&lines);
+        assert_eq!(
+            polygons,
+            wkt!(MULTIPOLYGON(
+                ((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(2. 2.,2. 4.,4. 4.,4. 2.,2. 2.)),
+                ((2. 2.,4. 2.,4. 4.,2. 4.,2. 2.))
+            ))
+        );
+        assert_eq!(unused, vec![lines[2].clone()]);
+    }
+
+    #[test]
+    fn polygonize_cut_edge() {
+        let lines = [
+            wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)),
+            // 连接两个正方形的桥
+            wkt!(LINESTRING(1. 0.,3. 0.)),
+            wkt!(LINESTRING(3. 0.,4. 0.,4. 1.,3. 1.,3. 0.)),
+        ];
+        let (polygons, unused) = polygonize(&lines);
+        assert_eq!(
+            polygons,
+            wkt!(MULTIPOLYGON(
+                ((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)),
+                ((3. 0.,4. 0.,4. 1.,3. 1.,3. 0.))
+            ))
+        );
+        assert_eq!(unused, vec![lines[1].clone()]);
+    }
+
+    #[test]
+    fn polygonize_matches_area_of_noded_grid() {
+        use crate::{node, Area};
+
+        // 井字形网格，打断后围成 9 个单位正方形
+        let lines: Vec<LineString> = (0..4)
+            .flat_map(|i| {
+                let i = i as f64;
+                [
+                    LineString::from(vec![(i, 0.), (i, 3.)]),
+                    LineString::from(vec![(0., i), (3., i)]),
+                ]
+            })
+            .collect();
+        let (polygons, unused) = polygonize(&node(&lines).0);
+        assert_eq!(polygons.0.len(), 9);
+        assert_eq!(polygons.signed_area(), 9.0);
+        assert!(unused.is_empty());
+    }
+
+    #[test]
+    fn polygonize_degenerate() {
+        let lines = [wkt!(LINESTRING(1. 1.,1. 1.)), wkt!(LINESTRING EMPTY)];
+        let (polygons, unused) = polygonize(&lines);
+        assert!(polygons.0.is_empty());
+        assert!(unused.is_empty());
+    }
+}
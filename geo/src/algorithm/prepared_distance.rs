@@ -0,0 +1,266 @@
+use num_traits::Bounded;
+use rstar::primitives::CachedEnvelope;
+use rstar::{RTree, AABB};
+
+use crate::geometry::*;
+use crate::{Closest, ClosestPoint, Distance, Euclidean, GeoFloat, Intersects};
+
+/// 预先为几何体构建线段 R 树，以便高效地重复查询点到该几何体的欧几里得距离和最近点。
+///
+/// [`Euclidean::distance`](crate::Euclidean) 和 [`ClosestPoint`] 在每次调用时都会重新遍历
+/// （或重新建立索引）整个几何体。当需要从许多点查询到同一个大型 [`LineString`] 或
+/// [`MultiPolygon`] 的距离时，`PreparedDistance` 只构建一次索引，每次查询仅需对数时间。
+///
+/// 对于多边形，位于其内部或边界上的点的距离为 `0`。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{point, wkt, Closest, PreparedDistance};
+///
+/// let line_string = wkt!(LINESTRING(0.0 0.0,10.0 0.0,10.0 10.0));
+/// let prepared = PreparedDistance::from(&line_string);
+///
+/// assert_eq!(prepared.distance_to(&point!(x: 5.0, y: 3.0)), 3.0);
+/// assert_eq!(
+///     prepared.closest_point_to(&point!(x: 12.0, y: 4.0)),
+///     Closest::SinglePoint(point!(x: 10.0, y: 4.0))
+/// );
+///
+/// let polygon = wkt!(POLYGON((0.0 0.0,4.0 0.0,4.0 4.0,0.0 4.0,0.0 0.0)));
+/// let prepared = PreparedDistance::from(&polygon);
+/// assert_eq!(prepared.distance_to(&point!(x: 1.0, y: 1.0)), 0.0);
+/// assert_eq!(prepared.distance_to(&point!(x: 7.0, y: 0.0)), 3.0);
+/// ```
+pub struct PreparedDistance<T: GeoFloat = f64> {
+    segments: RTree<CachedEnvelope<Line<T>>>,
+    polygons: RTree<CachedEnvelope<Polygon<T>>>,
+}
+
+impl<T: GeoFloat> PreparedDistance<T> {
+    /// 返回点到几何体的欧几里得距离。
+    ///
+    /// 如果几何体为空，返回 `T::max_value()`。
+    pub fn distance_to(&self, p: &Point<T>) -> T {
+        if self.is_inside_polygon(p) {
+            return T::zero();
+        }
+        self.segments
+            .nearest_neighbor(p)
+            .map_or_else(Bounded::max_value, |segment| {
+                Euclidean::distance(segment as &Line<T>, p)
+            })
+    }
+
+    /// 返回几何体上距离点最近的点。
+    ///
+    /// 如果点与几何体相交（包括位于多边形内部），返回 [`Closest::Intersection`]；
+    /// 如果几何体为空，返回 [`Closest::Indeterminate`]。
+    pub fn closest_point_to(&self, p: &Point<T>) -> Closest<T> {
+        if self.is_inside_polygon(p) {
+            return Closest::Intersection(*p);
+        }
+        match self.segments.nearest_neighbor(p) {
+            Some(segment) => segment.closest_point(p),
+            None => Closest::Indeterminate,
+        }
+    }
+
+    fn is_inside_polygon(&self, p: &Point<T>) -> bool {
+        self.polygons
+            .locate_in_envelope_intersecting(&AABB::from_point(*p))
+            .any(|polygon| polygon.intersects(p))
+    }
+
+    fn new(segments: Vec<Line<T>>, polygons: Vec<Polygon<T>>) -> Self {
+        Self {
+            segments: RTree::bulk_load(segments.into_iter().map(CachedEnvelope::new).collect()),
+            polygons: RTree::bulk_load(polygons.into_iter().map(CachedEnvelope::new).collect()),
+        }
+    }
+}
+
+/// 将点表示为退化的线段，以便统一查询。
+fn point_segments<T: GeoFloat>(point: &Point<T>) -> impl Iterator<Item = Line<T>> {
+    std::iter::once(Line::new(point.0, point.0))
+}
+
+fn line_string_segments<T: GeoFloat>(
+    line_string: &LineString<T>,
+) -> Box<dyn Iterator<Item = Line<T>> + '_> {
+    match line_string.0.as_slice() {
+        // 单个坐标的线串没有线段，但仍然是一个可以测量距离的位置
+        [coord] => Box::new(std::iter::once(Line::new(*coord, *coord))),
+        _ => Box::new(line_string.lines()),
+    }
+}
+
+fn polygon_segments<T: GeoFloat>(polygon: &Polygon<T>) -> impl Iterator<Item = Line<T>> + '_ {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(line_string_segments)
+}
+
+/// 收集几何体的所有线段和多边形。
+fn collect_geometry<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    segments: &mut Vec<Line<T>>,
+    polygons: &mut Vec<Polygon<T>>,
+) {
+    match geometry {
+        Geometry::Point(point) => segments.extend(point_segments(point)),
+        Geometry::Line(line) => segments.push(*line),
+        Geometry::LineString(line_string) => segments.extend(line_string_segments(line_string)),
+        Geometry::Polygon(polygon) => {
+            segments.extend(polygon_segments(polygon));
+            polygons.push(polygon.clone());
+        }
+        Geometry::MultiPoint(multi_point) => {
+            segments.extend(multi_point.iter().flat_map(point_segments))
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            segments.extend(multi_line_string.iter().flat_map(line_string_segments))
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            segments.extend(multi_polygon.iter().flat_map(polygon_segments));
+            polygons.extend(multi_polygon.iter().cloned());
+        }
+        Geometry::GeometryCollection(collection) => collection
+            .iter()
+            .for_each(|geometry| collect_geometry(geometry, segments, polygons)),
+        Geometry::Rect(rect) => {
+            let polygon = rect.to_polygon();
+            segments.extend(polygon_segments(&polygon));
+            polygons.push(polygon);
+        }
+        Geometry::Triangle(triangle) => {
+            let polygon = triangle.to_polygon();
+            segments.extend(polygon_segments(&polygon));
+            polygons.push(polygon);
+        }
+    }
+}
+
+impl<T: GeoFloat> From<&LineString<T>> for PreparedDistance<T> {
+    fn from(line_string: &LineString<T>) -> Self {
+        Self::new(line_string_segments(line_string).collect(), vec![])
+    }
+}
+
+impl<T: GeoFloat> From<&MultiLineString<T>> for PreparedDistance<T> {
+    fn from(multi_line_string: &MultiLineString<T>) -> Self {
+        Self::new(
+            multi_line_string
+                .iter()
+                .flat_map(line_string_segments)
+                .collect(),
+            vec![],
+        )
+    }
+}
+
+impl<T: GeoFloat> From<&Polygon<T>> for PreparedDistance<T> {
+    fn from(polygon: &Polygon<T>) -> Self {
+        Self::new(polygon_segments(polygon).collect(), vec![polygon.clone()])
+    }
+}
+
+impl<T: GeoFloat> From<&MultiPolygon<T>> for PreparedDistance<T> {
+    fn from(multi_polygon: &MultiPolygon<T>) -> Self {
+        Self::new(
+            multi_polygon.iter().flat_map(polygon_segments).collect(),
+            multi_polygon.0.clone(),
+        )
+    }
+}
+
+impl<T: GeoFloat> From<&Geometry<T>> for PreparedDistance<T> {
+    fn from(geometry: &Geometry<T>) -> Self {
+        let mut segments = vec![];
+        let mut polygons = vec![];
+        collect_geometry(geometry, &mut segments, &mut polygons);
+        Self::new(segments, polygons)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt};
+
+    #[test]
+    fn matches_unprepared_line_string() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let prepared = PreparedDistance::from(&line_string);
+
+        for point in [
+            point!(x: -91.0, y: 30.5),
+            point!(x: -89.0, y: 29.0),
+            point!(x: -100.0, y: 40.0),
+        ] {
+            assert_relative_eq!(
+                prepared.distance_to(&point),
+                Euclidean::distance(&point, &line_string)
+            );
+            assert_eq!(
+                prepared.closest_point_to(&point),
+                line_string.closest_point(&point)
+            );
+        }
+    }
+
+    #[test]
+    fn multi_polygon_with_hole() {
+        let multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)),
+            ((20. 0.,30. 0.,30. 10.,20. 10.,20. 0.))
+        ));
+        let prepared = PreparedDistance::from(&multi_polygon);
+
+        let inside = point!(x: 2.0, y: 2.0);
+        assert_eq!(prepared.distance_to(&inside), 0.0);
+        assert_eq!(
+            prepared.closest_point_to(&inside),
+            Closest::Intersection(inside)
+        );
+
+        // 洞中的点不在多边形内部
+        let in_hole = point!(x: 5.0, y: 4.5);
+        assert_relative_eq!(prepared.distance_to(&in_hole), 0.5);
+        assert_eq!(
+            prepared.closest_point_to(&in_hole),
+            Closest::SinglePoint(point!(x: 5.0, y: 4.0))
+        );
+
+        let between = point!(x: 14.0, y: 5.0);
+        assert_relative_eq!(prepared.distance_to(&between), 4.0);
+        assert_relative_eq!(
+            prepared.distance_to(&between),
+            Euclidean::distance(&between, &multi_polygon)
+        );
+    }
+
+    #[test]
+    fn geometry_collection() {
+        let geometry = Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(
+            POINT(0. 10.),
+            LINESTRING(5. 0.,5. 1.),
+            POLYGON((20. 0.,22. 0.,22. 2.,20. 0.))
+        )));
+        let prepared = PreparedDistance::from(&geometry);
+        assert_relative_eq!(prepared.distance_to(&point!(x: 0.0, y: 8.0)), 2.0);
+        assert_relative_eq!(prepared.distance_to(&point!(x: 7.0, y: 0.5)), 2.0);
+        assert_eq!(prepared.distance_to(&point!(x: 21.5, y: 1.0)), 0.0);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: LineString = wkt!(LINESTRING EMPTY);
+        let prepared = PreparedDistance::from(&empty);
+        assert_eq!(prepared.distance_to(&point!(x: 0.0, y: 0.0)), f64::MAX);
+        assert_eq!(
+            prepared.closest_point_to(&point!(x: 0.0, y: 0.0)),
+            Closest::Indeterminate
+        );
+    }
+}
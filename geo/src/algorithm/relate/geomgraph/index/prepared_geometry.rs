@@ -1,6 +1,7 @@
 use super::Segment;
 use crate::geometry::*;
 use crate::relate::geomgraph::{GeometryGraph, RobustLineIntersector};
+use crate::relate::IntersectionMatrix;
 use crate::GeometryCow;
 use crate::{GeoFloat, Relate};
 
@@ -172,6 +173,37 @@ where
     pub(crate) fn geometry(&self) -> &GeometryCow<F> {
         self.geometry_graph.geometry()
     }
+
+    /// 将此几何体依次与 `others` 中的每个几何体进行拓扑关联，按顺序返回各自的 [`IntersectionMatrix`]。
+    ///
+    /// 结果与对每个几何体分别调用 [`Relate::relate`] 相同。每次比较都会共享准备阶段构建的线段 R 树，
+    /// 并跳过此几何体的自节点计算，只复制其拓扑图；`others` 中的几何体仍按各自的 [`Relate`] 实现构建图。
+    ///
+    /// ```
+    /// use geo::{PreparedGeometry, wkt};
+    ///
+    /// let polygon = wkt! { POLYGON((0.0 0.0,4.0 0.0,4.0 4.0,0.0 4.0,0.0 0.0)) };
+    /// let points = [
+    ///     wkt! { POINT(2.0 2.0) },
+    ///     wkt! { POINT(4.0 2.0) },
+    ///     wkt! { POINT(5.0 2.0) },
+    /// ];
+    ///
+    /// let prepared_polygon = PreparedGeometry::from(&polygon);
+    /// let matrices = prepared_polygon.relate_all(&points);
+    /// assert!(matrices[0].is_contains());
+    /// assert!(matrices[1].is_touches());
+    /// assert!(matrices[2].is_disjoint());
+    /// ```
+    pub fn relate_all<'b, G>(
+        &self,
+        others: impl IntoIterator<Item = &'b G>,
+    ) -> Vec<IntersectionMatrix>
+    where
+        G: Relate<F> + 'b,
+    {
+        others.into_iter().map(|other| self.relate(other)).collect()
+    }
 }
 
 impl<F: GeoFloat> Relate<F> for PreparedGeometry<'_, F> {
@@ -206,6 +238,42 @@ mod tests {
         assert!(p2.relate(&prepared_1).is_within());
     }
 
+    #[test]
+    fn relate_all_reuses_prepared_graph() {
+        let poly = polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 4.0)];
+        let prepared_geom = PreparedGeometry::from(&poly);
+        let others: Vec<Polygon> = (0..20)
+            .map(|i| {
+                let offset = i as f64 * 0.5;
+                polygon![
+                    (x: offset, y: 1.0),
+                    (x: offset + 1.0, y: 1.0),
+                    (x: offset + 1.0, y: 2.0),
+                    (x: offset, y: 2.0),
+                ]
+            })
+            .collect();
+
+        let matrices = prepared_geom.relate_all(&others);
+        assert_eq!(matrices.len(), others.len());
+        for (matrix, other) in matrices.iter().zip(&others) {
+            assert_eq!(matrix, &poly.relate(other));
+        }
+
+        // 每次查询得到的图都共享准备阶段构建的 R 树，而不是重新构建
+        let prepared_tree = prepared_geom.geometry_graph.get_or_build_tree();
+        for arg_index in [0, 1] {
+            let graph = prepared_geom.geometry_graph(arg_index);
+            assert!(Rc::ptr_eq(&graph.get_or_build_tree(), &prepared_tree));
+        }
+        // 未准备的几何体每次都会构建新的树
+        let fresh_graph = poly.geometry_graph(0);
+        assert!(!Rc::ptr_eq(
+            &fresh_graph.get_or_build_tree(),
+            &fresh_graph.get_or_build_tree()
+        ));
+    }
+
     #[test]
     fn swap_arg_index() {
         let poly = polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 1.0, y: 1.0)];
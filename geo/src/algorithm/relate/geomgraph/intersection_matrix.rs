@@ -60,6 +60,10 @@ impl<T> std::ops::IndexMut<CoordPos> for LocationArray<T> {
     }
 }
 
+/// 解析或匹配 DE-9IM 字符串失败时返回的错误。
+///
+/// 由 [`IntersectionMatrix::matches`] 和 [`IntersectionMatrix`] 的 [`FromStr`] 实现返回，
+/// 例如字符串不是正好9个字符，或包含无效的字符。
 #[derive(Debug)]
 pub struct InvalidInputError {
     message: String,
@@ -539,6 +543,33 @@ impl IntersectionMatrix {
         }
     }
 
+    /// 返回两个几何体交集的维度，即内部和边界之间四个交集维度的最大值。
+    /// 如果两个几何体不相交，返回 `None`。
+    ///
+    /// 这可以区分“接触”和“重叠”：例如两条线在一点相交时为 [`Dimensions::ZeroDimensional`]，
+    /// 沿一段重叠时为 [`Dimensions::OneDimensional`]。
+    ///
+    /// ```
+    /// use geo::{wkt, dimensions::Dimensions, relate::Relate};
+    ///
+    /// let a = wkt!(LINESTRING(0. 0.,4. 0.));
+    /// let crossing = wkt!(LINESTRING(2. -1.,2. 1.));
+    /// let overlapping = wkt!(LINESTRING(2. 0.,6. 0.));
+    /// let disjoint = wkt!(LINESTRING(0. 1.,4. 1.));
+    ///
+    /// assert_eq!(a.relate(&crossing).intersection_dimension(), Some(Dimensions::ZeroDimensional));
+    /// assert_eq!(a.relate(&overlapping).intersection_dimension(), Some(Dimensions::OneDimensional));
+    /// assert_eq!(a.relate(&disjoint).intersection_dimension(), None);
+    /// ```
+    pub fn intersection_dimension(&self) -> Option<Dimensions> {
+        let dimension = [CoordPos::Inside, CoordPos::OnBoundary]
+            .into_iter()
+            .flat_map(|lhs| [CoordPos::Inside, CoordPos::OnBoundary].map(|rhs| self.0[lhs][rhs]))
+            .max()
+            .expect("四个元素");
+        (dimension != Dimensions::Empty).then_some(dimension)
+    }
+
     /// 直接访问这个矩阵
     ///
     /// ```
@@ -592,6 +623,12 @@ impl IntersectionMatrix {
     /// - t 或 T: 匹配任何非空的
     /// - *: 匹配任何
     ///
+    /// 这与 JTS 的 `IntersectionMatrix.matches` 相同，可以用来实现没有对应 `is_*` 方法的任意空间谓词。
+    ///
+    /// # 错误
+    ///
+    /// 如果 `spec` 不是正好9个字符，或包含上述之外的字符，则返回 [`InvalidInputError`]。
+    ///
     /// ```
     /// use geo::algorithm::Relate;
     /// use geo::geometry::Polygon;
@@ -717,6 +754,40 @@ mod tests {
         assert!(relate_ab.is_crosses());
     }
 
+    #[test]
+    fn test_intersection_dimension() {
+        let square = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)));
+        // 共享一条边
+        let adjacent = wkt!(POLYGON((2. 0.,4. 0.,4. 2.,2. 2.,2. 0.)));
+        // 只共享一个角
+        let corner = wkt!(POLYGON((2. 2.,4. 2.,4. 4.,2. 4.,2. 2.)));
+        let overlapping = wkt!(POLYGON((1. 1.,3. 1.,3. 3.,1. 3.,1. 1.)));
+
+        assert_eq!(
+            square.relate(&adjacent).intersection_dimension(),
+            Some(Dimensions::OneDimensional)
+        );
+        assert_eq!(
+            square.relate(&corner).intersection_dimension(),
+            Some(Dimensions::ZeroDimensional)
+        );
+        assert_eq!(
+            square.relate(&overlapping).intersection_dimension(),
+            Some(Dimensions::TwoDimensional)
+        );
+        assert_eq!(
+            square.relate(&wkt!(POINT(5. 5.))).intersection_dimension(),
+            None
+        );
+        // 线的端点落在另一条线的内部
+        assert_eq!(
+            wkt!(LINESTRING(0. 0.,2. 0.))
+                .relate(&wkt!(LINESTRING(1. 0.,1. 1.)))
+                .intersection_dimension(),
+            Some(Dimensions::ZeroDimensional)
+        );
+    }
+
     mod test_matches {
         use super::*;
 
@@ -744,6 +815,29 @@ mod tests {
         fn matches_wildcard() {
             assert!(subject().matches("F0011122*").unwrap());
         }
+
+        #[test]
+        fn matches_every_symbol() {
+            // 每个位置分别对应 F、0、1、2
+            assert!(subject().matches("F00111222").unwrap());
+            assert!(subject().matches("f00111222").unwrap());
+            assert!(subject().matches("*TTtTTtTT").unwrap());
+            assert!(subject().matches("*********").unwrap());
+            // T 不匹配空维度，F 只匹配空维度
+            assert!(!subject().matches("T********").unwrap());
+            assert!(!subject().matches("*F*******").unwrap());
+            assert!(!subject().matches("*1*******").unwrap());
+            assert!(!subject().matches("***2*****").unwrap());
+        }
+
+        #[test]
+        fn rejects_invalid_specs() {
+            assert!(subject().matches("F0011122").is_err());
+            assert!(subject().matches("F00111222*").is_err());
+            assert!(subject().matches("").is_err());
+            assert!(subject().matches("F0011122X").is_err());
+            assert!(subject().matches("F0011122 ").is_err());
+        }
     }
 
     #[test]
@@ -1,7 +1,8 @@
 pub(crate) use edge_end_builder::EdgeEndBuilder;
-pub use geomgraph::intersection_matrix::IntersectionMatrix;
+pub use geomgraph::intersection_matrix::{IntersectionMatrix, InvalidInputError};
 use relate_operation::RelateOperation;
 
+use crate::dimensions::Dimensions;
 use crate::geometry::*;
 pub use crate::relate::geomgraph::index::PreparedGeometry;
 pub use crate::relate::geomgraph::GeometryGraph;
@@ -62,6 +63,13 @@ pub trait Relate<F: GeoFloat> {
         RelateOperation::new(self.geometry_graph(0), other.geometry_graph(1))
             .compute_intersection_matrix()
     }
+
+    /// 返回两个几何体交集的维度，如果不相交则返回 `None`。
+    ///
+    /// 参见 [`IntersectionMatrix::intersection_dimension`]。
+    fn intersection_dimension(&self, other: &impl Relate<F>) -> Option<Dimensions> {
+        self.relate(other).intersection_dimension()
+    }
 }
 
 macro_rules! relate_impl {
@@ -1,6 +1,6 @@
 use crate::{
-    CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon, Rect, Triangle,
+    Coord, CoordFloat, CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 use geo_types::GeometryCollection;
 
@@ -192,9 +192,159 @@ impl<T: CoordNum> RemoveRepeatedPoints<T> for Geometry<T> {
     }
 }
 
+/// 与 [`RemoveRepeatedPoints`] 类似，但将欧几里得距离小于 `tolerance` 的点视为重复点，
+/// 用于清除浮点噪声产生的近似重复点。
+///
+/// - 对于 `LineString`，移除与上一个保留的坐标距离小于 `tolerance` 的连续坐标。
+///   始终保留首尾坐标，因此闭合的 `LineString` 仍然是闭合的；只剩首尾坐标时，
+///   它们之间的距离可能小于 `tolerance`。
+/// - 对于 `Polygon`，每个环都按照上述方式处理。如果某个环因此少于 4 个坐标（不再是有效的环），
+///   则保留该环的原始坐标。
+/// - 对于 `MultiPoint`，移除与之前保留的任何点距离小于 `tolerance` 的点。
+/// - `MultiLineString` 和 `MultiPolygon` 对其每个元素分别处理。
+///
+/// 距离恰好等于 `tolerance` 的点不被移除，因此 `tolerance` 为零时不会移除任何点；
+/// 要移除完全相同的点，请使用 [`RemoveRepeatedPoints`]。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, RemoveRepeatedPointsWithin};
+///
+/// let line_string = wkt!(LINESTRING(0. 0.,1. 0.,1.0000001 0.,2. 0.,2. 0.0000001));
+/// assert_eq!(
+///     line_string.remove_repeated_points_within(1e-6),
+///     wkt!(LINESTRING(0. 0.,1. 0.,2. 0.0000001))
+/// );
+/// ```
+pub trait RemoveRepeatedPointsWithin<T: CoordFloat> {
+    /// 创建一个去除距离小于 `tolerance` 的（连续）点的新几何对象。
+    fn remove_repeated_points_within(&self, tolerance: T) -> Self;
+    /// 就地移除距离小于 `tolerance` 的（连续）点。
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T);
+}
+
+fn is_within<T: CoordFloat>(a: Coord<T>, b: Coord<T>, tolerance: T) -> bool {
+    let delta = a - b;
+    delta.x.hypot(delta.y) < tolerance
+}
+
+/// 移除距离小于 `tolerance` 的连续坐标，保留首尾坐标。
+fn collapse_within<T: CoordFloat>(coords: &[Coord<T>], tolerance: T) -> Vec<Coord<T>> {
+    let mut kept: Vec<Coord<T>> = Vec::with_capacity(coords.len());
+    for &coord in coords {
+        match kept.last() {
+            Some(&last) if is_within(last, coord, tolerance) => {}
+            _ => kept.push(coord),
+        }
+    }
+    // 如果最后一个坐标被移除，用它替换最后保留的坐标（第一个坐标除外）
+    if let (Some(&last), Some(&last_kept)) = (coords.last(), kept.last()) {
+        if last != last_kept {
+            if kept.len() > 1 {
+                *kept.last_mut().unwrap() = last;
+                // 替换后的终点可能离之前保留的坐标过近，同样移除这些坐标（第一个坐标除外）
+                while kept.len() > 2 && is_within(kept[kept.len() - 2], last, tolerance) {
+                    kept.remove(kept.len() - 2);
+                }
+            } else {
+                kept.push(last);
+            }
+        }
+    }
+    kept
+}
+
+/// 与 [`collapse_within`] 相同，但环少于 4 个坐标时保留原始坐标。
+fn collapse_ring_within<T: CoordFloat>(ring: &mut LineString<T>, tolerance: T) {
+    let coords = collapse_within(&ring.0, tolerance);
+    if coords.len() >= 4 || coords.len() == ring.0.len() {
+        ring.0 = coords;
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for MultiPoint<T> {
+    fn remove_repeated_points_within(&self, tolerance: T) -> Self {
+        let mut points = self.clone();
+        points.remove_repeated_points_within_mut(tolerance);
+        points
+    }
+
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        let mut points: Vec<Point<T>> = vec![];
+        for p in self.0.iter() {
+            if !points.iter().any(|q| is_within(q.0, p.0, tolerance)) {
+                points.push(*p);
+            }
+        }
+        self.0 = points;
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for LineString<T> {
+    fn remove_repeated_points_within(&self, tolerance: T) -> Self {
+        LineString(collapse_within(&self.0, tolerance))
+    }
+
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        self.0 = collapse_within(&self.0, tolerance);
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for Polygon<T> {
+    fn remove_repeated_points_within(&self, tolerance: T) -> Self {
+        let mut polygon = self.clone();
+        polygon.remove_repeated_points_within_mut(tolerance);
+        polygon
+    }
+
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        self.exterior_mut(|exterior| collapse_ring_within(exterior, tolerance));
+        self.interiors_mut(|interiors| {
+            for interior in interiors {
+                collapse_ring_within(interior, tolerance);
+            }
+        });
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for MultiLineString<T> {
+    fn remove_repeated_points_within(&self, tolerance: T) -> Self {
+        MultiLineString::new(
+            self.0
+                .iter()
+                .map(|ls| ls.remove_repeated_points_within(tolerance))
+                .collect(),
+        )
+    }
+
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        for ls in self.0.iter_mut() {
+            ls.remove_repeated_points_within_mut(tolerance);
+        }
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for MultiPolygon<T> {
+    fn remove_repeated_points_within(&self, tolerance: T) -> Self {
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|p| p.remove_repeated_points_within(tolerance))
+                .collect(),
+        )
+    }
+
+    fn remove_repeated_points_within_mut(&mut self, tolerance: T) {
+        for p in self.0.iter_mut() {
+            p.remove_repeated_points_within_mut(tolerance);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::RemoveRepeatedPoints;
+    use crate::{wkt, RemoveRepeatedPoints, RemoveRepeatedPointsWithin};
     use crate::{
         Coord, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
         Polygon,
@@ -468,4 +618,83 @@ mod test {
 
         assert_eq!(gc, expected);
     }
+
+    #[test]
+    fn test_remove_repeated_points_within_linestring() {
+        // 尾部的近似重复点替换最后保留的坐标，以保留终点
+        let ls = wkt!(LINESTRING(0. 0.,0.05 0.,1. 0.,1. 0.05,1. 0.08));
+        assert_eq!(
+            ls.remove_repeated_points_within(0.1),
+            wkt!(LINESTRING(0. 0.,1. 0.08))
+        );
+        // 替换后的终点离之前保留的坐标过近时，该坐标也被移除
+        let doubling_back = wkt!(LINESTRING(0. 0.,1. 0.,1.1 0.,1.05 0.));
+        assert_eq!(
+            doubling_back.remove_repeated_points_within(0.1),
+            wkt!(LINESTRING(0. 0.,1.05 0.))
+        );
+        // 第一个坐标永远不会被移除
+        let doubling_back = wkt!(LINESTRING(0. 0.,0.1 0.,0.05 0.));
+        assert_eq!(
+            doubling_back.remove_repeated_points_within(0.1),
+            wkt!(LINESTRING(0. 0.,0.05 0.))
+        );
+        // 距离等于容差的点被保留
+        let evenly_spaced = wkt!(LINESTRING(0. 0.,0.5 0.,1. 0.));
+        assert_eq!(
+            evenly_spaced.remove_repeated_points_within(0.5),
+            evenly_spaced
+        );
+
+        // 所有点都在容差内时，仍保留两个端点
+        let short = wkt!(LINESTRING(0. 0.,0.01 0.,0.02 0.));
+        assert_eq!(
+            short.remove_repeated_points_within(1.),
+            wkt!(LINESTRING(0. 0.,0.02 0.))
+        );
+
+        let mut closed = wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0.00001 0.,0. 0.));
+        closed.remove_repeated_points_within_mut(0.001);
+        assert_eq!(closed, wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.)));
+        assert!(closed.is_closed());
+    }
+
+    #[test]
+    fn test_remove_repeated_points_within_polygon() {
+        let poly = wkt!(POLYGON(
+            (0. 0.,10. 0.,10.0001 0.,10. 10.,0. 10.,0. 0.0001,0. 0.),
+            (4. 4.,4.0001 4.,4. 4.0001,4. 4.)
+        ));
+        let expected = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            // 该环会收缩为少于 4 个坐标，因此保持不变
+            (4. 4.,4.0001 4.,4. 4.0001,4. 4.)
+        ));
+        assert_eq!(poly.remove_repeated_points_within(0.01), expected);
+
+        let mut mpoly = MultiPolygon(vec![poly.clone(), poly]);
+        mpoly.remove_repeated_points_within_mut(0.01);
+        assert_eq!(mpoly, MultiPolygon(vec![expected.clone(), expected]));
+    }
+
+    #[test]
+    fn test_remove_repeated_points_within_multi() {
+        let mp = wkt!(MULTIPOINT(0. 0.,5. 5.,0.001 0.,5. 5.001,1. 1.));
+        assert_eq!(
+            mp.remove_repeated_points_within(0.01),
+            wkt!(MULTIPOINT(0. 0.,5. 5.,1. 1.))
+        );
+
+        let mls = MultiLineString(vec![
+            wkt!(LINESTRING(0. 0.,0. 0.001,1. 1.)),
+            wkt!(LINESTRING(2. 2.,3. 3.)),
+        ]);
+        assert_eq!(
+            mls.remove_repeated_points_within(0.01),
+            MultiLineString(vec![
+                wkt!(LINESTRING(0. 0.,1. 1.)),
+                wkt!(LINESTRING(2. 2.,3. 3.)),
+            ])
+        );
+    }
 }
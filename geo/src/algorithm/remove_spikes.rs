@@ -0,0 +1,199 @@
+use crate::{angle_at_vertex, Coord, GeoFloat, LineString, MultiPolygon, Polygon};
+
+/// 移除尖刺：几何体原路折返形成的、几乎没有面积的突起。
+///
+/// 如果某个顶点处的夹角（由 [`angle_at_vertex`] 计算，取两侧中较小的一个）小于 `min_angle`（以**弧度**为单位），
+/// 则移除该顶点。移除后相邻的顶点可能形成新的尖刺，也会被继续移除，直到不存在尖刺为止。
+/// 连续重复的坐标会合并为一个。
+///
+/// 与共线点的移除不同，尖刺的顶点并不共线，而是几乎反向；其两侧线段的夹角接近 0。
+///
+/// - 对于 [`LineString`]，起点和终点始终保留。如果线串是闭合的，则按环处理。
+/// - 对于环（[`Polygon`] 的外环和内环），首尾坐标也会被检查，结果保持闭合，
+///   并且至少保留三个不同的坐标，因此不会产生坐标不足的环。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, RemoveSpikes};
+///
+/// // 在上边有一个几乎原路折返的尖刺
+/// let polygon = wkt!(POLYGON((0. 0.,10. 0.,10. 10.,5.2 10.,5.1 20.,5. 10.,0. 10.,0. 0.)));
+///
+/// assert_eq!(
+///     polygon.remove_spikes(2f64.to_radians()),
+///     wkt!(POLYGON((0. 0.,10. 0.,10. 10.,5.2 10.,5. 10.,0. 10.,0. 0.)))
+/// );
+/// ```
+pub trait RemoveSpikes<T: GeoFloat> {
+    fn remove_spikes(&self, min_angle: T) -> Self;
+}
+
+impl<T: GeoFloat> RemoveSpikes<T> for LineString<T> {
+    fn remove_spikes(&self, min_angle: T) -> Self {
+        if self.is_closed() {
+            return remove_ring_spikes(self, min_angle);
+        }
+        let mut coords: Vec<Coord<T>> = Vec::with_capacity(self.0.len());
+        for &coord in &self.0 {
+            push_removing_spikes(&mut coords, coord, min_angle, 2);
+        }
+        LineString::new(coords)
+    }
+}
+
+impl<T: GeoFloat> RemoveSpikes<T> for Polygon<T> {
+    fn remove_spikes(&self, min_angle: T) -> Self {
+        Polygon::new(
+            remove_ring_spikes(self.exterior(), min_angle),
+            self.interiors()
+                .iter()
+                .map(|ring| remove_ring_spikes(ring, min_angle))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> RemoveSpikes<T> for MultiPolygon<T> {
+    fn remove_spikes(&self, min_angle: T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.remove_spikes(min_angle))
+                .collect(),
+        )
+    }
+}
+
+fn is_spike<T: GeoFloat>(prev: Coord<T>, vertex: Coord<T>, next: Coord<T>, min_angle: T) -> bool {
+    let angle = angle_at_vertex(prev, vertex, next);
+    let tau = T::from(std::f64::consts::TAU).unwrap();
+    angle.min(tau - angle) < min_angle
+}
+
+/// 将 `coord` 加入 `coords` 的末尾，然后从末尾开始向前移除尖刺，直到只剩 `min_len` 个坐标
+fn push_removing_spikes<T: GeoFloat>(
+    coords: &mut Vec<Coord<T>>,
+    coord: Coord<T>,
+    min_angle: T,
+    min_len: usize,
+) {
+    if coords.last() == Some(&coord) {
+        return;
+    }
+    coords.push(coord);
+    while coords.len() >= 3 && coords.len() > min_len {
+        let n = coords.len();
+        if !is_spike(coords[n - 3], coords[n - 2], coords[n - 1], min_angle) {
+            break;
+        }
+        coords.remove(n - 2);
+        // 移除中间的顶点后，两侧的坐标可能重合
+        if coords[n - 3] == coords[n - 2] {
+            coords.pop();
+        }
+    }
+}
+
+fn remove_ring_spikes<T: GeoFloat>(ring: &LineString<T>, min_angle: T) -> LineString<T> {
+    if !ring.is_closed() || ring.0.len() < 4 {
+        return ring.clone();
+    }
+
+    let mut coords: Vec<Coord<T>> = Vec::with_capacity(ring.0.len());
+    // 不含闭合坐标
+    for &coord in &ring.0[..ring.0.len() - 1] {
+        push_removing_spikes(&mut coords, coord, min_angle, 3);
+    }
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+
+    // 检查首尾相接处的顶点
+    while coords.len() > 3 {
+        let n = coords.len();
+        if is_spike(coords[n - 2], coords[n - 1], coords[0], min_angle) {
+            coords.pop();
+        } else if is_spike(coords[n - 1], coords[0], coords[1], min_angle) {
+            coords.remove(0);
+        } else {
+            break;
+        }
+        let n = coords.len();
+        if coords[n - 1] == coords[0] {
+            coords.pop();
+        }
+    }
+
+    if coords.len() < 3 {
+        return ring.clone();
+    }
+    let mut result = LineString::new(coords);
+    result.close();
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Validation};
+
+    #[test]
+    fn line_string_keeps_endpoints() {
+        let line_string = wkt!(LINESTRING(0. 0.,10. 0.,0. 0.1,20. 0.));
+        // (10, 0) 处是尖刺，移除后 (0, 0.1) 处是直角，保留
+        assert_eq!(
+            line_string.remove_spikes(0.1),
+            wkt!(LINESTRING(0. 0.,0. 0.1,20. 0.))
+        );
+        // 阈值很小时不变
+        assert_eq!(line_string.remove_spikes(1e-6), line_string);
+
+        let short = wkt!(LINESTRING(0. 0.,1. 0.));
+        assert_eq!(short.remove_spikes(0.1), short);
+    }
+
+    #[test]
+    fn spike_at_ring_start() {
+        // 尖刺的顶点恰好是环的起点
+        let polygon = wkt!(POLYGON((5.1 20.,5.2 10.,10. 10.,10. 0.,0. 0.,0. 10.,5. 10.,5.1 20.)));
+        let cleaned = polygon.remove_spikes(2f64.to_radians());
+        assert_eq!(
+            cleaned,
+            wkt!(POLYGON((5.2 10.,10. 10.,10. 0.,0. 0.,0. 10.,5. 10.,5.2 10.)))
+        );
+        assert!(cleaned.is_valid());
+    }
+
+    #[test]
+    fn cascading_spikes_and_holes() {
+        // 尖刺由两段组成，移除尖端后，根部又形成新的尖刺
+        let polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 5.,20. 5.01,30. 5.02,15. 5.03,10. 5.04,10. 10.,0. 10.,0. 0.),
+            (2. 2.,2. 4.,4. 4.,4. 2.,3. 2.,3. 1.5,3. 2.,2. 2.)
+        ));
+        let cleaned = polygon.remove_spikes(2f64.to_radians());
+        assert_eq!(
+            cleaned,
+            wkt!(POLYGON(
+                (0. 0.,10. 0.,10. 5.,10. 5.04,10. 10.,0. 10.,0. 0.),
+                (2. 2.,2. 4.,4. 4.,4. 2.,3. 2.,2. 2.)
+            ))
+        );
+        assert_eq!(
+            MultiPolygon::new(vec![polygon]).remove_spikes(2f64.to_radians()),
+            MultiPolygon::new(vec![cleaned])
+        );
+    }
+
+    #[test]
+    fn rings_stay_valid() {
+        // 一个非常细长的三角形整体都是“尖刺”，但环至少保留三个不同的坐标
+        let sliver = wkt!(POLYGON((0. 0.,100. 0.,0. 0.1,0. 0.)));
+        let cleaned = sliver.remove_spikes(2f64.to_radians());
+        assert_eq!(cleaned, sliver);
+        assert!(cleaned.is_valid());
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.remove_spikes(0.1), empty);
+    }
+}
@@ -72,7 +72,7 @@ impl<T: CoordFloat + FromPrimitive> RhumbCalculations<T> {
 
     pub(crate) fn delta(&self) -> T {
         let threshold = T::from(10.0e-12).unwrap();
-        let q = if self.delta_psi > threshold {
+        let q = if self.delta_psi.abs() > threshold {
             self.delta_phi / self.delta_psi
         } else {
             self.phi1.cos()
@@ -94,29 +94,32 @@ impl<T: CoordFloat + FromPrimitive> RhumbCalculations<T> {
 
         let total_delta = self.delta();
 
-        if total_delta <= max_delta {
+        // `max_delta` 不是正数（包括 NaN）时无法加密，与距离足够短时一样只返回端点
+        let number_of_points = if max_delta > T::zero() && total_delta > max_delta {
+            (total_delta / max_delta).ceil().to_usize()
+        } else {
+            None
+        };
+        let Some(number_of_points) = number_of_points else {
             return if include_ends {
                 vec![self.from, self.to]
             } else {
                 vec![]
             };
-        }
-
-        let number_of_points = (total_delta / max_delta).ceil();
-        let interval = T::one() / number_of_points;
+        };
+        let interval = T::one() / T::from(number_of_points).unwrap();
 
-        let mut current_step = interval;
         let mut points = if include_ends {
             vec![self.from]
         } else {
             vec![]
         };
 
-        while current_step < T::one() {
-            let delta = total_delta * current_step;
+        // 使用整数步数而不是累加比例，避免浮点误差在终点附近多生成一个几乎重合的点
+        for step in 1..number_of_points {
+            let delta = total_delta * interval * T::from(step).unwrap();
             let point = calculate_destination(delta, lambda1, self.phi1, theta);
             points.push(point);
-            current_step = current_step + interval;
         }
 
         if include_ends {
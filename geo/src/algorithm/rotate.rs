@@ -1,6 +1,8 @@
-use crate::algorithm::{AffineOps, AffineTransform, BoundingRect, Centroid};
+use crate::algorithm::{
+    AffineOps, AffineTransform, BoundingRect, Centroid, MapCoords, MapCoordsInPlace,
+};
 use crate::geometry::*;
-use crate::CoordFloat;
+use crate::{coord, CoordFloat};
 
 /// 旋转几何图形绕某一点按角度（以度为单位）。
 ///
@@ -58,6 +60,9 @@ pub trait Rotate<T: CoordFloat> {
     ///
     /// 正角度为逆时针旋转，负角度为顺时针旋转。
     ///
+    /// 结果与应用 [`AffineTransform::rotate`] 完全相同。它在数值上等价于先平移到原点、
+    /// 绕原点旋转、再平移回来的组合变换，但直接构造单个变换矩阵，无需组合。
+    ///
     /// # 示例
     ///
     /// ```
@@ -142,6 +147,108 @@ where
     }
 }
 
+/// 在经纬度坐标上，绕一个地理点按角度（以度为单位）旋转几何图形。
+///
+/// 经度和纬度的一度代表的距离不同（除赤道外），因此直接对经纬度坐标使用 [`Rotate`]
+/// 会使几何图形变形。此方法将每个坐标视为单位球面上的向量，绕穿过 `center` 和球心的轴旋转
+/// （即对单位向量绕 `center` 方向的向量做 Rodrigues 旋转），距离 `center` 的大圆距离和形状保持不变。
+/// 适用于旋转 WGS84 中的建筑轮廓等小范围要素。
+///
+/// 与 [`Rotate`] 一样，正角度为逆时针旋转（从地面上方向下看），负角度为顺时针旋转。
+/// 坐标的单位为度，`x` 为经度，`y` 为纬度；结果的经度位于 `[-180, 180]` 范围内。
+///
+/// # 示例
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, Distance, Haversine, RotateGeographic};
+///
+/// let center = point!(x: 10.0, y: 60.0);
+/// // 位于中心正东方
+/// let east = point!(x: 10.01, y: 60.0);
+///
+/// let rotated = east.rotate_geographic(90.0, center);
+///
+/// // 旋转到中心的（近似）正北方，且到中心的距离不变
+/// assert_relative_eq!(rotated.x(), 10.0, epsilon = 1e-5);
+/// assert!(rotated.y() > 60.0);
+/// assert_relative_eq!(
+///     Haversine::distance(center, rotated),
+///     Haversine::distance(center, east),
+///     epsilon = 1e-6
+/// );
+/// ```
+pub trait RotateGeographic<T: CoordFloat> {
+    /// 绕地理点 `center` 按角度（以度为单位）旋转几何图形。
+    #[must_use]
+    fn rotate_geographic(&self, degrees: T, center: Point<T>) -> Self;
+
+    /// [`Self::rotate_geographic`]的可变版本
+    fn rotate_geographic_mut(&mut self, degrees: T, center: Point<T>);
+}
+
+impl<G, T> RotateGeographic<T> for G
+where
+    T: CoordFloat,
+    G: MapCoords<T, T, Output = G> + MapCoordsInPlace<T>,
+{
+    fn rotate_geographic(&self, degrees: T, center: Point<T>) -> Self {
+        let rotation = GeographicRotation::new(degrees, center);
+        self.map_coords(|coord| rotation.apply(coord))
+    }
+
+    fn rotate_geographic_mut(&mut self, degrees: T, center: Point<T>) {
+        let rotation = GeographicRotation::new(degrees, center);
+        self.map_coords_in_place(|coord| rotation.apply(coord))
+    }
+}
+
+/// 单位球面上绕穿过 `center` 的轴的旋转（Rodrigues 旋转公式）
+#[derive(Copy, Clone)]
+struct GeographicRotation<T> {
+    axis: [T; 3],
+    sin: T,
+    cos: T,
+}
+
+impl<T: CoordFloat> GeographicRotation<T> {
+    fn new(degrees: T, center: Point<T>) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        GeographicRotation {
+            axis: to_unit_vector(center.0),
+            sin,
+            cos,
+        }
+    }
+
+    fn apply(&self, coord: Coord<T>) -> Coord<T> {
+        let [kx, ky, kz] = self.axis;
+        let [x, y, z] = to_unit_vector(coord);
+        let dot = kx * x + ky * y + kz * z;
+        let cross = [ky * z - kz * y, kz * x - kx * z, kx * y - ky * x];
+        let one_minus_cos = T::one() - self.cos;
+        let rotated = [
+            x * self.cos + cross[0] * self.sin + kx * dot * one_minus_cos,
+            y * self.cos + cross[1] * self.sin + ky * dot * one_minus_cos,
+            z * self.cos + cross[2] * self.sin + kz * dot * one_minus_cos,
+        ];
+        from_unit_vector(rotated)
+    }
+}
+
+fn to_unit_vector<T: CoordFloat>(coord: Coord<T>) -> [T; 3] {
+    let (sin_lon, cos_lon) = coord.x.to_radians().sin_cos();
+    let (sin_lat, cos_lat) = coord.y.to_radians().sin_cos();
+    [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat]
+}
+
+fn from_unit_vector<T: CoordFloat>([x, y, z]: [T; 3]) -> Coord<T> {
+    coord! {
+        x: y.atan2(x).to_degrees(),
+        y: z.atan2(x.hypot(y)).to_degrees(),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::algorithm::Rotate;
@@ -571,4 +678,95 @@ mod test {
         let rotated_empty_multipolygon = empty_multipolygon.rotate_around_centroid(90.);
         assert_eq!(empty_multipolygon, rotated_empty_multipolygon);
     }
+
+    #[test]
+    fn test_rotate_geographic() {
+        use crate::algorithm::RotateGeographic;
+        use crate::{Distance, Haversine};
+
+        let center = point!(x: -73.98, y: 40.75);
+        let footprint = polygon![
+            (x: -73.981, y: 40.749),
+            (x: -73.979, y: 40.749),
+            (x: -73.979, y: 40.751),
+            (x: -73.981, y: 40.751),
+        ];
+
+        let rotated = footprint.rotate_geographic(30.0, center);
+        for (original, rotated) in footprint
+            .exterior()
+            .points()
+            .zip(rotated.exterior().points())
+        {
+            assert_relative_eq!(
+                Haversine::distance(center, original),
+                Haversine::distance(center, rotated),
+                epsilon = 1e-6
+            );
+        }
+        // 边长保持不变，而在经纬度上直接旋转会改变边长
+        let side = |polygon: &Polygon| -> f64 {
+            Haversine::distance(
+                Point(polygon.exterior().0[0]),
+                Point(polygon.exterior().0[1]),
+            )
+        };
+        assert_relative_eq!(side(&footprint), side(&rotated), epsilon = 1e-6);
+        assert!(
+            (side(&footprint) - side(&footprint.rotate_around_point(30.0, center))).abs() > 1.0
+        );
+
+        // 旋转一整圈回到原处，可变版本结果相同
+        assert_relative_eq!(
+            footprint.rotate_geographic(360.0, center),
+            footprint,
+            epsilon = 1e-9
+        );
+        let mut rotated_mut = footprint.clone();
+        rotated_mut.rotate_geographic_mut(30.0, center);
+        assert_eq!(rotated_mut, rotated);
+
+        // 中心点不动
+        assert_relative_eq!(
+            center.rotate_geographic(45.0, center),
+            center,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_rotate_around_point_matches_affine_pipeline() {
+        use crate::{AffineOps, AffineTransform};
+
+        let pivot = point!(x: 3.5, y: -1.25);
+        let geometries: Vec<Geometry> = vec![
+            point!(x: 1.0, y: 2.0).into(),
+            Line::new((0.0, 0.0), (4.0, 1.0)).into(),
+            line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 4.0), (x: 9.0, y: -2.0)].into(),
+            polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 2.0, y: 3.0)].into(),
+            Rect::new((1.0, 1.0), (2.0, 3.0)).into(),
+            Triangle::new((0.0, 0.0).into(), (1.0, 0.0).into(), (0.0, 1.0).into()).into(),
+        ];
+
+        let hand_composed = AffineTransform::translate(-pivot.x(), -pivot.y())
+            .rotated(33.0, (0.0, 0.0))
+            .translated(pivot.x(), pivot.y());
+
+        for geometry in geometries {
+            let rotated = geometry.rotate_around_point(33.0, pivot);
+            assert_eq!(
+                rotated,
+                geometry.affine_transform(&AffineTransform::rotate(33.0, pivot))
+            );
+            assert_relative_eq!(
+                rotated,
+                geometry.affine_transform(&hand_composed),
+                epsilon = 1e-12
+            );
+
+            let mut rotated_mut = geometry.clone();
+            rotated_mut.rotate_around_point_mut(33.0, pivot);
+            assert_eq!(rotated_mut, rotated);
+        }
+    }
 }
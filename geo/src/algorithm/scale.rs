@@ -79,6 +79,43 @@ pub trait Scale<T: CoordNum> {
 
     /// [`scale_around_point`](Self::scale_around_point) 的可变版本。
     fn scale_around_point_mut(&mut self, x_factor: T, y_factor: T, origin: impl Into<Coord<T>>);
+
+    /// 返回 [`scale`](Self::scale) 将应用于该几何图形的 [`AffineTransform`]，而不应用它。
+    ///
+    /// 参见 [`scale_xy_transform`](Self::scale_xy_transform)。
+    fn scale_transform(&self, scale_factor: T) -> AffineTransform<T>;
+
+    /// 返回 [`scale_xy`](Self::scale_xy) 将应用于该几何图形的 [`AffineTransform`]，而不应用它。
+    ///
+    /// 缩放原点是该几何图形的边界框中心。空几何图形没有边界框，此时返回[单位变换](AffineTransform::identity)。
+    ///
+    /// 返回的变换可以与其他变换组合，并一致地应用于其他几何图形。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{AffineOps, Scale, Translate};
+    /// use geo::line_string;
+    ///
+    /// let ls = line_string![(x: 0., y: 0.), (x: 10., y: 10.)];
+    ///
+    /// // 等价于 `ls.translate(1., 2.).scale_xy(2., 4.)` 的单个变换
+    /// let transform = ls
+    ///     .translate_transform(1., 2.)
+    ///     .compose(&ls.translate(1., 2.).scale_xy_transform(2., 4.));
+    /// assert_eq!(
+    ///     ls.affine_transform(&transform),
+    ///     ls.translate(1., 2.).scale_xy(2., 4.)
+    /// );
+    ///
+    /// // 将完全相同的变换应用于另一个几何图形
+    /// let other = line_string![(x: 5., y: 5.), (x: 6., y: 5.)];
+    /// assert_eq!(
+    ///     other.affine_transform(&transform),
+    ///     line_string![(x: 6., y: 7.), (x: 8., y: 7.)]
+    /// );
+    /// ```
+    fn scale_xy_transform(&self, x_factor: T, y_factor: T) -> AffineTransform<T>;
 }
 
 impl<T, IR, G> Scale<T> for G
@@ -122,4 +159,72 @@ where
         let affineop = AffineTransform::scale(x_factor, y_factor, origin);
         self.affine_transform_mut(&affineop)
     }
+
+    fn scale_transform(&self, scale_factor: T) -> AffineTransform<T> {
+        self.scale_xy_transform(scale_factor, scale_factor)
+    }
+
+    fn scale_xy_transform(&self, x_factor: T, y_factor: T) -> AffineTransform<T> {
+        match self.bounding_rect().into() {
+            Some(rect) => AffineTransform::scale(x_factor, y_factor, rect.center()),
+            // 空几何图形没有边界矩形，但在这种情况下，变换是无操作的。
+            None => AffineTransform::identity(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, MultiPolygon, Translate};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scale_multi_polygon_around_point_by_one_is_identity() {
+        let multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0.1 0.2,3.7 0.2,3.7 4.9,0.1 0.2)),
+            ((10. 10.,12.5 10.,12.5 11.25,10. 11.25,10. 10.),(10.5 10.5,11. 10.5,11. 11.,10.5 10.5))
+        ));
+        for origin in [
+            point!(x: 0., y: 0.),
+            point!(x: -123.456, y: 789.012),
+            point!(x: 1e6, y: -1e6),
+        ] {
+            let scaled = multi_polygon.scale_around_point(1., 1., origin);
+            assert_relative_eq!(scaled, multi_polygon, epsilon = 1e-9);
+
+            let mut scaled_mut = multi_polygon.clone();
+            scaled_mut.scale_around_point_mut(1., 1., origin);
+            assert_relative_eq!(scaled_mut, multi_polygon, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn scale_multi_polygon_around_shared_point() {
+        let multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,2. 0.,2. 2.,0. 0.)),
+            ((5. 5.,6. 5.,6. 6.,5. 5.))
+        ));
+        let origin = point!(x: 1., y: -1.);
+        let scaled = multi_polygon.scale_around_point(2., 3., origin);
+
+        // 等同于平移到原点、缩放、再平移回来
+        let expected = multi_polygon
+            .translate(-origin.x(), -origin.y())
+            .scale_around_point(2., 3., point!(x: 0., y: 0.))
+            .translate(origin.x(), origin.y());
+        assert_relative_eq!(scaled, expected);
+
+        // 每个多边形围绕同一锚点缩放，结果与单独缩放一致
+        for (scaled_polygon, polygon) in scaled.iter().zip(&multi_polygon) {
+            assert_relative_eq!(*scaled_polygon, polygon.scale_around_point(2., 3., origin));
+        }
+        assert_eq!(
+            scaled,
+            wkt!(MULTIPOLYGON(
+                ((-1. 2.,3. 2.,3. 8.,-1. 2.)),
+                ((9. 17.,11. 17.,11. 20.,9. 17.))
+            ))
+        );
+    }
 }
@@ -1,6 +1,10 @@
-use crate::algorithm::{CoordsIter, Distance, Euclidean};
+use crate::algorithm::{
+    Area, Centroid, CoordsIter, Distance, Euclidean, RemoveRepeatedPoints, Scale, Validation,
+};
 use crate::geometry::{Coord, Line, LineString, MultiLineString, MultiPolygon, Polygon};
 use crate::GeoFloat;
+#[cfg(feature = "multithreading")]
+use rayon::prelude::*;
 
 const LINE_STRING_INITIAL_MIN: usize = 2;
 const POLYGON_INITIAL_MIN: usize = 4;
@@ -155,6 +159,9 @@ where
 ///
 /// Multi*对象通过分别简化其所有组成几何体来简化。
 ///
+/// 启用 `multithreading` 功能时，内环较多的 [`Polygon`] 的各个环和成员较多的 [`MultiPolygon`] 的各个成员会并行简化，
+/// 结果与顺序简化完全相同。
+///
 /// 较大的`epsilon`意味着更积极地移除与保持现有形状的关注度较少的点。
 ///
 /// 具体来说，与简化输出距离比`epsilon`更近的点可能会被丢弃。
@@ -192,6 +199,34 @@ pub trait Simplify<T, Epsilon = T> {
     fn simplify(&self, epsilon: &T) -> Self
     where
         T: GeoFloat;
+
+    /// 与 [`simplify`](Self::simplify) 相同，同时返回坐标数量是否发生了变化，
+    /// 从而可以在简化没有移除任何点时跳过后续的处理（例如重新验证）。
+    ///
+    /// # 例子
+    ///
+    /// ```
+    /// use geo::{wkt, Simplify};
+    ///
+    /// let line_string = wkt!(LINESTRING(0.0 0.0,5.0 0.1,10.0 0.0));
+    ///
+    /// let (simplified, changed) = line_string.simplify_checked(&1.0);
+    /// assert!(changed);
+    /// assert_eq!(simplified, wkt!(LINESTRING(0.0 0.0,10.0 0.0)));
+    ///
+    /// let (unchanged, changed) = simplified.simplify_checked(&1.0);
+    /// assert!(!changed);
+    /// assert_eq!(unchanged, simplified);
+    /// ```
+    fn simplify_checked(&self, epsilon: &T) -> (Self, bool)
+    where
+        T: GeoFloat,
+        Self: Sized + CoordsIter,
+    {
+        let simplified = self.simplify(epsilon);
+        let changed = simplified.coords_count() != self.coords_count();
+        (simplified, changed)
+    }
 }
 
 /// 简化几何体，返回保留的输入索引。
@@ -209,6 +244,10 @@ pub trait SimplifyIdx<T, Epsilon = T> {
     /// 使用[Ramer-Douglas-Peucker](https://en.wikipedia.org/wiki/Ramer-Douglas-Peucker_algorithm)
     /// 算法返回几何体的简化索引
     ///
+    /// 返回的索引按升序排列，并且与 [`Simplify::simplify`] 的输出一一对应：
+    /// `result[i]` 是简化结果中第 `i` 个顶点在原始几何体中的索引。
+    /// 因此可以直接用它把每个顶点的属性（例如时间戳）带到简化后的几何体上。
+    ///
     /// # 例子
     ///
     /// ```
@@ -233,6 +272,11 @@ pub trait SimplifyIdx<T, Epsilon = T> {
     /// ];
     ///
     /// assert_eq!(expected, simplified);
+    ///
+    /// // 将每个顶点的属性映射到简化后的顶点
+    /// let timestamps = [10, 20, 30, 40, 50];
+    /// let simplified_timestamps: Vec<_> = simplified.iter().map(|&i| timestamps[i]).collect();
+    /// assert_eq!(simplified_timestamps, vec![10, 20, 30, 50]);
     /// ```
     fn simplify_idx(&self, epsilon: &T) -> Vec<usize>
     where
@@ -280,39 +324,414 @@ where
     }
 }
 
+fn simplify_polygon_ring<T: GeoFloat>(ring: &LineString<T>, epsilon: &T) -> LineString<T> {
+    LineString::from(rdp::<_, _, POLYGON_INITIAL_MIN>(
+        ring.coords_iter(),
+        epsilon,
+    ))
+}
+
+#[cfg(not(feature = "multithreading"))]
 impl<T> Simplify<T> for Polygon<T>
 where
     T: GeoFloat,
 {
     fn simplify(&self, epsilon: &T) -> Self {
+        simplify_polygon(self, epsilon)
+    }
+}
+
+#[cfg(not(feature = "multithreading"))]
+impl<T> Simplify<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(self.iter().map(|p| p.simplify(epsilon)).collect())
+    }
+}
+
+/// 启用 `multithreading` 功能时，只有环或成员的数量不少于此值才并行简化，
+/// 数量较少时线程调度的开销会超过并行带来的收益
+#[cfg(feature = "multithreading")]
+const PARALLEL_SIMPLIFY_MIN_PARTS: usize = 16;
+
+// 并行简化需要 `T: Send + Sync`，这一约束只在启用 `multithreading` 功能时添加
+#[cfg(feature = "multithreading")]
+impl<T> Simplify<T> for Polygon<T>
+where
+    T: GeoFloat + Send + Sync,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        if self.interiors().len() < PARALLEL_SIMPLIFY_MIN_PARTS {
+            return simplify_polygon(self, epsilon);
+        }
+        let (exterior, interiors) = rayon::join(
+            || simplify_polygon_ring(self.exterior(), epsilon),
+            || {
+                self.interiors()
+                    .par_iter()
+                    .map(|l| simplify_polygon_ring(l, epsilon))
+                    .collect()
+            },
+        );
+        Polygon::new(exterior, interiors)
+    }
+}
+
+#[cfg(feature = "multithreading")]
+impl<T> Simplify<T> for MultiPolygon<T>
+where
+    T: GeoFloat + Send + Sync,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        if self.0.len() < PARALLEL_SIMPLIFY_MIN_PARTS {
+            return MultiPolygon::new(self.iter().map(|p| p.simplify(epsilon)).collect());
+        }
+        MultiPolygon::new(self.par_iter().map(|p| p.simplify(epsilon)).collect())
+    }
+}
+
+fn simplify_polygon<T: GeoFloat>(polygon: &Polygon<T>, epsilon: &T) -> Polygon<T> {
+    Polygon::new(
+        simplify_polygon_ring(polygon.exterior(), epsilon),
+        polygon
+            .interiors()
+            .iter()
+            .map(|l| simplify_polygon_ring(l, epsilon))
+            .collect(),
+    )
+}
+
+/// 使用 RDP 算法简化多边形，然后绕简化结果的质心做一次均匀缩放，使其面积恢复为原始（无符号）面积。
+///
+/// 普通的简化在凸角处总是向内切，因此简化后的多边形往往偏小。对于分级统计图等对面积敏感的用途，
+/// 这种偏差会影响观感。注意这里只保证总面积不变（在浮点误差范围内），形状**不会**被完全保留：
+/// 缩放会使简化后的顶点略微偏离原始位置。
+///
+/// [`MultiPolygon`] 的每个成员分别绕各自的质心缩放，因此各成员的面积都分别保持不变。
+/// 如果简化后的面积为零（例如环退化），则不缩放，直接返回简化结果。
+///
+/// # 例子
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, Area, Simplify, SimplifyPreservingArea};
+///
+/// let polygon = polygon![
+///     (x: 0.0, y: 0.0),
+///     (x: 5.0, y: -0.5),
+///     (x: 10.0, y: 0.0),
+///     (x: 10.0, y: 10.0),
+///     (x: 0.0, y: 10.0),
+/// ];
+///
+/// assert!(polygon.simplify(&1.0).unsigned_area() < polygon.unsigned_area());
+///
+/// let simplified = polygon.simplify_preserving_area(&1.0);
+/// assert_eq!(simplified.exterior().0.len(), 5);
+/// assert_relative_eq!(simplified.unsigned_area(), polygon.unsigned_area(), epsilon = 1e-9);
+/// ```
+pub trait SimplifyPreservingArea<T, Epsilon = T> {
+    fn simplify_preserving_area(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyPreservingArea<T> for Polygon<T>
+where
+    T: GeoFloat,
+    Self: Simplify<T>,
+{
+    fn simplify_preserving_area(&self, epsilon: &T) -> Self {
+        let simplified = self.simplify(epsilon);
+        let original_area = self.unsigned_area();
+        let simplified_area = simplified.unsigned_area();
+        if simplified_area == T::zero() || original_area == simplified_area {
+            return simplified;
+        }
+        let Some(centroid) = simplified.centroid() else {
+            return simplified;
+        };
+        // 面积随缩放系数的平方变化
+        let factor = (original_area / simplified_area).sqrt();
+        simplified.scale_around_point(factor, factor, centroid)
+    }
+}
+
+impl<T> SimplifyPreservingArea<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+    Polygon<T>: Simplify<T>,
+{
+    fn simplify_preserving_area(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| p.simplify_preserving_area(epsilon))
+                .collect(),
+        )
+    }
+}
+
+/// 尝试 `epsilon` 的次数上限（每次减半），之后退回原始几何体
+const SIMPLIFY_VALID_MAX_ATTEMPTS: usize = 8;
+
+/// 使用 RDP 算法简化，并保证结果是[有效的](Validation)。
+///
+/// RDP 可能使有效的多边形自相交，或使外环与内环交叉。此方法检查简化结果的有效性，
+/// 如果无效，就将 `epsilon` 减半后重试；最多尝试 8 次，仍然无效时返回原始几何体的副本。
+///
+/// 只有在输入本身有效时，输出才保证有效。如果需要保持拓扑的简化算法，请参见
+/// [`SimplifyVwPreserve`](crate::SimplifyVwPreserve)。
+///
+/// # 例子
+///
+/// ```
+/// use geo::{wkt, Simplify, SimplifyValid, Validation};
+///
+/// // 外环底边有一个小的向外凸起，内环伸入了这个凸起中
+/// let polygon = wkt!(POLYGON(
+///     (0. 0.,4. 0.,5. -0.9,6. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+///     (4.8 -0.5,5.2 -0.5,5.2 0.5,4.8 0.5,4.8 -0.5)
+/// ));
+///
+/// // 直接简化会去掉凸起，使外环穿过内环
+/// assert!(!polygon.simplify(&1.0).is_valid());
+///
+/// let simplified = polygon.simplify_valid(&1.0);
+/// assert!(simplified.is_valid());
+/// assert_eq!(simplified, polygon.simplify(&0.5));
+/// ```
+pub trait SimplifyValid<T, Epsilon = T> {
+    fn simplify_valid(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+fn simplify_until_valid<T, G>(geometry: &G, epsilon: &T) -> G
+where
+    T: GeoFloat,
+    G: Simplify<T> + Validation + Clone,
+{
+    let two = T::one() + T::one();
+    let mut epsilon = *epsilon;
+    for _ in 0..SIMPLIFY_VALID_MAX_ATTEMPTS {
+        let simplified = geometry.simplify(&epsilon);
+        if simplified.is_valid() {
+            return simplified;
+        }
+        epsilon = epsilon / two;
+    }
+    geometry.clone()
+}
+
+impl<T> SimplifyValid<T> for Polygon<T>
+where
+    T: GeoFloat,
+    Self: Simplify<T>,
+{
+    fn simplify_valid(&self, epsilon: &T) -> Self {
+        simplify_until_valid(self, epsilon)
+    }
+}
+
+impl<T> SimplifyValid<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+    Self: Simplify<T>,
+{
+    fn simplify_valid(&self, epsilon: &T) -> Self {
+        simplify_until_valid(self, epsilon)
+    }
+}
+
+/// 使用 RDP 算法简化多边形，并保证每个环在简化后仍然是闭合的，且去除重复点后至少有 4 个坐标
+/// （包括闭合坐标）。
+///
+/// [`Simplify`] 已经不会把环简化到少于 4 个坐标，但输入中的重复坐标可能被保留下来，
+/// 使结果在去除重复点后只剩下退化的环，[`Validation`] 会将其报告为
+/// [`TooFewPointsInRing`](crate::algorithm::validation::InvalidPolygon::TooFewPointsInRing)。
+/// 此方法逐环检查简化结果：如果某个环不再闭合或者坐标不足，则该环退回原始环，其他环仍然被简化。
+///
+/// 如果原始环本身就坐标不足，输出中的该环也同样不足。
+///
+/// # 例子
+///
+/// ```
+/// use geo::{wkt, Simplify, SimplifyPreserveClosed, Validation};
+///
+/// // 内环是一个闭合坐标重复了一次的小三角形
+/// let polygon = wkt!(POLYGON(
+///     (0. 0.,5. 0.5,10. 0.,10. 10.,0. 10.,0. 0.),
+///     (1. 1.,6. 1.,6. 2.,1. 1.,1. 1.)
+/// ));
+/// assert!(polygon.is_valid());
+///
+/// // 直接简化会使内环退化
+/// assert!(!polygon.simplify(&2.0).is_valid());
+///
+/// let simplified = polygon.simplify_preserve_closed(&2.0);
+/// assert_eq!(
+///     simplified,
+///     wkt!(POLYGON(
+///         (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+///         (1. 1.,6. 1.,6. 2.,1. 1.,1. 1.)
+///     ))
+/// );
+/// assert!(simplified.is_valid());
+/// ```
+pub trait SimplifyPreserveClosed<T, Epsilon = T> {
+    fn simplify_preserve_closed(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+fn simplify_ring_preserve_closed<T: GeoFloat>(ring: &LineString<T>, epsilon: &T) -> LineString<T> {
+    let simplified = simplify_polygon_ring(ring, epsilon);
+    if simplified.is_closed() && simplified.remove_repeated_points().0.len() >= POLYGON_INITIAL_MIN
+    {
+        simplified
+    } else {
+        ring.clone()
+    }
+}
+
+impl<T> SimplifyPreserveClosed<T> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_preserve_closed(&self, epsilon: &T) -> Self {
         Polygon::new(
-            LineString::from(rdp::<_, _, POLYGON_INITIAL_MIN>(
-                self.exterior().coords_iter(),
-                epsilon,
-            )),
+            simplify_ring_preserve_closed(self.exterior(), epsilon),
             self.interiors()
                 .iter()
-                .map(|l| {
-                    LineString::from(rdp::<_, _, POLYGON_INITIAL_MIN>(l.coords_iter(), epsilon))
-                })
+                .map(|ring| simplify_ring_preserve_closed(ring, epsilon))
                 .collect(),
         )
     }
 }
 
-impl<T> Simplify<T> for MultiPolygon<T>
+impl<T> SimplifyPreserveClosed<T> for MultiPolygon<T>
 where
     T: GeoFloat,
 {
-    fn simplify(&self, epsilon: &T) -> Self {
-        MultiPolygon::new(self.iter().map(|p| p.simplify(epsilon)).collect())
+    fn simplify_preserve_closed(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| p.simplify_preserve_closed(epsilon))
+                .collect(),
+        )
+    }
+}
+
+/// 将闭合的环当作一个循环来简化，保证结果仍然闭合且至少有 4 个坐标（包括闭合坐标）。
+///
+/// 对闭合的 [`LineString`] 直接使用 [`Simplify`] 时，RDP 以起点（同时也是终点）为固定的锚点，
+/// 因此不论起点是否重要都会被保留，而它两侧的顶点则是相对于一个退化的“线段”来衡量的。
+/// 此方法不以起点为特殊点：它先选出环上两个相距最远的顶点作为锚点，把环分成两条链分别运行 RDP，
+/// 从而使结果只取决于环的形状，而不取决于环从哪个顶点开始。
+///
+/// 输出保持原始的顶点顺序。如果起点被保留，输出仍然从起点开始；否则从原始顺序中起点之后第一个
+/// 被保留的顶点开始。如果两条链被简化到只剩锚点，则额外保留离两个锚点连线最远的顶点，
+/// 使结果至少是一个三角形。
+///
+/// 输入不闭合、坐标少于 4 个、所有顶点共线，或者 `epsilon` 不大于零时，返回原始环的副本。
+///
+/// # 例子
+///
+/// ```
+/// use geo::{wkt, Simplify, SimplifyRing};
+///
+/// // 起点位于正方形底边的中点
+/// let ring = wkt!(LINESTRING(5. 0.,10. 0.,10. 10.,0. 10.,0. 0.,5. 0.));
+///
+/// // 普通的简化总是保留起点
+/// assert_eq!(ring.simplify(&1.0), ring);
+///
+/// // 按环简化则将其去除，输出从下一个被保留的顶点开始
+/// assert_eq!(
+///     ring.simplify_ring(&1.0),
+///     wkt!(LINESTRING(10. 0.,10. 10.,0. 10.,0. 0.,10. 0.))
+/// );
+/// ```
+pub trait SimplifyRing<T> {
+    fn simplify_ring(&self, epsilon: &T) -> LineString<T>
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyRing<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_ring(&self, epsilon: &T) -> LineString<T> {
+        if *epsilon <= T::zero() || !self.is_closed() || self.0.len() < POLYGON_INITIAL_MIN {
+            return self.clone();
+        }
+        // 不含闭合坐标的顶点
+        let vertices = &self.0[..self.0.len() - 1];
+        let farthest_from = |origin: Coord<T>| {
+            (0..vertices.len())
+                .map(|index| (index, Euclidean::distance(origin, vertices[index])))
+                .fold((0, T::zero()), |farthest, candidate| {
+                    if candidate.1 > farthest.1 {
+                        candidate
+                    } else {
+                        farthest
+                    }
+                })
+        };
+        let (first_anchor, _) = farthest_from(vertices[0]);
+        let (second_anchor, anchor_distance) = farthest_from(vertices[first_anchor]);
+        if anchor_distance == T::zero() {
+            return self.clone();
+        }
+        let start = first_anchor.min(second_anchor);
+        let end = first_anchor.max(second_anchor);
+
+        let rdp_index = |index: usize| RdpIndex {
+            index,
+            coord: vertices[index],
+        };
+        let chain: Vec<_> = (start..=end).map(rdp_index).collect();
+        let wrapping_chain: Vec<_> = (end..vertices.len())
+            .chain(0..=start)
+            .map(rdp_index)
+            .collect();
+        let mut kept = calculate_rdp_indices::<_, LINE_STRING_INITIAL_MIN>(&chain, epsilon);
+        let wrapping_kept =
+            calculate_rdp_indices::<_, LINE_STRING_INITIAL_MIN>(&wrapping_chain, epsilon);
+        // 两条链共享两个锚点
+        kept.extend_from_slice(&wrapping_kept[1..wrapping_kept.len() - 1]);
+
+        if kept.len() < 3 {
+            let anchor_line = Line::new(vertices[start], vertices[end]);
+            let (farthest_index, farthest_distance) = (0..vertices.len())
+                .map(|index| (index, Euclidean::distance(vertices[index], &anchor_line)))
+                .fold((0, T::zero()), |farthest, candidate| {
+                    if candidate.1 > farthest.1 {
+                        candidate
+                    } else {
+                        farthest
+                    }
+                });
+            if farthest_distance == T::zero() {
+                return self.clone();
+            }
+            kept.push(farthest_index);
+        }
+
+        kept.sort_unstable();
+        let mut coords: Vec<_> = kept.into_iter().map(|index| vertices[index]).collect();
+        coords.push(coords[0]);
+        LineString::new(coords)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{coord, line_string, polygon};
+    use crate::{coord, line_string, polygon, MapCoords};
 
     #[test]
     fn recursion_test() {
@@ -458,6 +877,74 @@ mod test {
         assert_eq!(vec![0usize, 1, 2, 3, 4], indices);
     }
 
+    #[test]
+    fn simplify_idx_matches_simplify() {
+        let ls: LineString = geo_test_fixtures::louisiana();
+        for epsilon in [0.0001, 0.01, 0.1] {
+            let indices = ls.simplify_idx(&epsilon);
+            let simplified = ls.simplify(&epsilon);
+            assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+            assert_eq!(
+                indices.iter().map(|&i| ls.0[i]).collect::<Vec<_>>(),
+                simplified.0
+            );
+        }
+    }
+
+    #[test]
+    fn simplify_preserving_area() {
+        use crate::Translate;
+
+        let polygon = Polygon::new(geo_test_fixtures::louisiana::<f64>(), vec![]);
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon.clone(),
+            polygon.translate(100., 0.).scale(0.5),
+        ]);
+
+        let simplified = multi_polygon.simplify_preserving_area(&0.05);
+        assert!(simplified.coords_count() < multi_polygon.coords_count());
+        for (original, simplified) in multi_polygon.iter().zip(simplified.iter()) {
+            assert_eq!(
+                simplified.exterior().0.len(),
+                original.simplify(&0.05).exterior().0.len()
+            );
+            assert_relative_eq!(
+                simplified.unsigned_area(),
+                original.unsigned_area(),
+                max_relative = 1e-12
+            );
+        }
+
+        // 退化为零面积时不缩放
+        let sliver = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.1), (x: 20.0, y: 0.0)];
+        assert_eq!(sliver.simplify_preserving_area(&1.0), sliver.simplify(&1.0));
+    }
+
+    #[test]
+    fn simplify_valid() {
+        use crate::wkt;
+
+        // 第一个成员的凸起伸入第二个成员的浅凹口中。两个成员各自简化后都有效，
+        // 但凹口被去掉后，两者会重叠
+        let multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,10. 0.,10. 4.,11.6 5.,10. 6.,10. 10.,0. 10.,0. 0.)),
+            ((11. 0.,20. 0.,20. 10.,11. 10.,11.8 5.,11. 0.))
+        ));
+        assert!(multi_polygon.is_valid());
+        assert!(!multi_polygon.simplify(&1.0).is_valid());
+        let simplified = multi_polygon.simplify_valid(&1.0);
+        assert!(simplified.is_valid());
+        assert_eq!(simplified, multi_polygon.simplify(&0.5));
+
+        // 始终有效时等同于 simplify
+        let polygon = wkt!(POLYGON((0. 0.,5. 0.1,10. 0.,10. 10.,0. 10.,0. 0.)));
+        assert_eq!(polygon.simplify_valid(&1.0), polygon.simplify(&1.0));
+
+        // 无效的输入无法修复，退回原始几何体
+        let bowtie = wkt!(POLYGON((0. 0.,10. 10.,10. 0.,0. 10.,0. 0.)));
+        assert_eq!(bowtie.simplify_valid(&1.0), bowtie);
+    }
+
     // https://github.com/georust/geo/issues/142
     #[test]
     fn simplify_line_string_polygon_initial_min() {
@@ -510,4 +997,203 @@ mod test {
         ];
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn simplify_preserve_closed() {
+        use crate::algorithm::validation::{InvalidPolygon, RingRole};
+        use crate::wkt;
+
+        let multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,5. 0.,5. 1.,0. 0.,0. 0.)),
+            ((10. 0.,15. 0.2,20. 0.,20. 10.,10. 10.,10. 0.))
+        ));
+        assert_eq!(
+            multi_polygon.simplify(&2.0).0[0].validation_errors(),
+            vec![InvalidPolygon::TooFewPointsInRing(RingRole::Exterior)]
+        );
+
+        let simplified = multi_polygon.simplify_preserve_closed(&2.0);
+        // 退化的环退回原始环，其他环照常简化
+        assert_eq!(simplified.0[0], multi_polygon.0[0]);
+        assert_eq!(simplified.0[1], multi_polygon.0[1].simplify(&2.0));
+        assert_eq!(simplified.0[1].exterior().0.len(), 5);
+        assert!(simplified.is_valid());
+    }
+
+    #[test]
+    fn simplify_ring_does_not_depend_on_start() {
+        // 同一个带有噪声的环，从不同的顶点开始
+        let coords = vec![
+            coord! { x: 0.0, y: 0.0 },
+            coord! { x: 5.0, y: 0.2 },
+            coord! { x: 10.0, y: 0.0 },
+            coord! { x: 10.2, y: 5.0 },
+            coord! { x: 10.0, y: 10.0 },
+            coord! { x: 5.0, y: 9.9 },
+            coord! { x: 0.0, y: 10.0 },
+            coord! { x: -0.1, y: 5.0 },
+        ];
+        let expected = [
+            coord! { x: 0.0, y: 0.0 },
+            coord! { x: 10.0, y: 0.0 },
+            coord! { x: 10.0, y: 10.0 },
+            coord! { x: 0.0, y: 10.0 },
+        ];
+        for rotation in 0..coords.len() {
+            let mut rotated = coords.clone();
+            rotated.rotate_left(rotation);
+            let mut ring = LineString::from(rotated);
+            ring.close();
+
+            let simplified = ring.simplify_ring(&1.0);
+            assert!(simplified.is_closed());
+            assert_eq!(simplified.0.len(), 5);
+            // 输出保持原始的顶点顺序，只是起点可能不同
+            let start = expected
+                .iter()
+                .position(|coord| *coord == simplified.0[0])
+                .unwrap();
+            let mut rotated_expected = expected.to_vec();
+            rotated_expected.rotate_left(start);
+            assert_eq!(&simplified.0[..4], &rotated_expected[..]);
+        }
+    }
+
+    #[test]
+    fn simplify_ring_keeps_at_least_a_triangle() {
+        // 一个很扁的环，普通的 RDP 会把两条链都简化为直线
+        let ring = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 5.0, y: -0.1),
+            (x: 10.0, y: 0.0),
+            (x: 5.0, y: 0.5),
+            (x: 0.0, y: 0.0),
+        ];
+        assert_eq!(
+            ring.simplify_ring(&1.0),
+            line_string![
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 5.0, y: 0.5),
+                (x: 0.0, y: 0.0),
+            ]
+        );
+
+        // 退化或不闭合的输入保持不变
+        let collinear = line_string![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 2.0, y: 0.0),
+            (x: 0.0, y: 0.0),
+        ];
+        assert_eq!(collinear.simplify_ring(&1.0), collinear);
+        let open = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0)];
+        assert_eq!(open.simplify_ring(&1.0), open);
+    }
+
+    #[test]
+    fn multipolygon_matches_sequential() {
+        // 足够多的成员和内环，使并行路径真正分担工作
+        let polygon = polygon!(
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 5.0, y: 0.4),
+                (x: 10.0, y: 0.0),
+                (x: 10.3, y: 5.0),
+                (x: 10.0, y: 10.0),
+                (x: 0.0, y: 10.0),
+            ],
+            interiors: [
+                [(x: 1.0, y: 1.0), (x: 2.0, y: 1.1), (x: 3.0, y: 1.0), (x: 3.0, y: 3.0), (x: 1.0, y: 3.0)],
+                [(x: 5.0, y: 5.0), (x: 6.0, y: 5.0), (x: 6.05, y: 6.0), (x: 6.0, y: 7.0), (x: 5.0, y: 7.0)],
+            ],
+        );
+        let multi_polygon = MultiPolygon::new(
+            (0..64)
+                .map(|i| {
+                    let offset = i as f64 * 20.0;
+                    polygon.map_coords(
+                        |c| coord! { x: c.x + offset, y: c.y * (1.0 + i as f64 / 64.0) },
+                    )
+                })
+                .collect(),
+        );
+
+        for epsilon in [0.0, 0.1, 0.5, 2.0] {
+            let expected = MultiPolygon::new(
+                multi_polygon
+                    .iter()
+                    .map(|p| {
+                        Polygon::new(
+                            simplify_polygon_ring(p.exterior(), &epsilon),
+                            p.interiors()
+                                .iter()
+                                .map(|ring| simplify_polygon_ring(ring, &epsilon))
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            );
+            assert_eq!(multi_polygon.simplify(&epsilon), expected);
+        }
+    }
+
+    #[test]
+    fn polygon_with_many_interiors_matches_sequential() {
+        // 内环数量超过并行阈值
+        let hole: LineString<f64> =
+            vec![(1.0, 1.0), (1.5, 1.02), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0)].into();
+        let interiors: Vec<_> = (0..40)
+            .map(|i| hole.map_coords(|c| coord! { x: c.x + i as f64 * 2.0, y: c.y }))
+            .collect();
+        let exterior: LineString<f64> = vec![
+            (0.0, 0.0),
+            (50.0, 0.3),
+            (100.0, 0.0),
+            (100.0, 5.0),
+            (0.0, 5.0),
+        ]
+        .into();
+        let polygon = Polygon::new(exterior, interiors);
+
+        for epsilon in [0.0, 0.01, 0.5] {
+            let expected = Polygon::new(
+                simplify_polygon_ring(polygon.exterior(), &epsilon),
+                polygon
+                    .interiors()
+                    .iter()
+                    .map(|ring| simplify_polygon_ring(ring, &epsilon))
+                    .collect(),
+            );
+            assert_eq!(polygon.simplify(&epsilon), expected);
+        }
+    }
+
+    #[test]
+    fn simplify_checked() {
+        let polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 5.0, y: 0.1),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 10.0),
+        ];
+        let (simplified, changed) = polygon.simplify_checked(&1.0);
+        assert!(changed);
+        assert_eq!(simplified, polygon.simplify(&1.0));
+
+        let multi_polygon = MultiPolygon::new(vec![simplified.clone(), simplified]);
+        let (unchanged, changed) = multi_polygon.simplify_checked(&1.0);
+        assert!(!changed);
+        assert_eq!(unchanged, multi_polygon);
+
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)],
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.01), (x: 2.0, y: 0.0)],
+        ]);
+        let (simplified, changed) = multi_line_string.simplify_checked(&0.1);
+        assert!(changed);
+        assert_eq!(simplified, multi_line_string.simplify(&0.1));
+        assert!(!multi_line_string.simplify_checked(&0.0).1);
+    }
 }
@@ -0,0 +1,210 @@
+use num_traits::FromPrimitive;
+use rstar::{PointDistance, RTree};
+
+use crate::line_measures::{Distance, Haversine};
+use crate::{GeoFloat, MultiPoint, Point, MEAN_EARTH_RADIUS};
+
+/// 对点集进行抽稀：贪心地保留点，并丢弃与任何已保留点距离小于 `min_distance` 的点。
+///
+/// 这是点图层的综合（generalization），类似于线的简化，但针对的是点。
+/// 结果中任意两点之间的距离都不小于 `min_distance`。邻近查询使用 R 树完成。
+///
+/// 按照输入顺序依次处理每个点，因此**结果依赖于点的顺序**：
+/// 排在前面的点总是优先被保留。如果需要让某些点优先保留（例如按重要性），请先对输入排序。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{point, MultiPoint, ThinPoints};
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: 0.0, y: 0.0),
+///     point!(x: 0.5, y: 0.0),
+///     point!(x: 1.0, y: 0.0),
+///     point!(x: 1.5, y: 0.0),
+/// ]);
+///
+/// let thinned = points.thin_points(1.0);
+/// assert_eq!(
+///     thinned,
+///     MultiPoint::new(vec![point!(x: 0.0, y: 0.0), point!(x: 1.0, y: 0.0)])
+/// );
+/// ```
+pub trait ThinPoints<T: GeoFloat> {
+    /// 使用欧几里得距离进行抽稀，`min_distance` 的单位与坐标相同。
+    fn thin_points(&self, min_distance: T) -> MultiPoint<T>;
+
+    /// 使用 [`Haversine`] 距离进行抽稀，坐标被解释为经度/纬度，`min_distance` 的单位为米。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{point, MultiPoint, ThinPoints};
+    ///
+    /// // 在赤道附近，0.001 度约为 111 米
+    /// let points = MultiPoint::new(vec![
+    ///     point!(x: 0.0, y: 0.0),
+    ///     point!(x: 0.001, y: 0.0),
+    ///     point!(x: 0.002, y: 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(points.thin_points_haversine(150.0).0.len(), 2);
+    /// assert_eq!(points.thin_points_haversine(100.0).0.len(), 3);
+    /// ```
+    fn thin_points_haversine(&self, min_distance: T) -> MultiPoint<T>;
+}
+
+impl<T> ThinPoints<T> for MultiPoint<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn thin_points(&self, min_distance: T) -> MultiPoint<T> {
+        let min_distance_2 = min_distance * min_distance;
+        let mut kept = RTree::new();
+        let mut result = vec![];
+        for point in self.iter() {
+            let too_close = kept
+                .locate_within_distance(*point, min_distance_2)
+                .any(|kept_point: &Point<T>| kept_point.distance_2(point) < min_distance_2);
+            if !too_close {
+                kept.insert(*point);
+                result.push(*point);
+            }
+        }
+        MultiPoint::new(result)
+    }
+
+    fn thin_points_haversine(&self, min_distance: T) -> MultiPoint<T> {
+        // 在单位球面上以三维坐标建立索引：弦长随大圆距离单调递增，
+        // 因此可以在不受两极和反子午线影响的情况下进行邻近查询。
+        let radius = T::from(MEAN_EARTH_RADIUS).unwrap();
+        let two = T::one() + T::one();
+        let half_angle = (min_distance / radius / two).min(T::from(90.0).unwrap().to_radians());
+        let chord = two * half_angle.sin();
+        // 稍微放宽查询范围，最终由 Haversine 距离决定
+        let query_distance_2 = (chord * chord) * T::from(1.0 + 1e-9).unwrap() + T::epsilon();
+
+        let mut kept: RTree<UnitVector<T>> = RTree::new();
+        let mut result = vec![];
+        for point in self.iter() {
+            let unit_vector = UnitVector::new(*point);
+            let too_close = kept
+                .locate_within_distance(unit_vector.xyz, query_distance_2)
+                .any(|kept_point| Haversine::distance(kept_point.point, *point) < min_distance);
+            if !too_close {
+                kept.insert(unit_vector);
+                result.push(*point);
+            }
+        }
+        MultiPoint::new(result)
+    }
+}
+
+/// 一个经度/纬度点及其在单位球面上对应的三维坐标。
+struct UnitVector<T: GeoFloat> {
+    point: Point<T>,
+    xyz: [T; 3],
+}
+
+impl<T: GeoFloat> UnitVector<T> {
+    fn new(point: Point<T>) -> Self {
+        let (lon, lat) = (point.x().to_radians(), point.y().to_radians());
+        let xyz = [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()];
+        Self { point, xyz }
+    }
+}
+
+impl<T: GeoFloat> rstar::RTreeObject for UnitVector<T> {
+    type Envelope = rstar::AABB<[T; 3]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        rstar::AABB::from_point(self.xyz)
+    }
+}
+
+impl<T: GeoFloat> PointDistance for UnitVector<T> {
+    fn distance_2(&self, point: &[T; 3]) -> T {
+        self.xyz.distance_2(point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt};
+
+    #[test]
+    fn thin_points_euclidean() {
+        let points: MultiPoint = wkt!(MULTIPOINT(0. 0.,3. 0.,1. 0.,0. 1.,5. 5.,5.5 5.));
+        let thinned = points.thin_points(2.0);
+        assert_eq!(thinned, wkt!(MULTIPOINT(0. 0.,3. 0.,5. 5.)));
+
+        // 结果中任意两点之间的距离都不小于 min_distance
+        for (i, a) in thinned.iter().enumerate() {
+            for b in thinned.iter().skip(i + 1) {
+                assert!(crate::Euclidean::distance(*a, *b) >= 2.0);
+            }
+        }
+    }
+
+    #[test]
+    fn thin_points_depends_on_order() {
+        let points: MultiPoint = wkt!(MULTIPOINT(0. 0.,1. 0.,2. 0.));
+        assert_eq!(points.thin_points(1.5).0.len(), 2);
+
+        let reordered: MultiPoint = wkt!(MULTIPOINT(1. 0.,0. 0.,2. 0.));
+        assert_eq!(reordered.thin_points(1.5), wkt!(MULTIPOINT(1. 0.)));
+    }
+
+    #[test]
+    fn thin_points_exact_distance_is_kept() {
+        let points: MultiPoint = wkt!(MULTIPOINT(0. 0.,1. 0.));
+        assert_eq!(points.thin_points(1.0), points);
+        assert!(MultiPoint::<f64>::new(vec![]).thin_points(1.0).0.is_empty());
+    }
+
+    #[test]
+    fn thin_points_haversine_across_antimeridian() {
+        let points = MultiPoint::new(vec![
+            point!(x: 179.9995, y: 0.0),
+            point!(x: -179.9995, y: 0.0),
+            point!(x: 0.0, y: 89.9999),
+            point!(x: 180.0, y: 89.9999),
+        ]);
+        // 跨越反子午线的两点相距约 111 米，靠近北极的两点相距约 22 米
+        let thinned = points.thin_points_haversine(200.0);
+        assert_eq!(
+            thinned,
+            MultiPoint::new(vec![
+                point!(x: 179.9995, y: 0.0),
+                point!(x: 0.0, y: 89.9999)
+            ])
+        );
+        assert_eq!(points.thin_points_haversine(10.0), points);
+    }
+
+    #[test]
+    fn thin_points_haversine_matches_brute_force() {
+        let points: MultiPoint = (0..200)
+            .map(|i| {
+                let i = i as f64;
+                point!(x: (i * 7.3) % 2.0, y: (i * 3.1) % 1.5)
+            })
+            .collect();
+        let min_distance = 50_000.0;
+
+        let mut expected: Vec<Point> = vec![];
+        for point in points.iter() {
+            if expected
+                .iter()
+                .all(|kept| Haversine::distance(*kept, *point) >= min_distance)
+            {
+                expected.push(*point);
+            }
+        }
+        assert_eq!(
+            points.thin_points_haversine(min_distance),
+            MultiPoint::new(expected)
+        );
+    }
+}
@@ -1,5 +1,70 @@
 pub use proj::{Area, Coord, Info, Proj, ProjBuilder, ProjError, ProjInfo, Transform};
 
+use crate::Geometry;
+
+/// 使用同一个 [`Proj`] 转换一批几何体。
+///
+/// 对每个几何体调用 `transform_crs_to_crs` 都会重新创建一次投影，几何体数量很多时开销很大。
+/// 此函数只使用调用者预先创建好的 `proj`，对每个几何体依次调用 [`Transform::transformed`]。
+/// 因此也可以使用 [`ProjBuilder`] 启用网络访问（`proj-network` 功能）后创建的投影。
+///
+/// 遇到第一个转换失败的几何体时立即返回，错误中包含该几何体在输入中的索引。
+///
+/// # 示例
+///
+/// ```
+/// use geo::transform::{transform_many, Proj};
+/// use geo::{point, Geometry};
+///
+/// let proj = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+/// let geometries: Vec<Geometry> = vec![
+///     point!(x: 4760096.421921, y: 3744293.729449).into(),
+///     point!(x: 4760196.421921, y: 3744393.729449).into(),
+/// ];
+///
+/// let transformed = transform_many(&geometries, &proj).unwrap();
+/// assert_eq!(transformed.len(), 2);
+/// ```
+pub fn transform_many<'a>(
+    geometries: impl IntoIterator<Item = &'a Geometry<f64>>,
+    proj: &Proj,
+) -> Result<Vec<Geometry<f64>>, TransformManyError> {
+    geometries
+        .into_iter()
+        .enumerate()
+        .map(|(index, geometry)| {
+            geometry
+                .transformed(proj)
+                .map_err(|source| TransformManyError { index, source })
+        })
+        .collect()
+}
+
+/// [`transform_many`] 中某个几何体转换失败时返回的错误。
+#[derive(Debug)]
+pub struct TransformManyError {
+    /// 转换失败的几何体在输入中的索引
+    pub index: usize,
+    /// PROJ 返回的错误
+    pub source: ProjError,
+}
+
+impl std::fmt::Display for TransformManyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to transform geometry at index {}: {}",
+            self.index, self.source
+        )
+    }
+}
+
+impl std::error::Error for TransformManyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,4 +94,38 @@ mod tests {
         // 断言转换结果与期望结果相对相等，误差容限为0.2
         assert_relative_eq!(subject, expected, epsilon = 0.2);
     }
+
+    #[test]
+    fn test_transform_many() {
+        use crate::Geometry;
+
+        let proj = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+        let geometries: Vec<Geometry> = vec![
+            point!(x: 4760096.421921f64, y: 3744293.729449f64).into(),
+            Rect::new(
+                point!(x: 4760096.421921f64, y: 3744293.729449f64),
+                point!(x: 4760196.421921f64, y: 3744393.729449f64),
+            )
+            .into(),
+        ];
+
+        let transformed = transform_many(&geometries, &proj).unwrap();
+        for (geometry, transformed) in geometries.iter().zip(&transformed) {
+            assert_relative_eq!(
+                geometry.transformed(&proj).unwrap(),
+                *transformed,
+                epsilon = 1e-9
+            );
+        }
+
+        // 错误中包含失败的几何体的索引：纬度超出范围
+        let to_mercator = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let lon_lat: Vec<Geometry> = vec![
+            point!(x: 10.0f64, y: 50.0f64).into(),
+            point!(x: 10.0f64, y: 91.0f64).into(),
+            point!(x: 11.0f64, y: 51.0f64).into(),
+        ];
+        let error = transform_many(&lon_lat, &to_mercator).unwrap_err();
+        assert_eq!(error.index, 1);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{AffineOps, AffineTransform, CoordNum};
+use crate::{AffineOps, AffineTransform, Coord, CoordNum};
 
 pub trait Translate<T: CoordNum> {
     /// 沿几何图形的坐标轴按照给定的偏移量进行平移
@@ -34,6 +34,40 @@ pub trait Translate<T: CoordNum> {
 
     /// Translate a Geometry along its axes, but in place.
     fn translate_mut(&mut self, x_offset: T, y_offset: T);
+
+    /// 按照以 [`Coord`] 表示的偏移向量平移几何图形，等同于 `translate(delta.x, delta.y)`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::Translate;
+    /// use geo::{coord, point};
+    ///
+    /// let p = point!(x: 1.0, y: 5.0);
+    /// let translated = p.translate_coord(coord! { x: 1., y: 2. });
+    ///
+    /// assert_eq!(translated, point!(x: 2.0, y: 7.0));
+    /// ```
+    #[must_use]
+    fn translate_coord(&self, delta: Coord<T>) -> Self;
+
+    /// 返回 [`translate`](Self::translate) 将应用的 [`AffineTransform`]，而不应用它。
+    ///
+    /// 返回的变换可以与其他变换（例如 [`Scale::scale_xy_transform`](crate::Scale::scale_xy_transform)）
+    /// 组合，并一致地应用于其他几何图形。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{AffineOps, Translate};
+    /// use geo::point;
+    ///
+    /// let p = point!(x: 1.0, y: 5.0);
+    /// let transform = p.translate_transform(30.0, 20.0);
+    ///
+    /// assert_eq!(p.affine_transform(&transform), p.translate(30.0, 20.0));
+    /// ```
+    fn translate_transform(&self, x_offset: T, y_offset: T) -> AffineTransform<T>;
 }
 
 impl<T, G> Translate<T> for G
@@ -50,6 +84,14 @@ where
         let transform = AffineTransform::translate(x_offset, y_offset);
         self.affine_transform_mut(&transform)
     }
+
+    fn translate_coord(&self, delta: Coord<T>) -> Self {
+        self.translate(delta.x, delta.y)
+    }
+
+    fn translate_transform(&self, x_offset: T, y_offset: T) -> AffineTransform<T> {
+        AffineTransform::translate(x_offset, y_offset)
+    }
 }
 
 #[cfg(test)]
@@ -70,6 +112,15 @@ mod test {
         assert_eq!(p, point!(x: 31.0, y: 25.0));
     }
     #[test]
+    fn test_translate_coord() {
+        let polygon = polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 1.0, y: 1.0)];
+        let delta = Coord { x: -3.0, y: 4.5 };
+        assert_eq!(
+            polygon.translate_coord(delta),
+            polygon.translate(delta.x, delta.y)
+        );
+    }
+    #[test]
     fn test_translate_linestring() {
         let linestring = line_string![
             (x: 0.0, y: 0.0),
@@ -116,6 +116,64 @@ pub trait TriangulateEarcut<T: CoordFloat> {
     /// );
     /// ```
     fn earcut_triangles_raw(&self) -> RawTriangulation<T>;
+
+    /// 返回三角形以及每个三角形顶点在输入坐标序列中的索引，以便将三角形映射回原始的多边形顶点。
+    ///
+    /// 输入坐标按以下顺序展平：先是外环的所有坐标（包括闭合坐标），然后依次是每个内环的所有坐标
+    /// （同样包括闭合坐标）。这与 [`CoordsIter::coords_iter`] 的顺序相同，
+    /// 因此索引 `i` 对应 `polygon.coords_iter().nth(i)`。
+    ///
+    /// 三角形和索引的顺序与 `earcutr` 的原生输出（即 [`RawTriangulation::triangle_indices`]）一致：
+    /// 第 `n` 个三角形的顶点依次是 `indices[n]` 所指向的坐标。
+    /// 请注意，这与 [`earcut_triangles`](Self::earcut_triangles) 返回的三角形顺序不同。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::{coord, polygon, CoordsIter, Triangle, TriangulateEarcut};
+    ///
+    /// let square_polygon = polygon![
+    ///     (x: 0., y: 0.), // 西南
+    ///     (x: 10., y: 0.), // 东南
+    ///     (x: 10., y: 10.), // 东北
+    ///     (x: 0., y: 10.), // 西北
+    ///     (x: 0., y: 0.), // 西南
+    /// ];
+    ///
+    /// let (triangles, indices) = square_polygon.earcut_triangles_with_indices();
+    ///
+    /// assert_eq!(indices, vec![[3, 0, 1], [1, 2, 3]]);
+    /// assert_eq!(
+    ///     triangles[0],
+    ///     Triangle(
+    ///         coord! { x: 0., y: 10. }, // 西北
+    ///         coord! { x: 0., y: 0. }, // 西南
+    ///         coord! { x: 10., y: 0. }, // 东南
+    ///     ),
+    /// );
+    ///
+    /// let coords: Vec<_> = square_polygon.coords_iter().collect();
+    /// assert_eq!(triangles[1].0, coords[indices[1][0]]);
+    /// ```
+    fn earcut_triangles_with_indices(&self) -> (Vec<Triangle<T>>, Vec<[usize; 3]>) {
+        let raw = self.earcut_triangles_raw();
+        let vertex = |index: usize| {
+            coord! {
+                x: raw.vertices[index * 2],
+                y: raw.vertices[index * 2 + 1],
+            }
+        };
+        raw.triangle_indices
+            .chunks_exact(3)
+            .map(|chunk| {
+                let indices = [chunk[0], chunk[1], chunk[2]];
+                (
+                    Triangle(vertex(indices[0]), vertex(indices[1]), vertex(indices[2])),
+                    indices,
+                )
+            })
+            .unzip()
+    }
 }
 
 impl<T: CoordFloat> TriangulateEarcut<T> for Polygon<T> {
@@ -204,7 +262,7 @@ fn flat_line_string_coords_2<T: CoordFloat>(
 #[cfg(test)]
 mod test {
     use super::TriangulateEarcut;
-    use crate::{coord, polygon, Triangle};
+    use crate::{coord, polygon, CoordsIter, Triangle};
 
     #[test]
     fn test_triangle() {
@@ -256,4 +314,53 @@ mod test {
             triangles,
         );
     }
+
+    #[test]
+    fn test_indices_with_interior() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            interiors: [
+                [
+                    (x: 4., y: 4.),
+                    (x: 4., y: 6.),
+                    (x: 6., y: 6.),
+                    (x: 6., y: 4.),
+                    (x: 4., y: 4.),
+                ],
+            ],
+        ];
+
+        let (triangles, indices) = polygon.earcut_triangles_with_indices();
+        let raw = polygon.earcut_triangles_raw();
+        let coords: Vec<_> = polygon.coords_iter().collect();
+
+        assert_eq!(triangles.len(), indices.len());
+        assert_eq!(indices.concat(), raw.triangle_indices);
+        for (triangle, [a, b, c]) in triangles.iter().zip(&indices) {
+            assert_eq!(*triangle, Triangle(coords[*a], coords[*b], coords[*c]));
+        }
+        // 内环的顶点从外环的坐标之后开始编号
+        assert!(indices.iter().flatten().any(|index| *index >= 5));
+
+        // 与 `earcut_triangles` 得到的三角形集合相同
+        let mut expected = polygon.earcut_triangles();
+        expected.reverse();
+        assert_eq!(
+            triangles
+                .iter()
+                .map(|t| {
+                    let mut v = t.to_array();
+                    v.reverse();
+                    Triangle::from(v)
+                })
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
 }
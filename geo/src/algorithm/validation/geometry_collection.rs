@@ -46,7 +46,9 @@ impl<F: GeoFloat> Validation for GeometryCollection<F> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::algorithm::validation::{assert_validation_errors, InvalidLineString};
+    use crate::algorithm::validation::{
+        assert_validation_errors, InvalidLineString, InvalidPolygon, RingRole,
+    };
     use crate::wkt;
 
     #[test]
@@ -69,6 +71,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_geometrycollection_reports_member_index() {
+        let gc = wkt!(
+            GEOMETRYCOLLECTION(
+                POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.)),
+                POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),(5. 5.,6. 5.,6. 6.,5. 5.))
+            )
+        );
+        assert_validation_errors!(
+            gc,
+            vec![InvalidGeometryCollection::InvalidGeometry(
+                GeometryIndex(1),
+                Box::new(InvalidGeometry::InvalidPolygon(
+                    InvalidPolygon::InteriorRingNotContainedInExteriorRing(RingRole::Interior(0))
+                )),
+            )]
+        );
+    }
+
     #[test]
     fn test_display() {
         let gc = wkt!(
@@ -9,6 +9,7 @@ mod line_string;
 mod multi_line_string;
 mod multi_point;
 mod multi_polygon;
+mod orientation;
 mod point;
 mod polygon;
 mod rect;
@@ -24,9 +25,10 @@ pub use line_string::InvalidLineString;
 pub use multi_line_string::InvalidMultiLineString;
 pub use multi_point::InvalidMultiPoint;
 pub use multi_polygon::InvalidMultiPolygon;
+pub use orientation::MakeValidOrientation;
 pub use point::InvalidPoint;
 pub use polygon::InvalidPolygon;
-pub use rect::InvalidRect;
+pub use rect::{Axis, InvalidRect};
 pub use triangle::InvalidTriangle;
 
 use std::boxed::Box;
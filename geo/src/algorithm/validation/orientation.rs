@@ -0,0 +1,96 @@
+use super::Validation;
+use crate::orient::{Direction, Orient};
+
+/// 将多边形的环调整为约定的方向，然后重新验证。
+///
+/// [`Validation`] 遵循 OGC 标准，不把环的方向视为错误，因此方向错误的多边形仍被认为是有效的。
+/// 但许多格式和算法（例如 GeoJSON 的 [RFC 7946]）要求外环为逆时针方向、内环为顺时针方向。
+/// 此方法使用 [`Orient`] 以 [`Direction::Default`] 重新定向所有环，然后重新检查有效性：
+/// 如果结果有效则返回重新定向后的几何体，否则返回所有验证错误。
+///
+/// 重新定向不会修复其他问题，例如自相交或相互交叉的环；这些错误在重新定向后依然存在并被返回。
+///
+/// [RFC 7946]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.6
+///
+/// # 示例
+///
+/// ```
+/// use geo::winding_order::Winding;
+/// use geo::{wkt, MakeValidOrientation, Validation};
+///
+/// // 外环为顺时针方向
+/// let polygon = wkt!(POLYGON((0. 0.,0. 2.,2. 2.,2. 0.,0. 0.)));
+/// assert!(polygon.exterior().is_cw());
+///
+/// // 检测：方向不影响有效性
+/// assert!(polygon.is_valid());
+///
+/// // 修正方向并重新检查
+/// let oriented = polygon.make_valid_orientation().unwrap();
+/// assert!(oriented.exterior().is_ccw());
+///
+/// // 其他错误无法通过重新定向修复
+/// let bow_tie = wkt!(POLYGON((0. 0.,2. 2.,2. 0.,0. 2.,0. 0.)));
+/// assert!(bow_tie.make_valid_orientation().is_err());
+/// ```
+pub trait MakeValidOrientation: Validation + Sized {
+    /// 将外环定向为逆时针、内环定向为顺时针，并返回重新验证的结果。
+    fn make_valid_orientation(self) -> Result<Self, Vec<Self::Error>>;
+}
+
+impl<G> MakeValidOrientation for G
+where
+    G: Validation + Orient,
+{
+    fn make_valid_orientation(self) -> Result<Self, Vec<Self::Error>> {
+        let oriented = self.orient(Direction::Default);
+        let validation_errors = oriented.validation_errors();
+        if validation_errors.is_empty() {
+            Ok(oriented)
+        } else {
+            Err(validation_errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::validation::{
+        GeometryIndex, InvalidMultiPolygon, InvalidPolygon, RingRole,
+    };
+    use crate::winding_order::Winding;
+    use crate::{wkt, MultiPolygon};
+
+    #[test]
+    fn polygon_rings_are_reoriented() {
+        // 外环顺时针，内环逆时针
+        let polygon = wkt!(POLYGON(
+            (0. 0.,0. 4.,4. 4.,4. 0.,0. 0.),
+            (1. 1.,2. 1.,2. 2.,1. 2.,1. 1.)
+        ));
+        assert!(polygon.is_valid());
+        assert!(polygon.exterior().is_cw());
+        assert!(polygon.interiors()[0].is_ccw());
+
+        let oriented = polygon.clone().make_valid_orientation().unwrap();
+        assert!(oriented.exterior().is_ccw());
+        assert!(oriented.interiors()[0].is_cw());
+        assert_eq!(oriented.validation_errors(), vec![]);
+        // 已正确定向的多边形不变
+        assert_eq!(oriented.clone().make_valid_orientation(), Ok(oriented));
+    }
+
+    #[test]
+    fn multi_polygon_remaining_errors_are_reported() {
+        let multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,0. 1.,1. 1.,1. 0.,0. 0.)),
+            ((0. 0.,2. 2.,2. 0.,0. 2.,0. 0.))
+        ));
+        let errors = multi_polygon.make_valid_orientation().unwrap_err();
+        assert!(errors.contains(&InvalidMultiPolygon::InvalidPolygon(
+            GeometryIndex(1),
+            InvalidPolygon::SelfIntersection(RingRole::Exterior)
+        )));
+    }
+}
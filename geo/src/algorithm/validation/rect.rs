@@ -1,5 +1,5 @@
 use super::{utils, CoordIndex, Validation};
-use crate::{GeoFloat, Rect};
+use crate::{Coord, GeoFloat, Rect};
 
 use std::fmt;
 
@@ -8,6 +8,27 @@ pub enum InvalidRect {
     /// 一个有效的 [`Rect`] 必须有有限的坐标。
     /// 索引 `0` 表示最小坐标，索引 `1` 表示最大坐标。
     NonFiniteCoord(CoordIndex),
+    /// 一个有效的 [`Rect`] 在每个轴上的最小坐标都不能大于最大坐标。
+    ///
+    /// [`Rect::new`] 总是会规范化坐标，但通过反序列化等方式构造的 `Rect` 可能违反这一点，
+    /// 而许多算法（例如包含和相交判断）都假设 `min <= max`。
+    MinGreaterThanMax { axis: Axis },
+}
+
+/// 坐标轴。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Axis::X => write!(f, "x"),
+            Axis::Y => write!(f, "y"),
+        }
+    }
 }
 
 impl std::error::Error for InvalidRect {}
@@ -19,6 +40,9 @@ impl fmt::Display for InvalidRect {
                 let corner = if idx.0 == 0 { "min" } else { "max" }; // 判断索引是最小还是最大
                 write!(f, "rect 的坐标 {corner} 是非有限的") // 输出错误信息
             }
+            InvalidRect::MinGreaterThanMax { axis } => {
+                write!(f, "rect 的最小 {axis} 坐标大于最大 {axis} 坐标")
+            }
         }
     }
 }
@@ -38,6 +62,74 @@ impl<F: GeoFloat> Validation for Rect<F> {
         if utils::check_coord_is_not_finite(&self.max()) {
             handle_validation_error(InvalidRect::NonFiniteCoord(CoordIndex(1)))?;
         }
+        // 检查最小坐标是否大于最大坐标
+        for axis in axes_with_min_greater_than_max(self.min(), self.max()) {
+            handle_validation_error(InvalidRect::MinGreaterThanMax { axis })?;
+        }
         Ok(())
     }
 }
+
+fn axes_with_min_greater_than_max<F: GeoFloat>(
+    min: Coord<F>,
+    max: Coord<F>,
+) -> impl Iterator<Item = Axis> {
+    [(Axis::X, min.x > max.x), (Axis::Y, min.y > max.y)]
+        .into_iter()
+        .filter_map(|(axis, is_invalid)| is_invalid.then_some(axis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm::validation::assert_valid;
+    use crate::coord;
+
+    #[test]
+    fn test_rect_valid() {
+        assert_valid!(Rect::new(coord! { x: 3., y: 1. }, coord! { x: 0., y: 2. }));
+        // 退化为点的 Rect 也是有效的
+        assert_valid!(Rect::new(coord! { x: 1., y: 1. }, coord! { x: 1., y: 1. }));
+    }
+
+    #[test]
+    fn test_rect_min_greater_than_max() {
+        // `Rect::new` 会规范化坐标，所以直接检查未规范化的最小和最大坐标
+        let axes: Vec<_> =
+            axes_with_min_greater_than_max(coord! { x: 3., y: 1. }, coord! { x: 0., y: 2. })
+                .collect();
+        assert_eq!(axes, vec![Axis::X]);
+
+        let axes: Vec<_> =
+            axes_with_min_greater_than_max(coord! { x: 3., y: 4. }, coord! { x: 0., y: 2. })
+                .collect();
+        assert_eq!(axes, vec![Axis::X, Axis::Y]);
+
+        assert_eq!(
+            InvalidRect::MinGreaterThanMax { axis: Axis::Y }.to_string(),
+            "rect 的最小 y 坐标大于最大 y 坐标"
+        );
+    }
+
+    #[cfg(feature = "use-serde")]
+    #[test]
+    fn test_deserialized_rect_min_greater_than_max() {
+        use serde::de::{value, IntoDeserializer};
+        use serde::Deserialize;
+
+        // 反序列化不会规范化坐标，因此可以得到最小坐标大于最大坐标的 `Rect`
+        let deserializer =
+            IntoDeserializer::<value::Error>::into_deserializer(vec![vec![3., 4.], vec![0., 2.]]);
+        let rect = Rect::<f64>::deserialize(deserializer).unwrap();
+        assert_eq!(rect.min(), coord! { x: 3., y: 4. });
+
+        assert!(!rect.is_valid());
+        assert_eq!(
+            rect.validation_errors(),
+            vec![
+                InvalidRect::MinGreaterThanMax { axis: Axis::X },
+                InvalidRect::MinGreaterThanMax { axis: Axis::Y },
+            ]
+        );
+    }
+}
@@ -149,9 +149,55 @@ where
     }
 }
 
+/// 返回折线 `prev → vertex → next` 在 `vertex` 处左侧的夹角（弧度），范围为 `[0, 2π)`。
+///
+/// 夹角从射线 `vertex → next` 逆时针量到射线 `vertex → prev`。因此：
+///
+/// - 对于逆时针方向的环，这就是多边形在该顶点处的内角；
+/// - 直线（不转弯）的夹角为 `π`，左转时小于 `π`，右转时大于 `π`；
+/// - 原路折返时夹角为 `0`。
+///
+/// 如果 `prev` 或 `next` 与 `vertex` 重合，夹角没有定义，返回 `0`。
+///
+/// # 示例
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{angle_at_vertex, coord};
+/// use std::f64::consts::{FRAC_PI_2, PI};
+///
+/// let vertex = coord! { x: 1.0, y: 0.0 };
+/// let prev = coord! { x: 0.0, y: 0.0 };
+///
+/// // 左转 90°
+/// let left = angle_at_vertex(prev, vertex, coord! { x: 1.0, y: 1.0 });
+/// assert_relative_eq!(left, FRAC_PI_2);
+///
+/// // 右转 90°
+/// let right = angle_at_vertex(prev, vertex, coord! { x: 1.0, y: -1.0 });
+/// assert_relative_eq!(right, 3.0 * FRAC_PI_2);
+///
+/// // 直行
+/// let straight = angle_at_vertex(prev, vertex, coord! { x: 2.0, y: 0.0 });
+/// assert_relative_eq!(straight, PI);
+/// ```
+pub fn angle_at_vertex<T: CoordFloat>(prev: Coord<T>, vertex: Coord<T>, next: Coord<T>) -> T {
+    let outgoing = next - vertex;
+    let incoming = prev - vertex;
+    let angle = outgoing
+        .wedge_product(incoming)
+        .atan2(outgoing.dot_product(incoming));
+    if angle < T::zero() {
+        angle + T::from(std::f64::consts::TAU).unwrap()
+    } else {
+        // 将 -0 规范化为 0
+        angle + T::zero()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Vector2DOps;
+    use super::{angle_at_vertex, Vector2DOps};
     use crate::coord;
 
     #[test]
@@ -229,6 +275,35 @@ mod test {
         assert_eq!(a.magnitude_squared(), 25f64);
     }
 
+    #[test]
+    fn test_angle_at_vertex() {
+        use std::f64::consts::{FRAC_PI_4, PI};
+
+        let vertex = coord! { x: 2f64, y: 3f64 };
+        let prev = coord! { x: 0f64, y: 3f64 };
+        for (next, expected) in [
+            (coord! { x: 3f64, y: 4f64 }, 3.0 * FRAC_PI_4),
+            (coord! { x: 3f64, y: 2f64 }, 5.0 * FRAC_PI_4),
+            (coord! { x: 1f64, y: 4f64 }, FRAC_PI_4),
+            (coord! { x: 1f64, y: 2f64 }, 7.0 * FRAC_PI_4),
+            (coord! { x: 5f64, y: 3f64 }, PI),
+        ] {
+            assert_relative_eq!(angle_at_vertex(prev, vertex, next), expected);
+            // 反向经过同一顶点时，左侧的夹角变为另一侧
+            assert_relative_eq!(
+                angle_at_vertex(next, vertex, prev),
+                2.0 * PI - expected,
+                epsilon = 1e-12
+            );
+        }
+
+        // 原路折返和退化的情况
+        let back = angle_at_vertex(prev, vertex, prev);
+        assert_eq!(back, 0.0);
+        assert!(back.is_sign_positive());
+        assert_eq!(angle_at_vertex(vertex, vertex, prev), 0.0);
+    }
+
     #[test]
     fn test_left_right() {
         let a = coord! { x: 1f64, y: 0f64 };
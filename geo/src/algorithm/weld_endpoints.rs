@@ -0,0 +1,144 @@
+use rstar::{PointDistance, RTree};
+
+use crate::{GeoFloat, LineString, MultiLineString, Point};
+
+/// 将各组成部分中彼此接近的端点焊接到同一个坐标上。
+///
+/// 来自不同数据源或经过舍入的线常常在“应该”相接的地方留下微小的缝隙。
+/// 焊接后，相接的端点坐标完全相同，线集合因此构成一个正确的图，
+/// 可以直接用于 [`polygonize`](crate::polygonize) 等需要端点精确相接的操作。
+///
+/// 按顺序处理每个组成部分的起点和终点：如果已有的锚点中存在与该端点距离不超过 `tolerance`
+/// 的点，则将端点移动到最近的锚点；否则该端点成为新的锚点。因此端点总是被吸附到原有的坐标上，
+/// **结果依赖于组成部分的顺序**。只移动端点，内部顶点保持不变。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, WeldEndpoints};
+///
+/// let lines = wkt!(MULTILINESTRING(
+///     (0. 0.,1. 0.),
+///     (1.001 0.,1. 1.),
+///     (1. 0.999,0. 0.001)
+/// ));
+///
+/// assert_eq!(
+///     lines.weld_endpoints(0.01),
+///     wkt!(MULTILINESTRING(
+///         (0. 0.,1. 0.),
+///         (1. 0.,1. 1.),
+///         (1. 1.,0. 0.)
+///     ))
+/// );
+/// ```
+pub trait WeldEndpoints<T: GeoFloat> {
+    fn weld_endpoints(&self, tolerance: T) -> MultiLineString<T>;
+}
+
+impl<T: GeoFloat> WeldEndpoints<T> for MultiLineString<T> {
+    fn weld_endpoints(&self, tolerance: T) -> MultiLineString<T> {
+        let tolerance_2 = tolerance * tolerance;
+        let mut anchors: RTree<Point<T>> = RTree::new();
+        let mut weld = |point: Point<T>| -> Point<T> {
+            match anchors.nearest_neighbor(&point) {
+                Some(anchor) if anchor.distance_2(&point) <= tolerance_2 => *anchor,
+                _ => {
+                    anchors.insert(point);
+                    point
+                }
+            }
+        };
+
+        let line_strings = self
+            .iter()
+            .map(|line_string| {
+                let mut coords = line_string.0.clone();
+                if let Some(first) = coords.first_mut() {
+                    *first = weld(Point(*first)).0;
+                }
+                if coords.len() > 1 {
+                    let last = coords.len() - 1;
+                    coords[last] = weld(Point(coords[last])).0;
+                }
+                LineString::new(coords)
+            })
+            .collect();
+        MultiLineString::new(line_strings)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygonize, wkt};
+
+    #[test]
+    fn interior_vertices_untouched() {
+        let lines = wkt!(MULTILINESTRING(
+            (0. 0.,0.0001 0.,5. 0.),
+            (5.0001 0.,10. 0.)
+        ));
+        assert_eq!(
+            lines.weld_endpoints(0.01),
+            wkt!(MULTILINESTRING(
+                (0. 0.,0.0001 0.,5. 0.),
+                (5. 0.,10. 0.)
+            ))
+        );
+    }
+
+    #[test]
+    fn snaps_to_nearest_anchor() {
+        let lines = wkt!(MULTILINESTRING(
+            (0. 0.,0. 5.),
+            (1. 0.,1. 5.),
+            (0.6 0.,3. 3.)
+        ));
+        // (0.6, 0) 与两个锚点都在容差范围内，吸附到较近的 (1, 0)
+        assert_eq!(
+            lines.weld_endpoints(0.7).0[2],
+            wkt!(LINESTRING(1. 0.,3. 3.))
+        );
+        // 超出容差的端点不变
+        assert_eq!(lines.weld_endpoints(0.1), lines);
+    }
+
+    #[test]
+    fn enables_polygonize() {
+        let lines = wkt!(MULTILINESTRING(
+            (0. 0.,1. 0.),
+            (1.001 0.,1. 1.),
+            (0.999 1.,0. 1.),
+            (0. 1.001,0. 0.)
+        ));
+        let (polygons, dangles) = polygonize(&lines.0);
+        assert!(polygons.0.is_empty());
+        assert_eq!(dangles.len(), 4);
+
+        let (polygons, dangles) = polygonize(&lines.weld_endpoints(0.01).0);
+        assert_eq!(polygons.0.len(), 1);
+        assert!(dangles.is_empty());
+    }
+
+    #[test]
+    fn degenerate() {
+        let lines = MultiLineString::new(vec![
+            LineString::new(vec![]),
+            wkt!(LINESTRING(0. 0.)),
+            wkt!(LINESTRING(0.001 0.,1. 1.)),
+        ]);
+        assert_eq!(
+            lines.weld_endpoints(0.01),
+            MultiLineString::new(vec![
+                LineString::new(vec![]),
+                wkt!(LINESTRING(0. 0.)),
+                wkt!(LINESTRING(0. 0.,1. 1.)),
+            ])
+        );
+        assert_eq!(
+            MultiLineString::<f64>::new(vec![]).weld_endpoints(1.0),
+            MultiLineString::new(vec![])
+        );
+    }
+}
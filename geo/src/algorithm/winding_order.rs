@@ -1,7 +1,7 @@
 use super::kernels::*;
 use crate::coords_iter::CoordsIter;
 use crate::utils::EitherIter;
-use crate::{CoordNum, GeoFloat, GeoNum, LineString, Point};
+use crate::{CoordNum, GeoFloat, GeoNum, LineString, Point, Polygon, Rect};
 use geo_types::{PointsIter, Triangle};
 use std::iter::Rev;
 
@@ -108,6 +108,31 @@ pub trait Winding {
             WindingOrder::CounterClockwise => self.make_ccw_winding(),
         }
     }
+
+    /// 将绕行顺序设置为指定的绕行顺序，并返回点的顺序是否被反转
+    ///
+    /// 绕行顺序未定义时（例如不同的点少于 3 个），对象不变并返回 `false`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use geo::winding_order::{Winding, WindingOrder};
+    /// use geo::wkt;
+    ///
+    /// let mut ring = wkt!(LINESTRING(0. 0.,0. 1.,1. 1.,0. 0.));
+    /// assert!(ring.set_winding(WindingOrder::CounterClockwise));
+    /// assert!(!ring.set_winding(WindingOrder::CounterClockwise));
+    /// assert!(ring.is_ccw());
+    /// ```
+    fn set_winding(&mut self, winding_order: WindingOrder) -> bool {
+        match self.winding_order() {
+            Some(current) if current != winding_order => {
+                self.make_winding_order(winding_order);
+                true
+            }
+            _ => false,
+        }
+    }
 }
 
 impl<T, K> Winding for LineString<T>
@@ -119,7 +144,7 @@ where
 
     fn winding_order(&self) -> Option<WindingOrder> {
         // 如果 linestring 的坐标数不超过3，它要么未闭合，要么最多两个不同点。无论哪种方式，绕行顺序未指定。
-        if self.coords_count() < 4 || !self.is_closed() {
+        if !self.is_ring() {
             return None;
         }
 
@@ -200,6 +225,112 @@ where
     }
 }
 
+/// 以指定的外环绕行顺序将 [`Rect`] 转换为 [`Polygon`]。
+///
+/// [`Rect::to_polygon`] 总是生成顺时针的外环。当下游要求特定的方向时，
+/// 使用此方法可以省去随后的 [`Orient`](crate::Orient) 调用。两种顺序的外环都从 `min` 角开始。
+///
+/// # 示例
+///
+/// ```
+/// use geo::winding_order::{ToPolygonWithWinding, WindingOrder};
+/// use geo::{coord, polygon, Rect};
+///
+/// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 2. });
+///
+/// assert_eq!(
+///     rect.to_polygon_with_winding(WindingOrder::CounterClockwise),
+///     polygon![
+///         (x: 0., y: 0.),
+///         (x: 1., y: 0.),
+///         (x: 1., y: 2.),
+///         (x: 0., y: 2.),
+///         (x: 0., y: 0.),
+///     ],
+/// );
+/// assert_eq!(
+///     rect.to_polygon_with_winding(WindingOrder::Clockwise),
+///     rect.to_polygon(),
+/// );
+/// ```
+pub trait ToPolygonWithWinding<T: CoordNum> {
+    fn to_polygon_with_winding(&self, winding: WindingOrder) -> Polygon<T>;
+}
+
+impl<T: CoordNum> ToPolygonWithWinding<T> for Rect<T> {
+    fn to_polygon_with_winding(&self, winding: WindingOrder) -> Polygon<T> {
+        match winding {
+            WindingOrder::Clockwise => self.to_polygon(),
+            WindingOrder::CounterClockwise => {
+                let (min, max) = (self.min(), self.max());
+                Polygon::new(
+                    LineString::from(vec![
+                        (min.x, min.y),
+                        (max.x, min.y),
+                        (max.x, max.y),
+                        (min.x, max.y),
+                        (min.x, min.y),
+                    ]),
+                    vec![],
+                )
+            }
+        }
+    }
+}
+
+/// 一次返回 [`Polygon`] 外环和每个内环的绕行顺序。
+///
+/// 每个环的结果与对其调用 [`Winding::winding_order`] 相同：少于三个不同坐标的环
+/// （例如空环或退化的内环）没有确定的绕行顺序，返回 `None`。
+///
+/// 按照 OGC 的约定，外环通常为逆时针、内环为顺时针，但 geo 并不强制这一点；
+/// 如需调整方向，请参见 [`Orient`](crate::Orient)。
+///
+/// # 示例
+///
+/// ```
+/// use geo::winding_order::{RingWindingOrders, WindingOrder};
+/// use geo::{wkt, LineString, Polygon};
+///
+/// let polygon = wkt!(POLYGON(
+///     (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+///     (2. 2.,2. 4.,4. 4.,4. 2.,2. 2.),
+///     (6. 6.,8. 6.,8. 8.,6. 6.)
+/// ));
+///
+/// assert_eq!(
+///     polygon.ring_winding_orders(),
+///     (
+///         Some(WindingOrder::CounterClockwise),
+///         vec![Some(WindingOrder::Clockwise), Some(WindingOrder::CounterClockwise)]
+///     )
+/// );
+///
+/// let degenerate_hole = Polygon::new(
+///     polygon.exterior().clone(),
+///     vec![LineString::from(vec![(1., 1.), (2., 2.), (1., 1.)])],
+/// );
+/// assert_eq!(
+///     degenerate_hole.ring_winding_orders(),
+///     (Some(WindingOrder::CounterClockwise), vec![None])
+/// );
+/// ```
+pub trait RingWindingOrders {
+    fn ring_winding_orders(&self) -> (Option<WindingOrder>, Vec<Option<WindingOrder>>);
+}
+
+impl<T: GeoNum> RingWindingOrders for Polygon<T> {
+    fn ring_winding_orders(&self) -> (Option<WindingOrder>, Vec<Option<WindingOrder>>) {
+        (
+            self.exterior().winding_order(),
+            self.interiors()
+                .iter()
+                .map(|interior| interior.winding_order())
+                .collect(),
+        )
+    }
+}
+
 // 此函数可能通过小重构特质实现转换为特质实现，但不在本次 PR 的范围内添加.
 /// 用于寻找三角形绕行顺序的特殊算法
 pub fn triangle_winding_order<T: GeoFloat>(tri: &Triangle<T>) -> Option<WindingOrder> {
@@ -239,6 +370,34 @@ mod test {
         assert_eq!(ls.winding_order(), Some(WindingOrder::Clockwise));
     }
 
+    #[test]
+    fn set_winding_reports_reversal() {
+        use crate::wkt;
+
+        let mut ring = wkt!(LINESTRING(0 0,2 0,1 2,0 0));
+        assert!(!ring.set_winding(WindingOrder::CounterClockwise));
+        assert_eq!(ring, wkt!(LINESTRING(0 0,2 0,1 2,0 0)));
+
+        assert!(ring.set_winding(WindingOrder::Clockwise));
+        assert!(ring.is_cw());
+
+        // 绕行顺序未定义时不做修改
+        let mut degenerate = wkt!(LINESTRING(0 0,1 1,0 0));
+        assert!(!degenerate.set_winding(WindingOrder::Clockwise));
+        assert_eq!(degenerate, wkt!(LINESTRING(0 0,1 1,0 0)));
+    }
+
+    #[test]
+    fn rect_to_polygon_with_winding() {
+        let rect = Rect::new((-2, -1), (3, 4));
+        for winding in [WindingOrder::Clockwise, WindingOrder::CounterClockwise] {
+            let polygon = rect.to_polygon_with_winding(winding);
+            assert_eq!(polygon.exterior().winding_order(), Some(winding));
+            assert_eq!(polygon.exterior().0[0], rect.min());
+            assert!(polygon.interiors().is_empty());
+        }
+    }
+
     #[test]
     fn robust_winding_integer() {
         // 三个点构成一个三角形
@@ -260,4 +419,22 @@ mod test {
 
         assert_eq!(&ls.points_ccw().collect::<Vec<_>>(), &ccw_ls,);
     }
+
+    #[test]
+    fn polygon_ring_winding_orders() {
+        use crate::wkt;
+
+        let polygon = wkt!(POLYGON(
+            (0 0,0 10,10 10,10 0,0 0),
+            (2 2,4 2,4 4,2 4,2 2),
+            (6 6,8 8,6 6)
+        ));
+        let (exterior, interiors) = polygon.ring_winding_orders();
+        assert_eq!(exterior, polygon.exterior().winding_order());
+        assert_eq!(exterior, Some(WindingOrder::Clockwise));
+        assert_eq!(interiors, vec![Some(WindingOrder::CounterClockwise), None]);
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.ring_winding_orders(), (None, vec![]));
+    }
 }
@@ -0,0 +1,301 @@
+use std::fmt::{Display, Write};
+
+use crate::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// 将几何体写为 [WKT](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry)
+/// 字符串，无需依赖 `wkt` crate。主要用于日志和调试。
+///
+/// `precision` 为 `Some(n)` 时，每个坐标值保留 `n` 位小数；为 `None` 时，使用坐标类型的
+/// [`Display`] 输出（对于浮点数，即能精确还原该值的最短表示）。整数坐标总是忽略精度。
+///
+/// 输出格式与 `wkt` crate 相同。[`Line`] 写为 `LINESTRING`，[`Rect`] 和 [`Triangle`] 写为 `POLYGON`。
+///
+/// # 示例
+///
+/// ```
+/// use geo::{wkt, ToWktString};
+///
+/// let polygon = wkt!(POLYGON((0. 0.,1. 0.,0.333333 1.,0. 0.)));
+/// assert_eq!(
+///     polygon.to_wkt_string(None),
+///     "POLYGON((0 0,1 0,0.333333 1,0 0))"
+/// );
+/// assert_eq!(
+///     polygon.to_wkt_string(Some(2)),
+///     "POLYGON((0.00 0.00,1.00 0.00,0.33 1.00,0.00 0.00))"
+/// );
+///
+/// let multi_point = wkt!(MULTIPOINT(1 2,3 4));
+/// assert_eq!(multi_point.to_wkt_string(None), "MULTIPOINT((1 2),(3 4))");
+/// ```
+pub trait ToWktString {
+    fn to_wkt_string(&self, precision: Option<usize>) -> String;
+}
+
+struct WktWriter {
+    out: String,
+    precision: Option<usize>,
+}
+
+impl WktWriter {
+    fn write<G: WriteWkt>(geometry: &G, precision: Option<usize>) -> String {
+        let mut writer = WktWriter {
+            out: String::new(),
+            precision,
+        };
+        geometry.write_wkt(&mut writer);
+        writer.out
+    }
+
+    fn value<T: Display>(&mut self, value: T) {
+        // 写入 String 不会失败
+        let _ = match self.precision {
+            Some(precision) => write!(self.out, "{value:.precision$}"),
+            None => write!(self.out, "{value}"),
+        };
+    }
+
+    fn coord<T: CoordNum + Display>(&mut self, coord: &Coord<T>) {
+        self.value(coord.x);
+        self.out.push(' ');
+        self.value(coord.y);
+    }
+
+    /// 写入 `(x y,x y,...)`。
+    fn coords<'a, T: CoordNum + Display + 'a>(
+        &mut self,
+        coords: impl IntoIterator<Item = &'a Coord<T>>,
+    ) {
+        self.out.push('(');
+        for (i, coord) in coords.into_iter().enumerate() {
+            if i > 0 {
+                self.out.push(',');
+            }
+            self.coord(coord);
+        }
+        self.out.push(')');
+    }
+
+    /// 写入 `(item,item,...)`。
+    fn list<I: IntoIterator>(&mut self, items: I, mut write_item: impl FnMut(&mut Self, I::Item)) {
+        self.out.push('(');
+        for (i, item) in items.into_iter().enumerate() {
+            if i > 0 {
+                self.out.push(',');
+            }
+            write_item(self, item);
+        }
+        self.out.push(')');
+    }
+
+    /// 写入 `((x y,...),(x y,...))`。
+    fn rings<T: CoordNum + Display>(&mut self, polygon: &Polygon<T>) {
+        let rings = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+        self.list(rings, |writer, ring| writer.coords(&ring.0));
+    }
+}
+
+trait WriteWkt {
+    fn write_wkt(&self, writer: &mut WktWriter);
+}
+
+macro_rules! impl_to_wkt_string {
+    ($($type:ident),*) => {
+        $(
+            impl<T: CoordNum + Display> ToWktString for $type<T> {
+                fn to_wkt_string(&self, precision: Option<usize>) -> String {
+                    WktWriter::write(self, precision)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_wkt_string!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Rect,
+    Triangle,
+    GeometryCollection,
+    Geometry
+);
+
+impl<T: CoordNum + Display> WriteWkt for Point<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        writer.out.push_str("POINT");
+        writer.coords([&self.0]);
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for Line<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        writer.out.push_str("LINESTRING");
+        writer.coords([&self.start, &self.end]);
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for LineString<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        if self.0.is_empty() {
+            writer.out.push_str("LINESTRING EMPTY");
+        } else {
+            writer.out.push_str("LINESTRING");
+            writer.coords(&self.0);
+        }
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for Polygon<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        if self.exterior().0.is_empty() {
+            writer.out.push_str("POLYGON EMPTY");
+        } else {
+            writer.out.push_str("POLYGON");
+            writer.rings(self);
+        }
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for MultiPoint<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        if self.0.is_empty() {
+            writer.out.push_str("MULTIPOINT EMPTY");
+        } else {
+            writer.out.push_str("MULTIPOINT");
+            writer.list(&self.0, |writer, point| writer.coords([&point.0]));
+        }
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for MultiLineString<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        if self.0.is_empty() {
+            writer.out.push_str("MULTILINESTRING EMPTY");
+        } else {
+            writer.out.push_str("MULTILINESTRING");
+            writer.list(&self.0, |writer, line_string| writer.coords(&line_string.0));
+        }
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for MultiPolygon<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        if self.0.is_empty() {
+            writer.out.push_str("MULTIPOLYGON EMPTY");
+        } else {
+            writer.out.push_str("MULTIPOLYGON");
+            writer.list(&self.0, |writer, polygon| writer.rings(polygon));
+        }
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for Rect<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        self.to_polygon().write_wkt(writer);
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for Triangle<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        self.to_polygon().write_wkt(writer);
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for GeometryCollection<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        if self.0.is_empty() {
+            writer.out.push_str("GEOMETRYCOLLECTION EMPTY");
+        } else {
+            writer.out.push_str("GEOMETRYCOLLECTION");
+            writer.list(&self.0, |writer, geometry| geometry.write_wkt(writer));
+        }
+    }
+}
+
+impl<T: CoordNum + Display> WriteWkt for Geometry<T> {
+    fn write_wkt(&self, writer: &mut WktWriter) {
+        match self {
+            Geometry::Point(g) => g.write_wkt(writer),
+            Geometry::Line(g) => g.write_wkt(writer),
+            Geometry::LineString(g) => g.write_wkt(writer),
+            Geometry::Polygon(g) => g.write_wkt(writer),
+            Geometry::MultiPoint(g) => g.write_wkt(writer),
+            Geometry::MultiLineString(g) => g.write_wkt(writer),
+            Geometry::MultiPolygon(g) => g.write_wkt(writer),
+            Geometry::GeometryCollection(g) => g.write_wkt(writer),
+            Geometry::Rect(g) => g.write_wkt(writer),
+            Geometry::Triangle(g) => g.write_wkt(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, point, wkt};
+
+    #[test]
+    fn round_trips_through_wkt_crate() {
+        use std::str::FromStr;
+
+        let geometry: Geometry = Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(
+            POINT(1.5 -2.),
+            LINESTRING(0. 0.,1. 1.),
+            POLYGON((0. 0.,4. 0.,4. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.)),
+            MULTIPOINT(1. 2.,3. 4.),
+            MULTILINESTRING((0. 0.,1. 0.),(2. 2.,3. 3.)),
+            MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,6. 5.,6. 6.,5. 5.))),
+            LINESTRING EMPTY
+        )));
+        let wkt_string = geometry.to_wkt_string(None);
+        let parsed: Geometry = wkt::Wkt::<f64>::from_str(&wkt_string)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(parsed, geometry);
+    }
+
+    #[test]
+    fn precision_and_integers() {
+        assert_eq!(
+            point!(x: 1.0 / 3.0, y: -2.0).to_wkt_string(Some(3)),
+            "POINT(0.333 -2.000)"
+        );
+        assert_eq!(
+            point!(x: 1.0 / 3.0, y: -2.0).to_wkt_string(Some(0)),
+            "POINT(0 -2)"
+        );
+        assert_eq!(
+            Line::new(coord! { x: 1, y: 2 }, coord! { x: 3, y: 4 }).to_wkt_string(Some(2)),
+            "LINESTRING(1 2,3 4)"
+        );
+        assert_eq!(
+            Rect::new(coord! { x: 0, y: 0 }, coord! { x: 1, y: 2 }).to_wkt_string(None),
+            "POLYGON((0 0,0 2,1 2,1 0,0 0))"
+        );
+    }
+
+    #[test]
+    fn empty_geometries() {
+        assert_eq!(
+            MultiPolygon::<f64>::new(vec![]).to_wkt_string(None),
+            "MULTIPOLYGON EMPTY"
+        );
+        assert_eq!(
+            Polygon::<f64>::new(LineString::new(vec![]), vec![]).to_wkt_string(Some(1)),
+            "POLYGON EMPTY"
+        );
+        assert_eq!(
+            GeometryCollection::<f64>::new_from(vec![]).to_wkt_string(None),
+            "GEOMETRYCOLLECTION EMPTY"
+        );
+    }
+}
@@ -44,19 +44,24 @@
 //! - **[`Bearing`]**: 计算两点之间的方位。
 //!
 //! - **[`Destination`]**: 给定方位和距离，从起始点计算目的地点。
-//! - **[`InterpolatePoint`]**: 沿着直线插入点。
-//! - **[`Densify`]**: 向几何体中插入点，以便两个点之间从不超过`max_segment_length`。
+//! - **[`InterpolatePoint`]**: 沿着直线插入点，或求两点之间的中点。
+//! - **[`Densify`]**: 向几何体中插入点，以便两个点之间从不超过`max_segment_length`；也可以用闭包为每条线段指定不同的最大长度
+//! - **[`DensifyWithFractions`]**: 与[`Densify`]相同，但同时返回每个点在原始线上的归一化弧长位置
+//! - **[`DensifySegments`]**: 与[`Densify`]相同，但只加密[`LineString`]中选定的线段
+//! - **[`DensifyInteger`]**: 对整数坐标的几何体进行密集化，插入的点四舍五入到整数
+//! - **[`DetectArcs`]**: 找出[`LineString`]中近似位于同一圆弧上的连续点，即加密的逆操作
 //!
 //! ### 杂项度量
 //!
-//! - **[`HausdorffDistance`]**: 计算“从任何一个集合中的一点到另一个集合中最近一点的距离的最大值。”（Rote, 1991）
+//! - **[`HausdorffDistance`]**: 计算“从任何一个集合中的一点到另一个集合中最近一点的距离的最大值。”（Rote, 1991）；也提供单向的有向距离
 //! - **[`VincentyDistance`]**: 使用Vincenty公式计算几何体之间的最小测地距离
 //! - **[`VincentyLength`]**: 使用Vincenty公式计算几何体的测地长度
-//! - **[`FrechetDistance`]**: 使用弗雷歇距离计算[`LineString`]之间的相似性
+//! - **[`FrechetDistance`]**: 使用弗雷歇距离计算[`LineString`]之间的相似性，可同时返回最优耦合路径
 //!
 //! ## 面积
 //!
 //! - **[`Area`]**: 计算几何体的平面区域
+//! - **[`LargestPolygon`]**: 按面积选择或排序[`MultiPolygon`]的成员多边形
 //! - **[`ChamberlainDuquetteArea`]**: 使用Chamberlain和Duquette（2007）在_球面上的多边形的一些算法_中提出的算法计算几何体在球体上的测地面积
 //! - **[`GeodesicArea`]**: 使用Charles Karney（2013）在_测地算法_中提出的算法计算几何体在椭球体上的测地面积和周长
 //!
@@ -64,6 +69,8 @@
 //!
 //! - **[`BooleanOps`]**: 使用交集、联合、异或或差运算组合或拆分（Multi）多边形
 //! - **[`unary_union`]**: 高效地联合多个[`Polygon`]或[`MultiPolygon`]。
+//! - **[`unary_union_owned`]**: 与[`unary_union`]相同，但接受拥有所有权的几何体迭代器，以降低峰值内存
+//! - **[`Dissolve`]**: 合并[`MultiPolygon`]中相互重叠的成员
 //!
 //! ## 异常值检测
 //!
@@ -71,19 +78,27 @@
 //!
 //! ## 简化
 //!
-//! - **[`Simplify`]**: 使用Ramer-Douglas-Peucker算法简化几何体
+//! - **[`Simplify`]**: 使用Ramer-Douglas-Peucker算法简化几何体，并可报告是否移除了点
 //! - **[`SimplifyIdx`]**: 使用Ramer-Douglas-Peucker算法计算简化的几何体，返回坐标索引
+//! - **[`SimplifyPreserveClosed`]**: 使用Ramer-Douglas-Peucker算法简化多边形，并保证每个环保持闭合且坐标充足
+//! - **[`SimplifyPreservingArea`]**: 使用Ramer-Douglas-Peucker算法简化多边形，并通过缩放保持其面积
+//! - **[`SimplifyRing`]**: 将闭合的环作为循环简化，不以起点为特殊点，并保证结果保持闭合
+//! - **[`SimplifyValid`]**: 使用Ramer-Douglas-Peucker算法简化几何体，并保证结果有效
 //! - **[`SimplifyVw`]**: 使用Visvalingam-Whyatt算法简化几何体
 //! - **[`SimplifyVwPreserve`]**: 使用Visvalingam-Whyatt算法的拓扑保存变体简化几何体
 //! - **[`SimplifyVwIdx`]**: 使用Visvalingam-Whyatt算法计算简化的几何体，返回坐标索引
+//! - **[`ThinPoints`]**: 贪心地丢弃与已保留点距离过近的点，对[`MultiPoint`]进行抽稀
 //!
 //! ## 查询
 //!
 //! - **[`ClosestPoint`]**: 找到几何体上最接近给定点的点
 //! - **[`HaversineClosestPoint`]**: 使用球面坐标和线为大圆弧找到几何体上最接近给定点的点
+//! - **[`NearestGeometry`]**: 在由几何体组成的R树中，按精确距离查找离某点最近的几何体及其数据
+//! - **[`PreparedDistance`]**: 为几何体预先构建索引，高效地重复查询点到它的距离和最近点
 //! - **[`IsConvex`]**: 计算[`LineString`]的凸性
 //! - **[`LineInterpolatePoint`]**: 生成一个在给定线段上位于给定比例的位置的点
 //! - **[`LineLocatePoint`]**: 计算线段总长的一部分代表从线段到给定点最近点的位置
+//! - **[`LineProjectPoint`]**: 一次性计算线上距给定点最近的点、它的分数位置、距离和所在线段
 //! - **[`InteriorPoint`]**: 计算几何体内的一个代表点
 //!
 //! ## 拓扑
@@ -93,6 +108,9 @@
 //! - **[`HasDimensions`]**: 确定几何的维度
 //! - **[`Intersects`]**: 计算一个几何是否与另一个几何相交
 //! - **[`line_intersection`]**: 计算两条线之间的交点（如果有的话）
+//! - **[`node`]**: 在所有交点处打断一组线，生成只在端点处相接的边
+//! - **[`polygonize`]**: 由一组已打断的线构建多边形，并返回未成环的边
+//! - **[`WeldEndpoints`]**: 将[`MultiLineString`]中彼此接近的端点焊接到同一坐标，常用于`polygonize`之前的预处理
 //! - **[`Relate`]**: 基于[DE-9IM](https://en.wikipedia.org/wiki/DE-9IM)语义拓扑关系两个几何
 //! - **[`Within`]**: 计算一个几何是否完全位于另一个几何内
 //!
@@ -104,10 +122,15 @@
 //!
 //! - **[`Orient`]**: 对[`Polygon`]的内部和外部环应用指定的绕线[`Direction`](orient::Direction)
 //! - **[`Winding`]**: 计算并操作[`LineString`]的[`WindingOrder`](winding_order::WindingOrder)
+//! - **[`ToPolygonWithWinding`]**: 以指定的绕线顺序将[`Rect`]转换为[`Polygon`]
+//! - **[`RingWindingOrders`]**: 一次返回[`Polygon`]外环和各内环的绕线顺序
+//! - **[`Normalize`]**: 将[`Polygon`]转换为统一绕线方向、起点和内环顺序的规范形式
 //!
 //! ## 迭代
 //!
 //! - **[`CoordsIter`]**: 迭代几何的坐标
+//! - **[`ForEachCoordMut`]**: 通过闭包就地修改几何的坐标
+//! - **[`Complexity`]**: 统计几何的顶点、环和顶层组成部分的数量
 //! - **[`MapCoords`]**: 在几何的所有坐标上映射一个函数，返回一个新几何体
 //! - **[`MapCoordsInPlace`]**: 就地在几何的所有坐标上映射一个函数
 //! - **[`LinesIter`]**: 迭代几何的线条
@@ -115,14 +138,20 @@
 //! ## 边界
 //!
 //! - **[`BoundingRect`]**: 计算几何的轴对齐边界矩形
+//! - **[`BoundingRectAccumulator`]**: 逐个累积几何的边界矩形，无需先收集所有几何
+//! - **[`BoundingRectBuilder`]**: 逐个坐标地累积边界矩形，忽略含有NaN的坐标
 //! - **[`MinimumRotatedRect`]**: 计算几何的最小边界盒
 //! - **[`ConcaveHull`]**: 计算几何的凹壳
+//! - **[`HullBuilder`]**: 对同一组点以不同参数多次计算凹壳，只计算一次凸壳和空间索引
 //! - **[`ConvexHull`]**: 计算几何的凸壳
+//! - **[`ToAoi`]**: 以凸壳、边界矩形或凹壳将一组点转换为感兴趣区域多边形
 //! - **[`Extremes`]**: 计算几何的极值坐标和索引
+//! - **[`ExtremesIndexed`]**: 计算多多边形的极值坐标，以及它们所属多边形的索引
 //!
 //! ## 仿射变换
 //!
 //! - **[`Rotate`]**: 围绕几何的质心旋转几何
+//! - **[`RotateGeographic`]**: 在单位球面上绕穿过地理点和球心的轴旋转经纬度几何
 //! - **[`Scale`]**: 按因子缩放几何
 //! - **[`Skew`]**: 沿`x`和`y`维度倾斜几何
 //! - **[`Translate`]**: 沿轴平移几何
@@ -134,17 +163,27 @@
 //! - **[`TryConvert`]**: 转换（可能有错误）几何坐标值的数值类型
 //! - **[`ToDegrees`]**: 将给定几何体的坐标从弧度转换为角度
 //! - **[`ToRadians`]**: 将给定几何体的坐标从角度转换为弧度
+//! - **[`ToWktString`]**: 将几何体写为WKT字符串，可控制坐标精度
 //!
 //! ## 杂项
 //!
 //! - **[`Centroid`]**: 计算几何体的质心
-//! - **[`ChaikinSmoothing`]**: 使用Chaikin算法平滑`LineString`、`Polygon`、`MultiLineString`和`MultiPolygon`
+//! - **[`CentroidAllDimensions`]**: 不区分维度，计算几何集合中所有坐标的算术平均值
+//! - **[`ChaikinSmoothing`]**: 使用Chaikin算法平滑`LineString`、`Polygon`、`MultiLineString`、`MultiPolygon`和`Triangle`，可配置切角比例
 //! - **[`proj`]**: 使用`proj` crate投影几何体（需要启用`use-proj`功能）
+//! - **[`OffsetCurve`]**: 计算[`Line`]或[`LineString`]向左或向右偏移的平行线，顶点处使用斜接连接
 //! - **[`LineStringSegmentize`]**: 将LineString分割为`n`段
 //! - **[`LineStringSegmentizeHaversine`]**: 使用Haversine距离分割LineString
+//! - **[`LineStringSplitAtDistances`]**: 在给定的累积距离处切分LineString
 //! - **[`Transform`]**: 使用Proj变换几何体
+//! - **[`transform_many`]**: 使用同一个Proj变换一批几何体，错误中包含失败的几何体的索引
 //! - **[`RemoveRepeatedPoints`]**: 从几何体中移除重复的点
+//! - **[`RemoveRepeatedPointsWithin`]**: 在给定容差内移除几何体中近似重复的点
+//! - **[`RemoveSpikes`]**: 移除几何体中几乎原路折返的尖刺顶点
 //! - **[`Validation`]**: 检测几何体是否结构正确。一些算法可能无法正确处理无效几何体
+//! - **[`MakeValidOrientation`]**: 修正多边形环的方向并重新验证
+//! - **[`lex_cmp`]**、**[`min_coord`]**、**[`max_coord`]**: 按字典顺序比较坐标，并求字典顺序最小或最大的坐标
+//! - **[`angle_at_vertex`]**: 计算折线在某个顶点处左侧的夹角
 //!
 //! # 空间索引
 //!
@@ -169,6 +208,7 @@
 //!     - ☐ 默认禁用
 //! - `multithreading`:
 //!     - 启用多线程支持（通过Rayon），并激活`geo-types`中的`multithreading`标志，支持对`Multi*`几何体的多线程迭代
+//!     - [`Simplify`]会并行简化多边形的环和多多边形的成员
 //!     - ☑ 默认启用
 //!
 //! # 生态系统
@@ -290,6 +330,13 @@ impl<T> GeoFloat for T where
 }
 
 /// 对整数**和**浮点数都有效的方法特性。
+///
+/// # 无符号整数
+///
+/// `u32` 和 `u64` 也实现了此特性（使用 [`SimpleKernel`](kernels::SimpleKernel)），
+/// 以便直接使用瓦片和像素坐标。但是，方向判断（[`Kernel::orient2d`](kernels::Kernel::orient2d)）
+/// 等谓词会对坐标做减法，对于无符号类型，当差值为负时会溢出（在 debug 构建中 panic）。
+/// 如果算法需要方向判断（例如相交、包含等拓扑判断），请先将坐标转换为 `i64`。
 pub trait GeoNum: CoordNum {
     type Ker: Kernel<Self>;
 
@@ -332,6 +379,8 @@ impl_geo_num_for_int!(i32);
 impl_geo_num_for_int!(i64);
 impl_geo_num_for_int!(i128);
 impl_geo_num_for_int!(isize);
+impl_geo_num_for_int!(u32);
+impl_geo_num_for_int!(u64);
 
 #[cfg(test)]
 mod tests {
@@ -354,6 +403,25 @@ mod tests {
         assert_eq!(GeoNum::total_cmp(&1i32, &2i32), Ordering::Less);
     }
 
+    #[test]
+    fn unsigned_int() {
+        use crate::kernels::{Kernel, Orientation};
+
+        assert_eq!(GeoNum::total_cmp(&3u32, &2u32), Ordering::Greater);
+        assert_eq!(GeoNum::total_cmp(&u64::MAX, &u64::MAX), Ordering::Equal);
+
+        // 坐标单调递增时，方向判断中的差值都不为负
+        let (p, q, r) = (
+            coord! { x: 0u32, y: 0 },
+            coord! { x: 1u32, y: 1 },
+            coord! { x: 2u32, y: 3 },
+        );
+        assert_eq!(
+            <u32 as GeoNum>::Ker::orient2d(p, q, r),
+            Orientation::CounterClockwise
+        );
+    }
+
     #[test]
     fn numeric_types() {
         let _n_i16 = Point::new(1i16, 2i16);
@@ -361,6 +429,8 @@ mod tests {
         let _n_i64 = Point::new(1i64, 2i64);
         let _n_i128 = Point::new(1i128, 2i128);
         let _n_isize = Point::new(1isize, 2isize);
+        let _n_u32 = Point::new(1u32, 2u32);
+        let _n_u64 = Point::new(1u64, 2u64);
         let _n_f32 = Point::new(1.0f32, 2.0f32);
         let _n_f64 = Point::new(1.0f64, 2.0f64);
     }
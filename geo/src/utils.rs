@@ -94,14 +94,7 @@ pub fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
 
 use std::cmp::Ordering;
 
-/// 按字典顺序比较两个坐标：先按 x 坐标比较，如相等则比较 y 坐标。
-/// 假设坐标都是可比较的（例如，没有 nan）。
-#[inline]
-pub fn lex_cmp<T: CoordNum>(p: &Coord<T>, q: &Coord<T>) -> Ordering {
-    p.x.partial_cmp(&q.x)
-        .unwrap()
-        .then(p.y.partial_cmp(&q.y).unwrap())
-}
+pub use crate::algorithm::lex_order::lex_cmp;
 
 /// 计算切片中最小点的索引。比较使用 [`lex_cmp`]。
 ///